@@ -3,30 +3,50 @@ use std::fs::{self, File};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
+/// Largest atlas page side; WebGL2 guarantees textures at least this big.
+const ATLAS_SIZE: u32 = 2048;
+
+/// Transparent gap around packed sprites so filtering never bleeds in a
+/// neighbour.
+const ATLAS_PADDING: u32 = 2;
+
+/// Longest side of a packed sprite. Larger sprites are downscaled first so a
+/// page holds a dozen of the usual 1536x1024 fish instead of one; the game
+/// sizes sprites from their rect, so they still show at the same world size.
+const ATLAS_SPRITE_MAX: u32 = 640;
+
+// Every downscaled sprite, padded on both sides, must fit on an empty page.
+const _: () = assert!(ATLAS_SPRITE_MAX + 2 * ATLAS_PADDING <= ATLAS_SIZE);
+
+/// Sprite categories packed into atlases with the `atlas` feature.
+const ATLAS_CATEGORIES: &[&str] = &["fish", "obscura", "flora"];
+
 fn main() {
-    // Themes to iterate over – must match the runtime list in `Theme::prefix`.
-    const THEMES: &[&str] = &["crayon", "chibi", "retro"];
+    // Every directory under `assets/images` holds one theme's sprites.
+    let themes = list_theme_dirs(Path::new("assets/images"));
 
     // Helper function to gather files for a given sub-directory across all themes.
-    fn gather_thematic_assets(subdir: &str) -> Vec<String> {
+    let gather_thematic_assets = |subdir: &str| -> Vec<String> {
         let mut result = Vec::new();
-        for theme in THEMES {
+        for theme in &themes {
             let dir = Path::new("assets/images").join(theme).join(subdir);
             for file in list_png_files(&dir) {
                 result.push(format!("{theme}/{subdir}/{file}"));
             }
         }
         result
-    }
+    };
 
     let flora_files = gather_thematic_assets("flora");
     let obscura_files = gather_thematic_assets("obscura");
     let enemy_fish_files = gather_thematic_assets("fish");
+    let hazard_files = gather_thematic_assets("hazards");
 
-    // Generate Rust source containing arrays for flora and obscura.
+    // Generate Rust source containing arrays for flora, obscura and hazards.
     let generated = format!(
         "pub const FLORA_ASSET_PATHS: &[&str] = &[{}];\n\n\
-         pub const OBSCURA_ASSET_PATHS: &[&str] = &[{}];\n",
+         pub const OBSCURA_ASSET_PATHS: &[&str] = &[{}];\n\n\
+         pub const HAZARD_ASSET_PATHS: &[&str] = &[{}];\n",
         flora_files
             .iter()
             .map(|f| format!("\"images/{}\"", f))
@@ -37,6 +57,11 @@ fn main() {
             .map(|f| format!("\"images/{}\"", f))
             .collect::<Vec<_>>()
             .join(", "),
+        hazard_files
+            .iter()
+            .map(|f| format!("\"images/{}\"", f))
+            .collect::<Vec<_>>()
+            .join(", "),
     );
 
     // Generate Rust source for enemy fish list
@@ -64,12 +89,39 @@ fn main() {
         .write_all(enemy_fish_generated.as_bytes())
         .expect("Failed to write enemy_fish_asset_list.rs");
 
+    // Pack each theme category into atlas pages (`--features atlas`); without
+    // the feature the index is empty and every sprite loads as its own PNG.
+    let pages = if env::var_os("CARGO_FEATURE_ATLAS").is_some() {
+        pack_atlases(&themes, &out_dir)
+    } else {
+        Vec::new()
+    };
+    fs::write(out_dir.join("atlas_index.rs"), atlas_index_source(&pages))
+        .expect("Failed to write atlas_index.rs");
+
     // Re-run the build script if any PNG files change.
     println!("cargo:rerun-if-changed=assets/images");
 }
 
+/// Names of the sub-directories of `dir`, sorted. This is a plain scan of the
+/// image folders; `assets/themes.ron` is not consulted, so a directory with no
+/// registry entry is still listed (its sprites are simply never requested).
+fn list_theme_dirs(dir: &Path) -> Vec<String> {
+    let mut dirs: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| e.file_name().to_str().map(|s| s.to_owned()))
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
 fn list_png_files(dir: &Path) -> Vec<String> {
-    fs::read_dir(dir)
+    let mut files: Vec<String> = fs::read_dir(dir)
         .map(|entries| {
             entries
                 .flatten()
@@ -87,5 +139,143 @@ fn list_png_files(dir: &Path) -> Vec<String> {
                 })
                 .collect::<Vec<_>>()
         })
-        .unwrap_or_default()
-} 
\ No newline at end of file
+        .unwrap_or_default();
+    // Sorted so generated lists and atlas pages are reproducible.
+    files.sort();
+    files
+}
+
+// -----------------------------------------------------------------------------
+//  Texture atlas packing
+// -----------------------------------------------------------------------------
+
+/// One sprite placed on an atlas page.
+struct PackedSprite {
+    /// Asset path of the original PNG, e.g. `images/crayon/fish/e_1.png`.
+    source: String,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+/// One generated atlas PNG in `$OUT_DIR/atlases/`.
+struct PackedPage {
+    file: String,
+    sprites: Vec<PackedSprite>,
+}
+
+/// Shelf-pack every theme's `fish`, `obscura` and `flora` sprites into as few
+/// pages as possible, downscaling any longer than [`ATLAS_SPRITE_MAX`].
+fn pack_atlases(themes: &[String], out_dir: &Path) -> Vec<PackedPage> {
+    let atlas_dir = out_dir.join("atlases");
+    fs::create_dir_all(&atlas_dir).expect("Unable to create atlas directory");
+
+    let mut pages = Vec::new();
+    for theme in themes {
+        for category in ATLAS_CATEGORIES {
+            let dir = Path::new("assets/images").join(theme).join(category);
+            let mut sprites: Vec<(String, image::RgbaImage)> = Vec::new();
+            for file in list_png_files(&dir) {
+                let source = format!("images/{theme}/{category}/{file}");
+                match image::open(dir.join(&file)) {
+                    Ok(img) => sprites.push((source, downscale(img.to_rgba8()))),
+                    Err(e) => println!("cargo:warning=not packing {source}: {e}"),
+                }
+            }
+
+            // Tallest first keeps shelves tight.
+            sprites.sort_by(|a, b| b.1.height().cmp(&a.1.height()).then_with(|| a.0.cmp(&b.0)));
+
+            let mut index = 0;
+            while !sprites.is_empty() {
+                let file = format!("{theme}_{category}_{index}.png");
+                let (page, rest) = pack_page(sprites, &atlas_dir.join(&file));
+                pages.push(PackedPage { file, sprites: page });
+                sprites = rest;
+                index += 1;
+            }
+        }
+    }
+    pages
+}
+
+/// Shrink `img` so its longest side is at most [`ATLAS_SPRITE_MAX`], keeping
+/// its aspect ratio.
+fn downscale(img: image::RgbaImage) -> image::RgbaImage {
+    let (width, height) = img.dimensions();
+    let longest = width.max(height);
+    if longest <= ATLAS_SPRITE_MAX {
+        return img;
+    }
+    let resize = |side: u32| (u64::from(side) * u64::from(ATLAS_SPRITE_MAX) / u64::from(longest)).max(1) as u32;
+    image::imageops::resize(&img, resize(width), resize(height), image::imageops::FilterType::Triangle)
+}
+
+/// Place as many `sprites` as fit on one page, save it to `path` and return
+/// the placements plus the sprites left for the next page.
+fn pack_page(
+    sprites: Vec<(String, image::RgbaImage)>,
+    path: &Path,
+) -> (Vec<PackedSprite>, Vec<(String, image::RgbaImage)>) {
+    let (mut x, mut y, mut shelf_height) = (ATLAS_PADDING, ATLAS_PADDING, 0);
+    let (mut used_width, mut used_height) = (0, 0);
+    let mut placed = Vec::new();
+    let mut rest = Vec::new();
+
+    for (source, img) in sprites {
+        let (width, height) = img.dimensions();
+        if x + width + ATLAS_PADDING > ATLAS_SIZE {
+            x = ATLAS_PADDING;
+            y += shelf_height + ATLAS_PADDING;
+            shelf_height = 0;
+        }
+        if y + height + ATLAS_PADDING > ATLAS_SIZE {
+            rest.push((source, img));
+            continue;
+        }
+
+        used_width = used_width.max(x + width + ATLAS_PADDING);
+        used_height = used_height.max(y + height + ATLAS_PADDING);
+        placed.push((PackedSprite { source, x, y, width, height }, img));
+        x += width + ATLAS_PADDING;
+        shelf_height = shelf_height.max(height);
+    }
+
+    // An empty page would leave the caller retrying the same sprites forever.
+    assert!(!placed.is_empty(), "no sprite fits on atlas page {}", path.display());
+
+    let mut canvas = image::RgbaImage::new(used_width, used_height);
+    for (sprite, img) in &placed {
+        image::imageops::replace(&mut canvas, img, i64::from(sprite.x), i64::from(sprite.y));
+    }
+    canvas.save(path).expect("Unable to write atlas page");
+
+    (placed.into_iter().map(|(sprite, _)| sprite).collect(), rest)
+}
+
+/// Rust source for `$OUT_DIR/atlas_index.rs`, included by `src/atlas.rs`.
+fn atlas_index_source(pages: &[PackedPage]) -> String {
+    let mut source = String::from("pub const ATLAS_PAGES: &[AtlasPage] = &[\n");
+    for page in pages {
+        source.push_str(&format!(
+            "    AtlasPage {{\n        path: \"atlases/{file}\",\n        \
+             bytes: include_bytes!(concat!(env!(\"OUT_DIR\"), \"/atlases/{file}\")),\n        \
+             sprites: &[\n",
+            file = page.file
+        ));
+        for sprite in &page.sprites {
+            source.push_str(&format!(
+                "            AtlasSprite {{ source: {:?}, min: [{}, {}], max: [{}, {}] }},\n",
+                sprite.source,
+                sprite.x,
+                sprite.y,
+                sprite.x + sprite.width,
+                sprite.y + sprite.height
+            ));
+        }
+        source.push_str("        ],\n    },\n");
+    }
+    source.push_str("];\n");
+    source
+}
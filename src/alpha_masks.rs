@@ -1,6 +1,10 @@
 use std::collections::HashMap;
+use bevy::math::URect;
 use bevy::prelude::*;
 
+use crate::assets::ThemeSprite;
+use crate::theme::Theme;
+
 /// Bit-mask of opaque (alpha > threshold) pixels for an image.
 /// A simple row-major flat vector where `x + y * width` indexes the pixel.
 #[derive(Clone)]
@@ -20,10 +24,13 @@ impl AlphaMask {
         self.pixels[(x + y * self.width) as usize]
     }
 
-    /// Build an alpha mask from a loaded Bevy `Image`.
-    pub fn from_image(img: &Image) -> Self {
-        let width = img.texture_descriptor.size.width;
-        let height = img.texture_descriptor.size.height;
+    /// Build an alpha mask from a loaded Bevy `Image`, limited to `rect` for
+    /// sprites packed into an atlas.
+    pub fn from_image(img: &Image, rect: Option<URect>) -> Self {
+        let image_width = img.texture_descriptor.size.width;
+        let rect = rect.unwrap_or(URect::new(0, 0, image_width, img.texture_descriptor.size.height));
+        let width = rect.width();
+        let height = rect.height();
         let mut pixels = Vec::with_capacity((width * height) as usize);
 
         let Some(bytes) = img.data.as_ref() else {
@@ -34,10 +41,13 @@ impl AlphaMask {
 
         // Image data is stored in RGBA8 sRGB format by default.
         // Every 4 bytes correspond to RGBA channels.
-        for chunk in bytes.chunks_exact(4) {
-            // Alpha channel is at index 3
-            let alpha = chunk[3];
-            pixels.push(alpha > 127); // treat >50% opacity as solid
+        for y in rect.min.y..rect.max.y {
+            let row = (y * image_width + rect.min.x) as usize * 4;
+            for chunk in bytes[row..row + width as usize * 4].chunks_exact(4) {
+                // Alpha channel is at index 3
+                let alpha = chunk[3];
+                pixels.push(alpha > 127); // treat >50% opacity as solid
+            }
         }
 
         Self {
@@ -48,6 +58,14 @@ impl AlphaMask {
     }
 }
 
-/// Global cache of alpha masks for all loaded textures we care about.
+/// Global cache of alpha masks for all loaded sprites we care about.
 #[derive(Resource, Default)]
-pub struct AlphaMasks(pub HashMap<Handle<Image>, AlphaMask>); 
\ No newline at end of file
+pub struct AlphaMasks(pub HashMap<ThemeSprite, AlphaMask>);
+
+impl AlphaMasks {
+    /// Drop the masks of sprites outside `theme`, releasing their images along
+    /// with the cache keys.
+    pub fn retain_theme(&mut self, theme: &Theme) {
+        self.0.retain(|sprite, _| sprite.belongs_to(theme));
+    }
+}
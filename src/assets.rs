@@ -1,7 +1,398 @@
 use bevy::prelude::*;
 use bevy::audio::AudioSource;
-use crate::theme::Theme;
-use bevy::asset::UntypedHandle;
+use std::collections::HashMap;
+
+use crate::alpha_masks::AlphaMasks;
+use crate::atlas;
+use crate::theme::{ParticleShape, Theme, ThemeId, ThemeOrigin, ThemeRegistry};
+use bevy::asset::io::embedded::EmbeddedAssetRegistry;
+use bevy::asset::{AssetLoadFailedEvent, RecursiveDependencyLoadState, UntypedHandle};
+use bevy::image::ImageSampler;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::math::URect;
+use crate::localization::{Language, Localization};
+
+// -----------------------------------------------------------------------------
+//  Theme sprites (stand-alone images or atlas rects)
+// -----------------------------------------------------------------------------
+
+/// A theme sprite: either a whole image or, with the `atlas` feature, a rect
+/// on a packed atlas page.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ThemeSprite {
+    pub image: Handle<Image>,
+    /// Region of `image` holding the sprite; `None` uses the whole image.
+    pub rect: Option<URect>,
+}
+
+impl ThemeSprite {
+    /// Sprite component drawing this sprite.
+    pub fn sprite(&self) -> Sprite {
+        Sprite {
+            image: self.image.clone(),
+            rect: self.rect.map(|rect| rect.as_rect()),
+            ..default()
+        }
+    }
+
+    /// Unscaled size in pixels, `None` while a whole image is still loading.
+    pub fn size(&self, images: &Assets<Image>) -> Option<Vec2> {
+        match self.rect {
+            Some(rect) => Some(rect.size().as_vec2()),
+            None => images.get(&self.image).map(Image::size_f32),
+        }
+    }
+
+    /// Load the image at `path`, or its atlas rect when it was packed.
+    pub fn load(asset_server: &AssetServer, path: String) -> Self {
+        match atlas::lookup(&path) {
+            Some((page, rect)) => Self {
+                image: asset_server.load(format!("embedded://{}", page.path)),
+                rect: Some(rect),
+            },
+            None => Self::from(asset_server.load::<Image>(path)),
+        }
+    }
+
+    /// Whether this sprite was loaded from `theme`'s image directory, either
+    /// directly or through one of its atlas pages.
+    pub fn belongs_to(&self, theme: &Theme) -> bool {
+        let Some(path) = self.image.path() else {
+            return false;
+        };
+        let root = format!("{}/", theme.image_root());
+        let path = path.to_string();
+        match path.strip_prefix("embedded://") {
+            Some(page) => atlas::page_covers(page, &root),
+            None => path.starts_with(&root),
+        }
+    }
+}
+
+impl From<Handle<Image>> for ThemeSprite {
+    fn from(image: Handle<Image>) -> Self {
+        Self { image, rect: None }
+    }
+}
+
+/// Make the atlas pages packed by `build.rs` loadable from
+/// `embedded://atlases/…`. Runs before the sprite loaders at `PreStartup`.
+#[allow(clippy::needless_pass_by_value)]
+pub fn register_atlas_pages(registry: Res<EmbeddedAssetRegistry>) {
+    for page in atlas::ATLAS_PAGES {
+        registry.insert_asset(page.path.into(), std::path::Path::new(page.path), page.bytes);
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  Placeholder sprite (stand-in for sprites that failed to load)
+// -----------------------------------------------------------------------------
+
+/// Size of the placeholder sprite in pixels.
+const PLACEHOLDER_SIZE: UVec2 = UVec2::new(64, 32);
+
+/// Magenta ellipse drawn in place of enemy fish, background fish and plants
+/// whose image failed to load, so the gap is obvious but the game goes on.
+#[derive(Resource)]
+pub struct PlaceholderSprite(pub Handle<Image>);
+
+/// Create the [`PlaceholderSprite`] image. Runs at `PreStartup`.
+pub fn create_placeholder_sprite(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let UVec2 { x: width, y: height } = PLACEHOLDER_SIZE;
+    let mut data = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            // Inside the ellipse when the normalised distance is at most 1.
+            let dx = (x as f32 + 0.5) / width as f32 * 2.0 - 1.0;
+            let dy = (y as f32 + 0.5) / height as f32 * 2.0 - 1.0;
+            let alpha = if dx * dx + dy * dy <= 1.0 { 255 } else { 0 };
+            data.extend_from_slice(&[255, 0, 255, alpha]);
+        }
+    }
+
+    let image = Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        // Keep the pixels on the CPU too for the collision alpha masks.
+        RenderAssetUsages::default(),
+    );
+    commands.insert_resource(PlaceholderSprite(images.add(image)));
+}
+
+/// Swap theme sprites whose image failed to load for the
+/// [`PlaceholderSprite`]. Playable fish have no stand-in; the load error
+/// screen reports them instead.
+#[allow(clippy::needless_pass_by_value)]
+pub fn substitute_failed_sprites_system(
+    mut failures: EventReader<AssetLoadFailedEvent<Image>>,
+    placeholder: Res<PlaceholderSprite>,
+    mut enemy: Option<ResMut<EnemyFishAssets>>,
+    mut obscura: Option<ResMut<ObscuraAssets>>,
+    mut flora: Option<ResMut<FloraAssets>>,
+) {
+    for failure in failures.read() {
+        let mut replaced = 0;
+        for images in [
+            enemy.as_mut().map(|assets| &mut assets.images),
+            obscura.as_mut().map(|assets| &mut assets.images),
+            flora.as_mut().map(|assets| &mut assets.images),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for sprite in images.iter_mut().filter(|sprite| sprite.image.id() == failure.id) {
+                *sprite = ThemeSprite::from(placeholder.0.clone());
+                replaced += 1;
+            }
+        }
+
+        if replaced > 0 {
+            warn!("{} failed to load, drawing {replaced} sprite(s) as a placeholder", failure.path);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  Effect sprites (generated)
+// -----------------------------------------------------------------------------
+
+/// Diameter of the generated round sprites in pixels.
+const BUBBLE_SIZE: u32 = 32;
+
+/// Size of the generated crayon stroke in pixels.
+const STROKE_SIZE: UVec2 = UVec2::new(48, 16);
+
+/// Size of the generated light shaft in pixels.
+const RAY_SIZE: UVec2 = UVec2::new(32, 128);
+
+/// Sprites for particles and feedback effects, generated rather than themed.
+/// White so effects can tint them.
+#[derive(Resource)]
+pub struct EffectSprites {
+    /// Bubble: a bright rim around a faint fill.
+    pub bubble: Handle<Image>,
+    /// Soft-edged dot for plankton specks.
+    pub speck: Handle<Image>,
+    /// Short, slightly ragged crayon stroke.
+    pub stroke: Handle<Image>,
+    /// Light shaft: soft sides, fading out towards the bottom.
+    pub ray: Handle<Image>,
+}
+
+/// White image of `size` pixels whose alpha (0‒1) is `alpha(x, y)` for
+/// coordinates normalised to -1‒1 across the image.
+fn generated_image(size: UVec2, alpha: impl Fn(f32, f32) -> f32) -> Image {
+    let mut data = Vec::with_capacity((size.x * size.y * 4) as usize);
+    for y in 0..size.y {
+        for x in 0..size.x {
+            let nx = (x as f32 + 0.5) / size.x as f32 * 2.0 - 1.0;
+            let ny = (y as f32 + 0.5) / size.y as f32 * 2.0 - 1.0;
+            let a = alpha(nx, ny).clamp(0.0, 1.0);
+            data.extend_from_slice(&[255, 255, 255, (a * 255.0) as u8]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Create the [`EffectSprites`] images. Runs at `PreStartup`.
+pub fn create_effect_sprites(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let round = UVec2::splat(BUBBLE_SIZE);
+    let bubble = generated_image(round, |x, y| {
+        // Normalised distance from the centre (1 at the rim).
+        let d = (x * x + y * y).sqrt();
+        if d > 1.0 {
+            0.0
+        } else if d > 0.8 {
+            1.0
+        } else {
+            0.25
+        }
+    });
+    let speck = generated_image(round, |x, y| 1.0 - (x * x + y * y).sqrt());
+    let stroke = generated_image(STROKE_SIZE, |x, y| {
+        // Rounded ends, and a wavy edge like wax dragged across paper.
+        let edge = 0.75 + 0.2 * (x * 9.0).sin() * (x * 4.0 + 1.0).cos();
+        let inside = x * x * x * x + (y / edge) * (y / edge) <= 1.0;
+        if inside {
+            0.9
+        } else {
+            0.0
+        }
+    });
+
+    // Image rows run top to bottom, so y = -1 is the top edge.
+    let ray = generated_image(RAY_SIZE, |x, y| {
+        let across = 1.0 - x.abs();
+        across * across * (1.0 - y) / 2.0
+    });
+
+    commands.insert_resource(EffectSprites {
+        bubble: images.add(bubble),
+        speck: images.add(speck),
+        stroke: images.add(stroke),
+        ray: images.add(ray),
+    });
+}
+
+// -----------------------------------------------------------------------------
+//  Hazard sprites (themed, or generated in the theme's colours)
+// -----------------------------------------------------------------------------
+
+/// Height of the generated hazard sprites in pixels. Pixel-art themes get a
+/// quarter of it, drawn without filtering.
+const HAZARD_SIZE: u32 = 64;
+
+/// Sprites of the environmental hazards. A theme can draw its own as
+/// `images/hazards/{jellyfish,hook,mine}.png` (the hook's line is tied to the
+/// top centre); the rest are generated in the theme's hazard colours.
+#[derive(Resource)]
+pub struct HazardSprites {
+    pub jellyfish: ThemeSprite,
+    pub hook: ThemeSprite,
+    pub mine: ThemeSprite,
+}
+
+/// Like [`generated_image`], but in `color` and kept on the CPU too for the
+/// collision alpha masks.
+fn generated_hazard(
+    size: UVec2,
+    color: (f32, f32, f32),
+    pixelated: bool,
+    alpha: impl Fn(f32, f32) -> f32,
+) -> Image {
+    let size = if pixelated { (size / 4).max(UVec2::ONE) } else { size };
+    let mut image = generated_image(size, alpha);
+    let (r, g, b) = color;
+    let rgb = [r, g, b].map(|c| (c.clamp(0.0, 1.0) * 255.0) as u8);
+    if let Some(data) = image.data.as_mut() {
+        for pixel in data.chunks_exact_mut(4) {
+            pixel[..3].copy_from_slice(&rgb);
+        }
+    }
+    image.asset_usage = RenderAssetUsages::default();
+    if pixelated {
+        image.sampler = ImageSampler::nearest();
+    }
+    image
+}
+
+/// The theme's `hazards/<name>.png` if it has one, otherwise a generated
+/// sprite.
+fn hazard_sprite(
+    themed: &[String],
+    name: &str,
+    asset_server: &AssetServer,
+    images: &mut Assets<Image>,
+    generate: impl FnOnce() -> Image,
+) -> ThemeSprite {
+    let file = format!("/{name}.png");
+    match themed.iter().find(|path| path.ends_with(&file)) {
+        Some(path) => ThemeSprite::load(asset_server, path.clone()),
+        None => ThemeSprite::from(images.add(generate())),
+    }
+}
+
+/// Load or generate the [`HazardSprites`] for the selected theme. Runs at
+/// `PreStartup` and again whenever a theme is picked.
+#[allow(clippy::needless_pass_by_value)]
+pub fn load_hazard_sprites(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    theme: Res<Theme>,
+) {
+    let themed = theme_image_paths(&theme, "hazards");
+    let colors = theme.hazards;
+    let pixelated = matches!(theme.particles.shape, ParticleShape::Pixel);
+    let square = UVec2::splat(HAZARD_SIZE);
+
+    let jellyfish = hazard_sprite(&themed, "jellyfish", &asset_server, &mut images, || {
+        generated_hazard(square, colors.jellyfish, pixelated, |x, y| {
+            // Dome over the top half, its rim brighter than the rest.
+            let bell = (x / 0.9).powi(2) + (y / 0.85).powi(2);
+            if y <= 0.0 {
+                return if bell > 1.0 {
+                    0.0
+                } else if bell > 0.7 {
+                    1.0
+                } else {
+                    0.75
+                };
+            }
+            // Four wavy tentacles trailing below, thinning and fading.
+            let tentacle = [-0.6f32, -0.2, 0.2, 0.6].iter().any(|&cx| {
+                let centre = cx + 0.1 * (y * 7.0 + cx * 4.0).sin();
+                (x - centre).abs() < 0.08 * (1.0 - y * 0.5)
+            });
+            if tentacle {
+                0.85 * (1.0 - y * 0.6)
+            } else {
+                0.0
+            }
+        })
+    });
+
+    let hook = hazard_sprite(&themed, "hook", &asset_server, &mut images, || {
+        generated_hazard(UVec2::new(HAZARD_SIZE / 2, HAZARD_SIZE), colors.hook, pixelated, |x, y| {
+            // In height units: the image is half as wide as it is tall.
+            let u = x * 0.5;
+            let half_width = 0.05;
+            // Eye at the top centre where the line is tied, the shank down
+            // the middle, the bend and the point rising on the left.
+            let eye = (u.hypot(y + 0.84) - 0.09).abs() < 0.035;
+            let shank = u.abs() < half_width && (-0.75..=0.45).contains(&y);
+            let bend = y >= 0.45 && ((u + 0.2).hypot(y - 0.45) - 0.2).abs() < half_width;
+            let taper = 0.3 + 0.7 * ((y - 0.05) / 0.4).clamp(0.0, 1.0);
+            let point = (u + 0.4).abs() < half_width * taper && (0.05..=0.45).contains(&y);
+            if eye || shank || bend || point {
+                1.0
+            } else {
+                0.0
+            }
+        })
+    });
+
+    let mine = hazard_sprite(&themed, "mine", &asset_server, &mut images, || {
+        generated_hazard(square, colors.mine, pixelated, |x, y| {
+            const CORE: f32 = 0.62;
+            const REACH: f32 = 0.95;
+            let d = x.hypot(y);
+            if d <= CORE {
+                return 1.0;
+            }
+            // Eight horns, narrowing towards their tips.
+            let step = std::f32::consts::TAU / 8.0;
+            let angle = y.atan2(x);
+            let off = ((angle / step).round() * step - angle).abs() * d;
+            let width = 0.02 + 0.09 * (REACH - d) / (REACH - CORE);
+            if d <= REACH && off < width {
+                1.0
+            } else {
+                0.0
+            }
+        })
+    });
+
+    commands.insert_resource(HazardSprites { jellyfish, hook, mine });
+}
 
 // -----------------------------------------------------------------------------
 //  Playable fish textures (the three options shown in the picker)
@@ -34,46 +425,27 @@ pub fn load_playable_fish_textures(mut commands: Commands, asset_server: Res<Ass
 /// Collection of textures found under `images/fish/*.png`.
 #[derive(Resource, Default, Clone)]
 pub struct EnemyFishAssets {
-    pub images: Vec<Handle<Image>>, // arbitrary number of PNGs
+    pub images: Vec<ThemeSprite>, // arbitrary number of PNGs
+    /// Whether the fish in `images` at the same index is of a glowing
+    /// species (see [`crate::theme::ThemeLighting::glowing`]).
+    pub glowing: Vec<bool>,
 }
 
 /// Load all PNGs in `assets/images/fish` into an [`EnemyFishAssets`] resource (PreStartup).
 pub fn load_enemy_fish_assets(mut commands: Commands, asset_server: Res<AssetServer>, theme: Res<Theme>) {
-    #[cfg(target_arch = "wasm32")]
-    {
-        // Embedded asset list generated in build.rs for wasm.
-        // include!(concat!(env!("OUT_DIR"), "/enemy_fish_asset_list.rs"));
-        let prefix = format!("images/{}/", theme.prefix());
-        let handles = ENEMY_FISH_ASSET_PATHS
-            .iter()
-            .filter(|p| p.starts_with(&prefix))
-            .map(|path| asset_server.load(*path))
-            .collect();
-        commands.insert_resource(EnemyFishAssets { images: handles });
-        return;
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        use std::ffi::OsStr;
-        use std::fs;
-
-        let mut handles = Vec::new();
-
-        if let Ok(entries) = fs::read_dir(theme.assets_dir("fish")) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.extension() == Some(OsStr::new("png")) {
-                    if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
-                        let rel_path = theme.path(&format!("fish/{file_name}"));
-                        handles.push(asset_server.load(rel_path));
-                    }
-                }
-            }
-        }
-
-        commands.insert_resource(EnemyFishAssets { images: handles });
-    }
+    let paths = theme_image_paths(&theme, "fish");
+    let glowing = paths
+        .iter()
+        .map(|path| {
+            let file_name = path.rsplit('/').next().unwrap_or(path);
+            theme.lighting.glowing.iter().any(|name| name == file_name)
+        })
+        .collect();
+    let handles = paths
+        .into_iter()
+        .map(|path| ThemeSprite::load(&asset_server, path))
+        .collect();
+    commands.insert_resource(EnemyFishAssets { images: handles, glowing });
 }
 
 // -----------------------------------------------------------------------------
@@ -87,7 +459,7 @@ pub fn load_enemy_fish_assets(mut commands: Commands, asset_server: Res<AssetSer
 /// visible.
 #[derive(Resource, Default, Clone)]
 pub struct ObscuraAssets {
-    pub images: Vec<Handle<Image>>, // arbitrary number of PNGs
+    pub images: Vec<ThemeSprite>, // arbitrary number of PNGs
 }
 
 /// Load **all** PNG files located in `assets/images/obscura/` and store their
@@ -99,39 +471,11 @@ pub struct ObscuraAssets {
 include!(concat!(env!("OUT_DIR"), "/asset_list.rs"));
 
 pub fn load_obscura_assets(mut commands: Commands, asset_server: Res<AssetServer>, theme: Res<Theme>) {
-    #[cfg(target_arch = "wasm32")]
-    {
-        let prefix = format!("images/{}/", theme.prefix());
-        let handles = OBSCURA_ASSET_PATHS
-            .iter()
-            .filter(|p| p.starts_with(&prefix))
-            .map(|path| asset_server.load(*path))
-            .collect();
-        commands.insert_resource(ObscuraAssets { images: handles });
-        return;
-    }
-
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        use std::ffi::OsStr;
-        use std::fs;
-
-        let mut handles = Vec::new();
-
-        if let Ok(entries) = fs::read_dir(theme.assets_dir("obscura")) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.extension() == Some(OsStr::new("png")) {
-                    if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
-                        let rel_path = theme.path(&format!("obscura/{file_name}"));
-                        handles.push(asset_server.load(rel_path));
-                    }
-                }
-            }
-        }
-
-        commands.insert_resource(ObscuraAssets { images: handles });
-    }
+    let handles = theme_image_paths(&theme, "obscura")
+        .into_iter()
+        .map(|path| ThemeSprite::load(&asset_server, path))
+        .collect();
+    commands.insert_resource(ObscuraAssets { images: handles });
 }
 
 // -----------------------------------------------------------------------------
@@ -141,22 +485,50 @@ pub fn load_obscura_assets(mut commands: Commands, asset_server: Res<AssetServer
 /// Collection of textures located under `images/flora/*.png`.
 #[derive(Resource, Default, Clone)]
 pub struct FloraAssets {
-    pub images: Vec<Handle<Image>>, // arbitrary number of PNGs
+    pub images: Vec<ThemeSprite>, // arbitrary number of PNGs
 }
 
 /// Load **all** PNG files located in `assets/images/flora/` and store their
 /// handles in a [`FloraAssets`] resource. Runs during `PreStartup`.
 pub fn load_flora_assets(mut commands: Commands, asset_server: Res<AssetServer>, theme: Res<Theme>) {
+    let handles = theme_image_paths(&theme, "flora")
+        .into_iter()
+        .map(|path| ThemeSprite::load(&asset_server, path))
+        .collect();
+    commands.insert_resource(FloraAssets { images: handles });
+}
+
+/// Asset paths of every PNG in one of the theme's image sub-directories
+/// (`fish`, `obscura`, `flora` or `hazards`).
+///
+/// Theme packs list their files when they are registered; built-in themes use
+/// the lists generated by `build.rs` on wasm and read the directory natively.
+fn theme_image_paths(theme: &Theme, subdir: &str) -> Vec<String> {
+    if let ThemeOrigin::Pack { files, .. } = &theme.origin {
+        let prefix = format!("images/{subdir}/");
+        return files
+            .iter()
+            .filter_map(|file| file.strip_prefix(&prefix))
+            .filter(|name| name.ends_with(".png") && !name.contains('/'))
+            .map(|name| theme.path(&format!("{subdir}/{name}")))
+            .collect();
+    }
+
     #[cfg(target_arch = "wasm32")]
     {
-        let prefix = format!("images/{}/", theme.prefix());
-        let handles = FLORA_ASSET_PATHS
+        let generated: &[&str] = match subdir {
+            "fish" => ENEMY_FISH_ASSET_PATHS,
+            "obscura" => OBSCURA_ASSET_PATHS,
+            "flora" => FLORA_ASSET_PATHS,
+            "hazards" => HAZARD_ASSET_PATHS,
+            _ => &[],
+        };
+        let prefix = format!("{}/{subdir}/", theme.image_root());
+        generated
             .iter()
             .filter(|p| p.starts_with(&prefix))
-            .map(|path| asset_server.load(*path))
-            .collect();
-        commands.insert_resource(FloraAssets { images: handles });
-        return;
+            .map(|p| p.to_string())
+            .collect()
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -164,21 +536,20 @@ pub fn load_flora_assets(mut commands: Commands, asset_server: Res<AssetServer>,
         use std::ffi::OsStr;
         use std::fs;
 
-        let mut handles = Vec::new();
+        let mut paths = Vec::new();
 
-        if let Ok(entries) = fs::read_dir(theme.assets_dir("flora")) {
+        if let Ok(entries) = fs::read_dir(theme.assets_dir(subdir)) {
             for entry in entries.flatten() {
                 let path = entry.path();
                 if path.extension() == Some(OsStr::new("png")) {
                     if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
-                        let rel_path = theme.path(&format!("flora/{file_name}"));
-                        handles.push(asset_server.load(rel_path));
+                        paths.push(theme.path(&format!("{subdir}/{file_name}")));
                     }
                 }
             }
         }
 
-        commands.insert_resource(FloraAssets { images: handles });
+        paths
     }
 }
 
@@ -186,12 +557,17 @@ pub fn load_flora_assets(mut commands: Commands, asset_server: Res<AssetServer>,
 #[cfg(target_arch = "wasm32")]
 include!(concat!(env!("OUT_DIR"), "/enemy_fish_asset_list.rs"));
 
-/// Preloaded audio handles for the three theme-select sound effects.
-#[derive(Resource)]
+/// Preloaded audio handles for every theme's select sound effect.
+#[derive(Resource, Default)]
 pub struct ThemeSelectAudio {
-    pub crayon: Handle<AudioSource>,
-    pub chibi: Handle<AudioSource>,
-    pub retro: Handle<AudioSource>,
+    pub clips: HashMap<ThemeId, Handle<AudioSource>>,
+}
+
+impl ThemeSelectAudio {
+    /// Select clip belonging to `theme`.
+    pub fn for_theme(&self, theme: ThemeId) -> Option<Handle<AudioSource>> {
+        self.clips.get(&theme).cloned()
+    }
 }
 
 /// Load all theme-select audio files once at application start and store them
@@ -200,53 +576,217 @@ pub struct ThemeSelectAudio {
 pub fn load_theme_select_audio_assets(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    registry: Res<ThemeRegistry>,
 ) {
     let audio = ThemeSelectAudio {
-        crayon: asset_server.load("sounds/crayon_sketch.mp3"),
-        chibi: asset_server.load("sounds/cute_and_chibi.mp3"),
-        retro: asset_server.load("sounds/retro_pixelated.mp3"),
+        clips: registry
+            .iter()
+            .map(|theme| (theme.id, asset_server.load(theme.select_sound.clone())))
+            .collect(),
     };
 
     commands.insert_resource(audio);
 }
 
 // -----------------------------------------------------------------------------
-//  Global preloading for *all* assets across every theme
+//  Per-theme audio bank (gameplay SFX, UI sounds and background music)
 // -----------------------------------------------------------------------------
 
-/// Collection of untyped asset handles returned from [`AssetServer::load_folder`]
-/// when preloading every asset at application start.
+/// Sound effects and the looping music track for the currently selected theme,
+/// loaded from `assets/sounds/<theme>/`.
+#[derive(Resource, Clone)]
+pub struct ThemeAudioBank {
+    /// Which theme these clips belong to.
+    pub theme: ThemeId,
+    pub eat_small: Handle<AudioSource>,
+    pub eat_medium: Handle<AudioSource>,
+    pub eat_large: Handle<AudioSource>,
+    pub death: Handle<AudioSource>,
+    pub level_up: Handle<AudioSource>,
+    pub ui_click: Handle<AudioSource>,
+    pub ui_hover: Handle<AudioSource>,
+    pub music: Handle<AudioSource>,
+}
+
+/// Load the audio bank for the current theme into a [`ThemeAudioBank`]
+/// resource. Runs at `PreStartup` and again whenever the theme changes.
+#[allow(clippy::needless_pass_by_value)]
+pub fn load_theme_audio_bank(mut commands: Commands, asset_server: Res<AssetServer>, theme: Res<Theme>) {
+    let bank = ThemeAudioBank {
+        theme: theme.id,
+        eat_small: asset_server.load(theme.sound_path("eat_small.wav")),
+        eat_medium: asset_server.load(theme.sound_path("eat_medium.wav")),
+        eat_large: asset_server.load(theme.sound_path("eat_large.wav")),
+        death: asset_server.load(theme.sound_path("death.wav")),
+        level_up: asset_server.load(theme.sound_path("level_up.wav")),
+        ui_click: asset_server.load(theme.sound_path("ui_click.wav")),
+        ui_hover: asset_server.load(theme.sound_path("ui_hover.wav")),
+        music: asset_server.load(theme.sound_path("music.wav")),
+    };
+
+    commands.insert_resource(bank);
+}
+
+// -----------------------------------------------------------------------------
+//  Localisation string tables
+// -----------------------------------------------------------------------------
+
+/// Load the string table of every [`Language`] into the [`Localization`]
+/// resource so switching language in the settings menu is instant.
+#[allow(clippy::needless_pass_by_value)]
+pub fn load_string_tables(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let tables = Language::ALL
+        .iter()
+        .map(|language| (*language, asset_server.load(language.table_path())))
+        .collect();
+
+    commands.insert_resource(Localization::new(tables));
+}
+
+// -----------------------------------------------------------------------------
+//  Theme residency (selected theme up front, the others in the background)
+// -----------------------------------------------------------------------------
+
+/// How many themes may stay loaded at once.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeResidency {
+    /// Fetch every theme in the background and keep it, so switching themes in
+    /// the picker is instant.
+    All,
+    /// Keep only the selected theme; the previous one is released on a switch.
+    SelectedOnly,
+}
+
+/// Browsers reporting at most this much device memory (GiB) only keep the
+/// selected theme.
+#[cfg(target_arch = "wasm32")]
+const LOW_MEMORY_GIB: f64 = 4.0;
+
+impl ThemeResidency {
+    /// Pick the residency for this device. Native builds keep every theme
+    /// unless `FISHY_LOW_MEMORY` is set; browsers keep only the selected theme
+    /// on touch devices or when `navigator.deviceMemory` is low.
+    pub fn detect() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let low_memory = std::env::var_os("FISHY_LOW_MEMORY").is_some();
+
+        #[cfg(target_arch = "wasm32")]
+        let low_memory = web_sys::window().is_some_and(|window| {
+            let navigator = window.navigator();
+            // Only Chromium exposes `deviceMemory`, so it is read dynamically.
+            let device_memory = js_sys::Reflect::get(&navigator, &"deviceMemory".into())
+                .ok()
+                .and_then(|value| value.as_f64());
+            navigator.max_touch_points() > 0 || device_memory.is_some_and(|gib| gib <= LOW_MEMORY_GIB)
+        });
+
+        if low_memory {
+            ThemeResidency::SelectedOnly
+        } else {
+            ThemeResidency::All
+        }
+    }
+}
+
+/// Untyped handles returned from [`AssetServer::load_folder`] that keep whole
+/// theme directories (and the shared fonts) resident.
+///
+/// The loading splash waits for [`PrefetchedAssets::startup_handles`]; the
+/// other themes stream in afterwards, see
+/// [`prefetch_background_themes_system`].
 #[derive(Resource, Default)]
 pub struct PrefetchedAssets {
-    pub handles: Vec<UntypedHandle>,
+    /// Folders every theme uses (`fonts/`).
+    pub shared: Vec<UntypedHandle>,
+    /// Image directories of the resident themes.
+    pub themes: HashMap<ThemeId, Vec<UntypedHandle>>,
 }
 
-/// Preload **all** assets found under each theme directory as well as common
-/// folders such as `sounds/` and `fonts/`. This drastically reduces stutter
-/// later when the player switches themes because the assets are already
-/// resident in memory.
-///
-/// The returned handles are stored in a [`PrefetchedAssets`] resource so we can
-/// later check their [`LoadState`](bevy::asset::LoadState) inside the loading
-/// splash screen.
+impl PrefetchedAssets {
+    /// Handles needed before leaving the loading splash: the shared folders
+    /// and the selected theme's directory.
+    pub fn startup_handles(&self, theme: ThemeId) -> impl Iterator<Item = &UntypedHandle> {
+        self.shared.iter().chain(self.themes.get(&theme).into_iter().flatten())
+    }
+
+    /// Whether every requested folder has finished loading (or failed to).
+    fn is_settled(&self, asset_server: &AssetServer) -> bool {
+        self.shared.iter().chain(self.themes.values().flatten()).all(|handle| {
+            matches!(
+                asset_server.get_recursive_dependency_load_state(handle.id()),
+                Some(RecursiveDependencyLoadState::Loaded | RecursiveDependencyLoadState::Failed(_))
+            )
+        })
+    }
+}
+
+/// Start loading everything under one theme's image directory. Sprites packed
+/// into atlases are embedded, so only the playable fish and score icon remain
+/// to be fetched for those themes.
+fn load_theme_folder(asset_server: &AssetServer, theme: &Theme) -> Vec<UntypedHandle> {
+    let root = theme.image_root();
+    if atlas::covers(&format!("{root}/")) {
+        vec![
+            asset_server.load_folder(format!("{root}/playable_fish")).untyped(),
+            asset_server.load::<Image>(theme.path("score_icon.png")).untyped(),
+        ]
+    } else {
+        vec![asset_server.load_folder(root).untyped()]
+    }
+}
+
+/// Preload the selected theme's directory and the shared fonts at
+/// `PreStartup`. The handles are stored in a [`PrefetchedAssets`] resource so
+/// the loading splash can check their [`LoadState`](bevy::asset::LoadState).
 #[allow(clippy::needless_pass_by_value)]
-pub fn preload_all_assets(
-    mut commands: Commands,
+pub fn preload_selected_theme(mut commands: Commands, asset_server: Res<AssetServer>, theme: Res<Theme>) {
+    let mut prefetched = PrefetchedAssets {
+        shared: vec![asset_server.load_folder("fonts").untyped()],
+        ..default()
+    };
+    prefetched.themes.insert(theme.id, load_theme_folder(&asset_server, &theme));
+
+    commands.insert_resource(prefetched);
+}
+
+/// Fetch the remaining themes one at a time once everything requested so far
+/// has loaded, so background themes never compete with the selected one.
+#[allow(clippy::needless_pass_by_value)]
+pub fn prefetch_background_themes_system(
     asset_server: Res<AssetServer>,
+    registry: Res<ThemeRegistry>,
+    residency: Res<ThemeResidency>,
+    mut prefetched: ResMut<PrefetchedAssets>,
 ) {
-    let mut handles: Vec<UntypedHandle> = Vec::new();
-
-    // Preload every asset under each theme directory.
-    for theme in [Theme::Crayon, Theme::Chibi, Theme::Retro] {
-        let folder_handle = asset_server.load_folder(format!("images/{}", theme.prefix()));
-        handles.push(folder_handle.untyped());
+    if *residency != ThemeResidency::All || !prefetched.is_settled(&asset_server) {
+        return;
     }
+    let Some(next) = registry.iter().find(|theme| !prefetched.themes.contains_key(&theme.id)) else {
+        return;
+    };
 
-    // Preload common non-themed assets.
-    for folder in ["sounds", "fonts"] {
-        let folder_handle = asset_server.load_folder(folder);
-        handles.push(folder_handle.untyped());
-    }
+    debug!("prefetching theme {:?} in the background", next.key);
+    prefetched.themes.insert(next.id, load_theme_folder(&asset_server, next));
+}
 
-    commands.insert_resource(PrefetchedAssets { handles });
-} 
\ No newline at end of file
+/// Make sure the newly selected theme is resident and, on low memory devices,
+/// release every other theme's folders and cached alpha masks. Runs whenever
+/// the [`Theme`] resource changes.
+#[allow(clippy::needless_pass_by_value)]
+pub fn release_inactive_themes_system(
+    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    residency: Res<ThemeResidency>,
+    mut prefetched: ResMut<PrefetchedAssets>,
+    mut masks: ResMut<AlphaMasks>,
+) {
+    prefetched
+        .themes
+        .entry(theme.id)
+        .or_insert_with(|| load_theme_folder(&asset_server, &theme));
+
+    if *residency == ThemeResidency::SelectedOnly {
+        prefetched.themes.retain(|id, _| *id == theme.id);
+        masks.retain_theme(&theme);
+    }
+}
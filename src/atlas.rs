@@ -0,0 +1,55 @@
+//! Texture atlases packed by `build.rs` when the `atlas` feature is enabled.
+//!
+//! Each theme's `fish/`, `obscura/` and `flora/` sprites are packed into a few
+//! PNG pages that are embedded in the binary and served from the
+//! `embedded://atlases/` asset source, downscaled so many fit on a page.
+//! Without the feature [`ATLAS_PAGES`] is empty and every sprite is loaded as
+//! its own image.
+
+use bevy::math::URect;
+
+/// One embedded atlas page.
+pub struct AtlasPage {
+    /// Path inside the embedded asset source, e.g. `atlases/crayon_fish_0.png`.
+    pub path: &'static str,
+    pub bytes: &'static [u8],
+    pub sprites: &'static [AtlasSprite],
+}
+
+/// Where one original PNG ended up on its page (pixels, `max` exclusive).
+pub struct AtlasSprite {
+    /// Asset path of the original image, e.g. `images/crayon/fish/e_1.png`.
+    pub source: &'static str,
+    pub min: [u32; 2],
+    pub max: [u32; 2],
+}
+
+include!(concat!(env!("OUT_DIR"), "/atlas_index.rs"));
+
+/// Find the atlas page and rect replacing the image at `source`.
+pub fn lookup(source: &str) -> Option<(&'static AtlasPage, URect)> {
+    ATLAS_PAGES.iter().find_map(|page| {
+        page.sprites.iter().find(|sprite| sprite.source == source).map(|sprite| {
+            let rect = URect::new(sprite.min[0], sprite.min[1], sprite.max[0], sprite.max[1]);
+            (page, rect)
+        })
+    })
+}
+
+/// Whether any sprite under the asset path prefix `root` is packed.
+pub fn covers(root: &str) -> bool {
+    ATLAS_PAGES
+        .iter()
+        .flat_map(|page| page.sprites)
+        .any(|sprite| sprite.source.starts_with(root))
+}
+
+/// Whether the page at `path` (e.g. `atlases/crayon_fish_0.png`) holds any
+/// sprite from under the asset path prefix `root`.
+pub fn page_covers(path: &str, root: &str) -> bool {
+    ATLAS_PAGES
+        .iter()
+        .filter(|page| page.path == path)
+        .flat_map(|page| page.sprites)
+        .any(|sprite| sprite.source.starts_with(root))
+}
@@ -0,0 +1,152 @@
+//! Gameplay tuning: player movement, growth, enemy spawning and hazards.
+//!
+//! Shared with the `simulate` balancing tool (which includes this file by
+//! path), so it only depends on `bevy::math`, `rand` and `serde`. Parameter
+//! sets for the simulator are RON files with any subset of [`Balance`]'s
+//! fields.
+
+use bevy::math::Vec2;
+use bevy::prelude::Resource;
+use rand::Rng;
+use rand_distr::{Distribution, Exp1};
+use serde::{Deserialize, Serialize};
+
+/// World area every gameplay measurement is based on (the playfield's
+/// virtual size).
+pub const VIRTUAL_SIZE: Vec2 = Vec2::new(1920.0, 1080.0);
+
+/// Smallest on-screen size of the player fish, in world units.
+const MIN_PLAYER_SIZE: f32 = 64.0;
+
+/// Jellyfish rise speed at the peak of a pulse (world units / s).
+pub const JELLYFISH_RISE: f32 = 140.0;
+
+/// Hook: drop and reel speeds (world units / s), and seconds from the drop
+/// until it is reeled in.
+pub const HOOK_DROP_SPEED: f32 = 600.0;
+pub const HOOK_REEL_SPEED: f32 = 160.0;
+pub const HOOK_WAIT: f32 = 5.0;
+
+/// Mine: sink speed (world units / s) and seconds it stays armed before
+/// sinking away.
+pub const MINE_SINK_SPEED: f32 = 60.0;
+pub const MINE_LIFETIME: f32 = 20.0;
+
+/// Seconds after a sting before the player can be stung again.
+pub const STING_COOLDOWN: f32 = 1.2;
+
+/// Tuning values behind movement, growth and spawning.
+#[derive(Resource, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Balance {
+    /// Acceleration towards the pointer while it is held (world units / s²).
+    pub acceleration: f32,
+    /// Speed cap of the player fish (world units / s).
+    pub max_speed: f32,
+    /// Proportion of velocity lost per second.
+    pub water_resistance: f32,
+    /// Fraction of a swallowed fish's `Meat` the player gains.
+    pub growth_factor: f32,
+    /// Smallest transform scale of the player fish.
+    pub player_base_scale: f32,
+    /// Mean seconds between enemy spawns (intervals are exponential).
+    pub spawn_interval: f32,
+    /// Exponent applied to the uniform size sample; above 1 favours small fish.
+    pub spawn_size_bias: f32,
+    /// Smallest enemy area, relative to its sprite at `player_base_scale`.
+    pub min_enemy_area: f32,
+    /// Largest enemy area, relative to the virtual playfield.
+    pub max_enemy_area: f32,
+    /// Enemy speed at scale 1; smaller fish swim proportionally faster.
+    pub enemy_base_speed: f32,
+    pub enemy_min_speed: f32,
+    pub enemy_max_speed: f32,
+    /// Enemy speeds are scaled by a random factor within ±this.
+    pub enemy_speed_jitter: f32,
+    /// Share of spawns that are hazards (jellyfish, hooks, mines) instead of
+    /// fish.
+    pub hazard_chance: f32,
+    /// Seconds a hazard gives warning before it becomes dangerous.
+    pub hazard_telegraph: f32,
+    /// Fraction of the player's `Meat` a jellyfish sting takes.
+    pub sting_shrink: f32,
+}
+
+impl Default for Balance {
+    fn default() -> Self {
+        Self {
+            acceleration: 800.0,
+            max_speed: 500.0,
+            water_resistance: 1.0,
+            growth_factor: 0.25,
+            player_base_scale: 0.05,
+            spawn_interval: 1.0,
+            spawn_size_bias: 2.0,
+            min_enemy_area: 0.1,
+            max_enemy_area: 1.0 / 9.0,
+            enemy_base_speed: 25.0,
+            enemy_min_speed: 10.0,
+            enemy_max_speed: 150.0,
+            enemy_speed_jitter: 0.2,
+            hazard_chance: 0.08,
+            hazard_telegraph: 1.5,
+            sting_shrink: 0.2,
+        }
+    }
+}
+
+impl Balance {
+    /// Starting scale of a player fish whose sprite is `image_size` pixels:
+    /// comfortably visible and at least `player_base_scale`.
+    pub fn player_start_scale(&self, image_size: Vec2) -> f32 {
+        let min_size = Vec2::splat(MIN_PLAYER_SIZE).max(VIRTUAL_SIZE / 100.0);
+        let scale = min_size / image_size;
+        scale.x.max(scale.y).max(self.player_base_scale)
+    }
+
+    /// Accelerate `velocity` towards `direction` (normalised) for `dt` seconds,
+    /// capped at `max_speed`.
+    pub fn accelerate(&self, velocity: Vec2, direction: Vec2, dt: f32) -> Vec2 {
+        (velocity + direction * self.acceleration * dt).clamp_length_max(self.max_speed)
+    }
+
+    /// Apply `dt` seconds of water resistance, stopping very slow fish.
+    pub fn damp(&self, velocity: Vec2, dt: f32) -> Vec2 {
+        let velocity = velocity * (1.0 - self.water_resistance * dt).max(0.0);
+        if velocity.length_squared() < 0.01 {
+            Vec2::ZERO
+        } else {
+            velocity
+        }
+    }
+
+    /// `Meat` gained by swallowing a fish of `prey_meat` (rounded down).
+    pub fn meat_gain(&self, prey_meat: usize) -> usize {
+        (prey_meat as f32 * self.growth_factor) as usize
+    }
+
+    /// Seconds until the next enemy spawn.
+    pub fn next_spawn_delay(&self, rng: &mut impl Rng) -> f32 {
+        let sample: f32 = Exp1.sample(rng);
+        sample * self.spawn_interval
+    }
+
+    /// Random scale for an enemy drawn with a sprite of `sprite_area` px².
+    pub fn enemy_scale(&self, sprite_area: f32, rng: &mut impl Rng) -> f32 {
+        let min_area = sprite_area * self.player_base_scale * self.player_base_scale * self.min_enemy_area;
+        let max_area = VIRTUAL_SIZE.x * VIRTUAL_SIZE.y * self.max_enemy_area;
+
+        // Bias towards smaller fish: a uniform sample raised to a power
+        // clusters near 0 before being remapped into [min_area, max_area].
+        let t = rng.gen::<f32>().powf(self.spawn_size_bias);
+        let desired_area = min_area + t * (max_area - min_area);
+        (desired_area / sprite_area).sqrt()
+    }
+
+    /// Random horizontal speed for an enemy at `scale`; smaller is faster.
+    pub fn enemy_speed(&self, scale: f32, rng: &mut impl Rng) -> f32 {
+        let jitter = self.enemy_speed_jitter;
+        (self.enemy_base_speed / scale).clamp(self.enemy_min_speed, self.enemy_max_speed)
+            * rng.gen_range(1.0 - jitter..1.0 + jitter)
+    }
+}
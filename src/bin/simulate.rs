@@ -0,0 +1,643 @@
+//! Headless balancing simulator.
+//!
+//! Plays thousands of accelerated games without rendering, the player fish
+//! driven by a heuristic bot (shared with the attract mode), and reports
+//! survival time, final size and what killed the player. Spawning, movement
+//! and growth use the game's own [`Balance`] code, hazards included;
+//! parameter sets to compare are RON files overriding any of its fields.
+//!
+//! ```text
+//! cargo run --release --bin simulate -- [--runs N] [--bot greedy|cautious|random]...
+//!     [--params FILE.ron]... [--theme ID] [--seed N] [--max-time SECONDS] [--assets DIR]
+//! ```
+//!
+//! Fish collide as circles with the same area as their opaque pixels, an
+//! approximation of the game's pixel-perfect test. Hazards are circles too,
+//! follow simplified versions of their paths in `systems/hazards.rs` and show
+//! to the bots as fish too big to eat.
+
+#[path = "../balance.rs"]
+mod balance;
+#[path = "../bots.rs"]
+mod bots;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use bevy::math::Vec2;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+
+use balance::{
+    Balance, HOOK_DROP_SPEED, HOOK_REEL_SPEED, HOOK_WAIT, JELLYFISH_RISE, MINE_LIFETIME, MINE_SINK_SPEED, STING_COOLDOWN,
+    VIRTUAL_SIZE,
+};
+use bots::{make_bot, Bot, NearbyFish, Surroundings};
+
+/// Fixed simulation step (seconds), the game's frame time at 60 FPS.
+const DT: f32 = 1.0 / 60.0;
+
+/// Enemies are removed this far beyond the playfield edge, like in the game.
+const DESPAWN_MARGIN: f32 = 150.0;
+
+/// Alpha above which a pixel counts as solid, matching `AlphaMask::from_image`.
+const SOLID_ALPHA: u8 = 127;
+
+/// A jellyfish's mean rise speed over its pulses (world units / s); the game
+/// rises at [`JELLYFISH_RISE`] times a half-wave rectified sine.
+const JELLYFISH_MEAN_RISE: f32 = JELLYFISH_RISE / std::f32::consts::PI;
+
+/// Killer/player `Meat` ratios death causes are bucketed by.
+const RATIO_BUCKETS: [(f32, &str); 3] = [(1.5, "< 1.5x"), (3.0, "1.5-3x"), (f32::INFINITY, ">= 3x")];
+
+/// Bots run when no `--bot` is given.
+const BOTS: [&str; 3] = ["greedy", "cautious", "random"];
+
+/// The part of an `assets/themes.ron` entry the simulator needs.
+#[derive(Deserialize)]
+struct RegistryEntry {
+    id: String,
+    directory: String,
+}
+
+/// Size and opaque fraction of one sprite.
+struct Sprite {
+    name: String,
+    size: Vec2,
+    coverage: f32,
+}
+
+struct ThemeSprites {
+    players: Vec<Sprite>,
+    enemies: Vec<Sprite>,
+}
+
+struct Options {
+    runs: u32,
+    bots: Vec<String>,
+    params: Vec<PathBuf>,
+    theme: Option<String>,
+    seed: u64,
+    max_time: f32,
+    assets: PathBuf,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let sprites = match load_theme(&options.assets, options.theme.as_deref()) {
+        Ok(sprites) => sprites,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut param_sets = vec![("default".to_string(), Balance::default())];
+    if !options.params.is_empty() {
+        param_sets.clear();
+        for path in &options.params {
+            match fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|source| ron::from_str::<Balance>(&source).map_err(|e| e.to_string()))
+            {
+                Ok(balance) => param_sets.push((path.display().to_string(), balance)),
+                Err(e) => {
+                    eprintln!("{}: {e}", path.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    for (name, balance) in &param_sets {
+        for bot in &options.bots {
+            let outcomes: Vec<Outcome> = (0..options.runs)
+                .map(|run| {
+                    let seed = options.seed.wrapping_add(u64::from(run));
+                    let mut bot = make_bot(bot).expect("bot names are validated");
+                    let player = run as usize % sprites.players.len();
+                    Game::new(balance, &sprites, player, seed).play(bot.as_mut(), options.max_time)
+                })
+                .collect();
+            print_summary(name, bot, &outcomes, options.max_time);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        runs: 1000,
+        bots: Vec::new(),
+        params: Vec::new(),
+        theme: None,
+        seed: 0,
+        max_time: 300.0,
+        assets: PathBuf::from("assets"),
+    };
+
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{flag} needs a value"));
+        match flag.as_str() {
+            "--runs" => options.runs = value()?.parse().map_err(|e| format!("--runs: {e}"))?,
+            "--bot" => {
+                let bot = value()?;
+                if make_bot(&bot).is_none() {
+                    return Err(format!("unknown bot {bot:?} (available: {})", BOTS.join(", ")));
+                }
+                options.bots.push(bot);
+            }
+            "--params" => options.params.push(PathBuf::from(value()?)),
+            "--theme" => options.theme = Some(value()?),
+            "--seed" => options.seed = value()?.parse().map_err(|e| format!("--seed: {e}"))?,
+            "--max-time" => options.max_time = value()?.parse().map_err(|e| format!("--max-time: {e}"))?,
+            "--assets" => options.assets = PathBuf::from(value()?),
+            _ => return Err(format!("unknown argument {flag:?}")),
+        }
+    }
+    if options.bots.is_empty() {
+        options.bots = BOTS.iter().map(|bot| bot.to_string()).collect();
+    }
+    Ok(options)
+}
+
+// -----------------------------------------------------------------------------
+//  Sprites
+// -----------------------------------------------------------------------------
+
+/// Player and enemy sprite sizes of the theme `id` (default: the first one
+/// in `themes.ron`).
+fn load_theme(assets: &Path, id: Option<&str>) -> Result<ThemeSprites, String> {
+    let registry_path = assets.join("themes.ron");
+    let registry: Vec<RegistryEntry> = fs::read_to_string(&registry_path)
+        .map_err(|e| e.to_string())
+        .and_then(|source| ron::from_str(&source).map_err(|e| e.to_string()))
+        .map_err(|e| format!("{}: {e}", registry_path.display()))?;
+
+    let entry = match id {
+        Some(id) => registry.iter().find(|entry| entry.id == id),
+        None => registry.first(),
+    }
+    .ok_or_else(|| format!("theme {id:?} not found in {}", registry_path.display()))?;
+    let dir = assets.join("images").join(&entry.directory);
+
+    let players = ["fish_1.png", "fish_2.png", "fish_3.png"]
+        .iter()
+        .map(|file| load_sprite(&dir.join("playable_fish").join(file)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let enemies = list_pngs(&dir.join("fish"))
+        .iter()
+        .map(|file| load_sprite(&dir.join("fish").join(file)))
+        .collect::<Result<Vec<_>, _>>()?;
+    if enemies.is_empty() {
+        return Err(format!("no enemy fish sprites in {}", dir.join("fish").display()));
+    }
+
+    println!("theme {:?}: {} enemy sprites", entry.id, enemies.len());
+    Ok(ThemeSprites { players, enemies })
+}
+
+fn load_sprite(path: &Path) -> Result<Sprite, String> {
+    let image = image::open(path).map_err(|e| format!("{}: {e}", path.display()))?.to_rgba8();
+    let (width, height) = image.dimensions();
+    let solid = image.pixels().filter(|pixel| pixel[3] > SOLID_ALPHA).count();
+    Ok(Sprite {
+        name: path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default(),
+        size: Vec2::new(width as f32, height as f32),
+        coverage: solid as f32 / (width as usize * height as usize).max(1) as f32,
+    })
+}
+
+/// Names of the PNG files directly inside `dir`, sorted.
+fn list_pngs(dir: &Path) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_file())
+                .filter(|e| e.path().extension().is_some_and(|ext| ext == "png"))
+                .filter_map(|e| e.file_name().to_str().map(str::to_owned))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+// -----------------------------------------------------------------------------
+//  Simulation
+// -----------------------------------------------------------------------------
+
+struct Enemy {
+    sprite: usize,
+    position: Vec2,
+    velocity: Vec2,
+    meat: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum HazardKind {
+    Jellyfish,
+    Hook,
+    Mine,
+}
+
+impl HazardKind {
+    /// Collision radius, roughly the opaque area of the game's sprites.
+    fn radius(self) -> f32 {
+        match self {
+            Self::Jellyfish => 38.0,
+            Self::Hook => 28.0,
+            Self::Mine => 32.0,
+        }
+    }
+}
+
+struct Hazard {
+    kind: HazardKind,
+    position: Vec2,
+    /// Depth a hook drops to and a mine settles at.
+    rest_y: f32,
+    /// Seconds since it appeared.
+    age: f32,
+}
+
+/// A single game in progress.
+struct Game<'a> {
+    balance: &'a Balance,
+    sprites: &'a ThemeSprites,
+    rng: StdRng,
+    time: f32,
+    next_spawn: f32,
+    position: Vec2,
+    velocity: Vec2,
+    meat: usize,
+    /// Stings never shrink the player below this.
+    start_meat: usize,
+    /// Opaque fraction of the player's sprite.
+    coverage: f32,
+    enemies: Vec<Enemy>,
+    hazards: Vec<Hazard>,
+    /// Seconds until the player can be stung again.
+    sting_cooldown: f32,
+    eaten: u32,
+    stings: u32,
+    /// What the bot sees, refreshed every step.
+    nearby: Vec<NearbyFish>,
+}
+
+/// What killed the player.
+enum Death {
+    /// Eaten by the enemy drawn with `sprite`, `ratio` times its `Meat`.
+    Eaten { sprite: String, ratio: f32 },
+    Hooked,
+    Exploded,
+}
+
+/// How a game ended.
+struct Outcome {
+    time: f32,
+    final_meat: usize,
+    eaten: u32,
+    stings: u32,
+    /// `None` if the player survived until the time limit.
+    death: Option<Death>,
+}
+
+impl<'a> Game<'a> {
+    fn new(balance: &'a Balance, sprites: &'a ThemeSprites, player: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let sprite = &sprites.players[player];
+        let scale = balance.player_start_scale(sprite.size);
+        let next_spawn = balance.next_spawn_delay(&mut rng);
+        let meat = (sprite.size.x * sprite.size.y * scale * scale) as usize;
+        Self {
+            balance,
+            sprites,
+            rng,
+            time: 0.0,
+            next_spawn,
+            position: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            meat,
+            start_meat: meat,
+            coverage: sprite.coverage,
+            enemies: Vec::new(),
+            hazards: Vec::new(),
+            sting_cooldown: 0.0,
+            eaten: 0,
+            stings: 0,
+            nearby: Vec::new(),
+        }
+    }
+
+    fn radius(&self) -> f32 {
+        opaque_radius(self.meat, self.coverage)
+    }
+
+    fn enemy_radius(&self, enemy: &Enemy) -> f32 {
+        opaque_radius(enemy.meat, self.sprites.enemies[enemy.sprite].coverage)
+    }
+
+    fn play(mut self, bot: &mut dyn Bot, max_time: f32) -> Outcome {
+        let mut bot_rng = StdRng::seed_from_u64(self.rng.gen());
+        while self.time < max_time {
+            self.nearby.clear();
+            for enemy in &self.enemies {
+                self.nearby.push(NearbyFish {
+                    position: enemy.position,
+                    velocity: enemy.velocity,
+                    meat: enemy.meat,
+                    radius: opaque_radius(enemy.meat, self.sprites.enemies[enemy.sprite].coverage),
+                });
+            }
+            for hazard in &self.hazards {
+                self.nearby.push(NearbyFish {
+                    position: hazard.position,
+                    velocity: Vec2::ZERO,
+                    meat: usize::MAX,
+                    radius: hazard.kind.radius(),
+                });
+            }
+            let view = Surroundings {
+                time: self.time,
+                position: self.position,
+                meat: self.meat,
+                radius: self.radius(),
+                max_speed: self.balance.max_speed,
+                bounds: VIRTUAL_SIZE / 2.0,
+                fish: &self.nearby,
+            };
+            let target = bot.steer(&view, &mut bot_rng);
+            if let Some(death) = self.step(target) {
+                return self.outcome(Some(death));
+            }
+        }
+        self.outcome(None)
+    }
+
+    fn outcome(&self, death: Option<Death>) -> Outcome {
+        Outcome {
+            time: self.time,
+            final_meat: self.meat,
+            eaten: self.eaten,
+            stings: self.stings,
+            death,
+        }
+    }
+
+    /// Advance one step with the pointer held at `target` (if any). Returns
+    /// what killed the player, if anything did.
+    fn step(&mut self, target: Option<Vec2>) -> Option<Death> {
+        let balance = self.balance;
+        self.time += DT;
+
+        // Player, in the order of the game's acceleration and movement systems.
+        if let Some(target) = target {
+            let direction = target - self.position;
+            if direction.length_squared() > 1e-4 {
+                self.velocity = balance.accelerate(self.velocity, direction.normalize(), DT);
+            }
+        }
+        let arena = VIRTUAL_SIZE / 2.0;
+        self.position = (self.position + self.velocity * DT).clamp(-arena, arena);
+        self.velocity = balance.damp(self.velocity, DT);
+
+        // Spawning.
+        self.next_spawn -= DT;
+        if self.next_spawn <= 0.0 {
+            if self.rng.gen::<f32>() < balance.hazard_chance {
+                self.spawn_hazard();
+            } else {
+                self.spawn_enemy();
+            }
+            self.next_spawn = balance.next_spawn_delay(&mut self.rng);
+        }
+
+        // Enemy movement.
+        let limit = VIRTUAL_SIZE.x / 2.0 + DESPAWN_MARGIN;
+        for enemy in &mut self.enemies {
+            enemy.position += enemy.velocity * DT;
+        }
+        self.enemies.retain(|enemy| enemy.position.x.abs() <= limit);
+        self.move_hazards();
+
+        // Collisions.
+        let mut index = 0;
+        while index < self.enemies.len() {
+            let enemy = &self.enemies[index];
+            let touching =
+                enemy.position.distance(self.position) <= self.radius() + self.enemy_radius(enemy);
+            if !touching {
+                index += 1;
+            } else if enemy.meat < self.meat {
+                self.meat += balance.meat_gain(enemy.meat);
+                self.eaten += 1;
+                self.enemies.swap_remove(index);
+            } else {
+                return Some(Death::Eaten {
+                    sprite: self.sprites.enemies[enemy.sprite].name.clone(),
+                    ratio: enemy.meat as f32 / self.meat.max(1) as f32,
+                });
+            }
+        }
+        self.hazard_collisions()
+    }
+
+    /// Sting or kill the player if it touches an armed hazard, like the
+    /// game's `hazard_collision_system`.
+    fn hazard_collisions(&mut self) -> Option<Death> {
+        self.sting_cooldown -= DT;
+        for hazard in &self.hazards {
+            let away = self.position - hazard.position;
+            if hazard.age < self.balance.hazard_telegraph || away.length() > self.radius() + hazard.kind.radius() {
+                continue;
+            }
+            match hazard.kind {
+                HazardKind::Jellyfish if self.sting_cooldown <= 0.0 => {
+                    let shrunk = (self.meat as f32 * (1.0 - self.balance.sting_shrink)) as usize;
+                    self.meat = shrunk.max(self.start_meat).min(self.meat);
+                    self.velocity = away.normalize_or(Vec2::Y) * self.balance.max_speed;
+                    self.sting_cooldown = STING_COOLDOWN;
+                    self.stings += 1;
+                }
+                HazardKind::Jellyfish => {}
+                HazardKind::Hook => return Some(Death::Hooked),
+                HazardKind::Mine => return Some(Death::Exploded),
+            }
+        }
+        None
+    }
+
+    /// Move each hazard along its path and drop those that have left.
+    fn move_hazards(&mut self) {
+        let half = VIRTUAL_SIZE / 2.0;
+        let telegraph = self.balance.hazard_telegraph;
+        for hazard in &mut self.hazards {
+            hazard.age += DT;
+            let armed_for = hazard.age - telegraph;
+            let y = &mut hazard.position.y;
+            match hazard.kind {
+                HazardKind::Jellyfish if armed_for >= 0.0 => *y += JELLYFISH_MEAN_RISE * DT,
+                HazardKind::Jellyfish => {}
+                HazardKind::Hook if armed_for >= HOOK_WAIT => *y += HOOK_REEL_SPEED * DT,
+                HazardKind::Hook if armed_for >= 0.0 => *y = move_towards(*y, hazard.rest_y, HOOK_DROP_SPEED * DT),
+                HazardKind::Hook => {}
+                HazardKind::Mine if armed_for >= MINE_LIFETIME => *y -= MINE_SINK_SPEED * DT,
+                HazardKind::Mine => *y = move_towards(*y, hazard.rest_y, MINE_SINK_SPEED * DT),
+            }
+        }
+        self.hazards.retain(|hazard| {
+            let radius = hazard.kind.radius();
+            match hazard.kind {
+                HazardKind::Jellyfish | HazardKind::Hook => hazard.position.y - radius <= half.y,
+                HazardKind::Mine => hazard.position.y + radius >= -half.y,
+            }
+        });
+    }
+
+    /// Spawn a hazard where the game's `spawn_hazard` would: a jellyfish in
+    /// the arena, a hook or mine just above the surface.
+    fn spawn_hazard(&mut self) {
+        let half = VIRTUAL_SIZE / 2.0;
+        let kind = match self.rng.gen_range(0..3) {
+            0 => HazardKind::Jellyfish,
+            1 => HazardKind::Hook,
+            _ => HazardKind::Mine,
+        };
+        let radius = kind.radius();
+        let x = self.rng.gen_range(-half.x + radius..half.x - radius);
+        let (y, rest_y) = match kind {
+            HazardKind::Jellyfish => {
+                let y = self.rng.gen_range(-half.y * 0.8..half.y * 0.3);
+                (y, y)
+            }
+            HazardKind::Hook | HazardKind::Mine => (half.y + radius, self.rng.gen_range(-half.y * 0.6..half.y * 0.4)),
+        };
+        self.hazards.push(Hazard {
+            kind,
+            position: Vec2::new(x, y),
+            rest_y,
+            age: 0.0,
+        });
+    }
+
+    fn spawn_enemy(&mut self) {
+        let sprite = self.rng.gen_range(0..self.sprites.enemies.len());
+        let size = self.sprites.enemies[sprite].size;
+        let scale = self.balance.enemy_scale(size.x * size.y, &mut self.rng);
+        let rightwards = self.rng.gen_bool(0.5);
+        let half_width = VIRTUAL_SIZE.x / 2.0 + size.x * scale / 2.0;
+        let x = if rightwards { -half_width } else { half_width };
+        let y = self.rng.gen_range(-VIRTUAL_SIZE.y / 2.0..VIRTUAL_SIZE.y / 2.0);
+        let speed = self.balance.enemy_speed(scale, &mut self.rng);
+
+        self.enemies.push(Enemy {
+            sprite,
+            position: Vec2::new(x, y),
+            velocity: Vec2::new(if rightwards { speed } else { -speed }, 0.0),
+            meat: (size.x * size.y * scale * scale) as usize,
+        });
+    }
+}
+
+/// Move `current` towards `target` by at most `max_step`.
+fn move_towards(current: f32, target: f32, max_step: f32) -> f32 {
+    current + (target - current).clamp(-max_step, max_step)
+}
+
+/// Radius of a circle as large as the opaque part of a fish of `meat`.
+fn opaque_radius(meat: usize, coverage: f32) -> f32 {
+    (meat as f32 * coverage / std::f32::consts::PI).sqrt()
+}
+
+// -----------------------------------------------------------------------------
+//  Report
+// -----------------------------------------------------------------------------
+
+fn print_summary(params: &str, bot: &str, outcomes: &[Outcome], max_time: f32) {
+    let runs = outcomes.len().max(1) as f32;
+    let times: Vec<f32> = outcomes.iter().map(|outcome| outcome.time).collect();
+    let meats: Vec<f32> = outcomes.iter().map(|outcome| outcome.final_meat as f32).collect();
+    let eaten: Vec<f32> = outcomes.iter().map(|outcome| outcome.eaten as f32).collect();
+    let stings: Vec<f32> = outcomes.iter().map(|outcome| outcome.stings as f32).collect();
+    let deaths: Vec<&Death> = outcomes.iter().filter_map(|outcome| outcome.death.as_ref()).collect();
+    let survived = outcomes.len() - deaths.len();
+
+    println!("\nparams {params}, bot {bot}: {} runs", outcomes.len());
+    println!(
+        "  survival    {}   ({:.1}% reached the {max_time:.0} s limit)",
+        stats(&times, "s"),
+        survived as f32 / runs * 100.0
+    );
+    println!("  final meat  {}", stats(&meats, ""));
+    println!("  fish eaten  {}", stats(&eaten, ""));
+    println!("  stings      {}", stats(&stings, ""));
+
+    if deaths.is_empty() {
+        return;
+    }
+    let share = |count: usize| count as f32 / deaths.len() as f32 * 100.0;
+
+    let eaten_by: Vec<(&str, f32)> = deaths
+        .iter()
+        .filter_map(|death| match death {
+            Death::Eaten { sprite, ratio } => Some((sprite.as_str(), *ratio)),
+            Death::Hooked | Death::Exploded => None,
+        })
+        .collect();
+    let hooked = deaths.iter().filter(|death| matches!(death, Death::Hooked)).count();
+    let exploded = deaths.iter().filter(|death| matches!(death, Death::Exploded)).count();
+    println!(
+        "  deaths by cause: eaten {:.1}%, hooked {:.1}%, exploded {:.1}%",
+        share(eaten_by.len()),
+        share(hooked),
+        share(exploded)
+    );
+
+    let mut previous = 1.0;
+    let buckets: Vec<String> = RATIO_BUCKETS
+        .iter()
+        .map(|(limit, label)| {
+            let count = eaten_by.iter().filter(|(_, ratio)| *ratio >= previous && *ratio < *limit).count();
+            previous = *limit;
+            format!("{label} {:.1}%", share(count))
+        })
+        .collect();
+    println!("  deaths by killer/player meat: {}", buckets.join(", "));
+
+    let mut by_sprite: HashMap<&str, usize> = HashMap::new();
+    for (sprite, _) in &eaten_by {
+        *by_sprite.entry(*sprite).or_default() += 1;
+    }
+    let mut by_sprite: Vec<_> = by_sprite.into_iter().collect();
+    by_sprite.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    let sprites: Vec<String> = by_sprite
+        .iter()
+        .map(|(sprite, count)| format!("{sprite} {:.1}%", share(*count)))
+        .collect();
+    println!("  deaths by killer sprite: {}", sprites.join(", "));
+}
+
+/// Mean, median and 90th percentile of `values`.
+fn stats(values: &[f32], unit: &str) -> String {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f32::total_cmp);
+    let percentile = |p: f32| sorted.get(((sorted.len() as f32 - 1.0) * p).round() as usize).copied().unwrap_or(0.0);
+    let mean = sorted.iter().sum::<f32>() / sorted.len().max(1) as f32;
+    format!(
+        "mean {mean:.1}{unit}  median {:.1}{unit}  p90 {:.1}{unit}",
+        percentile(0.5),
+        percentile(0.9)
+    )
+}
@@ -0,0 +1,281 @@
+//! Offline checker for the theme images under `assets/images/<theme>/`.
+//!
+//! Catches problems that otherwise only show up at runtime: missing required
+//! sprites (the loading splash never finishes), PNGs that are not 8-bit RGBA
+//! (collision masks need exactly that layout), suspicious alpha coverage,
+//! unusual dimensions and the same sprite used in several categories.
+//!
+//! ```text
+//! cargo run --bin validate_assets [ASSETS_DIR]
+//! ```
+//!
+//! Prints a report and exits with status 1 if any error was found.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use serde::Deserialize;
+
+/// Sprites the game loads by name, relative to the theme's image directory.
+const REQUIRED_IMAGES: &[&str] = &[
+    "playable_fish/fish_1.png",
+    "playable_fish/fish_2.png",
+    "playable_fish/fish_3.png",
+    "score_icon.png",
+];
+
+/// Sprite folders whose contents are picked up by directory listing.
+const CATEGORIES: &[&str] = &["playable_fish", "fish", "flora", "obscura", "hazards"];
+
+/// Optional hazard sprites a theme can draw instead of the generated ones.
+const HAZARD_IMAGES: &[&str] = &["jellyfish.png", "hook.png", "mine.png"];
+
+/// Alpha above which a pixel counts as solid, matching `AlphaMask::from_image`.
+const SOLID_ALPHA: u8 = 127;
+
+/// Sides outside this range (px) are flagged as unusual.
+const MIN_SIDE: u32 = 16;
+const MAX_SIDE: u32 = 2048;
+
+/// Width/height ratios beyond this are flagged as unusual.
+const MAX_ASPECT: f32 = 6.0;
+
+/// The part of an `assets/themes.ron` entry the checker needs.
+#[derive(Deserialize)]
+struct RegistryEntry {
+    id: String,
+    directory: String,
+    preview: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Severity {
+    Warning,
+    Error,
+}
+
+/// One finding, reported against a path relative to the assets directory.
+struct Issue {
+    severity: Severity,
+    path: String,
+    message: String,
+}
+
+#[derive(Default)]
+struct Report {
+    issues: Vec<Issue>,
+}
+
+impl Report {
+    fn error(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.push(Severity::Error, path.into(), message.into());
+    }
+
+    fn warning(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.push(Severity::Warning, path.into(), message.into());
+    }
+
+    fn push(&mut self, severity: Severity, path: String, message: String) {
+        self.issues.push(Issue { severity, path, message });
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.issues.iter().filter(|issue| issue.severity == severity).count()
+    }
+}
+
+fn main() -> ExitCode {
+    let assets = PathBuf::from(std::env::args().nth(1).unwrap_or_else(|| "assets".to_string()));
+    let mut report = Report::default();
+
+    let registry_path = assets.join("themes.ron");
+    let registry: Vec<RegistryEntry> = match fs::read_to_string(&registry_path)
+        .map_err(|e| e.to_string())
+        .and_then(|source| ron::from_str(&source).map_err(|e| e.to_string()))
+    {
+        Ok(entries) => entries,
+        Err(e) => {
+            report.error("themes.ron", e);
+            Vec::new()
+        }
+    };
+
+    let images = assets.join("images");
+    for dir in list_dirs(&images) {
+        if !registry.iter().any(|entry| entry.directory == dir) {
+            report.warning(format!("images/{dir}"), "not listed in themes.ron, the game never loads it");
+        }
+    }
+
+    for entry in &registry {
+        println!("checking theme {:?} (images/{})", entry.id, entry.directory);
+        check_theme(&images.join(&entry.directory), entry, &mut report);
+    }
+
+    print_report(&report);
+    if report.count(Severity::Error) > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Run every check on one theme's image directory.
+fn check_theme(dir: &Path, entry: &RegistryEntry, report: &mut Report) {
+    let rel = |file: &str| format!("images/{}/{file}", entry.directory);
+
+    if !dir.is_dir() {
+        report.error(format!("images/{}", entry.directory), "theme directory listed in themes.ron does not exist");
+        return;
+    }
+
+    let mut required: Vec<&str> = REQUIRED_IMAGES.to_vec();
+    if !required.contains(&entry.preview.as_str()) {
+        required.push(&entry.preview);
+    }
+    for file in required {
+        if !dir.join(file).is_file() {
+            report.error(rel(file), "required image is missing");
+        }
+    }
+    if list_pngs(&dir.join("fish")).is_empty() {
+        report.error(rel("fish/"), "no enemy fish sprites, gameplay spawns nothing");
+    }
+    for file in list_pngs(&dir.join("hazards")) {
+        if !HAZARD_IMAGES.contains(&file.as_str()) {
+            report.warning(rel(&format!("hazards/{file}")), "not a known hazard, the game never loads it");
+        }
+    }
+
+    // Sprite hash → first path seen with it, to spot duplicates.
+    let mut seen: HashMap<u64, (String, &str)> = HashMap::new();
+
+    let root_files = list_pngs(dir).into_iter().map(|file| ("", file));
+    let category_files = CATEGORIES
+        .iter()
+        .flat_map(|category| list_pngs(&dir.join(category)).into_iter().map(move |file| (*category, file)));
+
+    for (category, file) in root_files.chain(category_files) {
+        let file = if category.is_empty() { file } else { format!("{category}/{file}") };
+        let path = rel(&file);
+        let Some(hash) = check_image(&dir.join(&file), &path, report) else {
+            continue;
+        };
+
+        match seen.get(&hash) {
+            Some((first, first_category)) if *first_category != category => {
+                report.warning(&path, format!("identical to {first} (different category)"));
+            }
+            Some(_) => {}
+            None => {
+                seen.insert(hash, (path, category));
+            }
+        }
+    }
+}
+
+/// Decode one PNG and check its format, alpha coverage and dimensions.
+/// Returns a hash of the decoded pixels for duplicate detection.
+fn check_image(file: &Path, path: &str, report: &mut Report) -> Option<u64> {
+    let image = match image::open(file) {
+        Ok(image) => image,
+        Err(e) => {
+            report.error(path, format!("cannot be decoded: {e}"));
+            return None;
+        }
+    };
+
+    // Collision masks read the decoded texture as 8-bit RGBA, 4 bytes a pixel.
+    if !image.color().has_alpha() {
+        report.error(
+            path,
+            format!("{:?} has no alpha channel, collision treats it as fully opaque", image.color()),
+        );
+    } else if image.color() != image::ColorType::Rgba8 {
+        report.error(
+            path,
+            format!("{:?} is not 8-bit RGBA, its collision mask would be garbled", image.color()),
+        );
+    }
+
+    let rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+
+    let total = (width as usize * height as usize).max(1);
+    let solid = rgba.pixels().filter(|pixel| pixel[3] > SOLID_ALPHA).count();
+    let coverage = solid as f32 / total as f32;
+    if image.color().has_alpha() && solid == total {
+        report.warning(path, "alpha channel is fully opaque, the sprite will collide as a rectangle");
+    } else if solid == 0 {
+        report.error(path, "fully transparent, the sprite is invisible and never collides");
+    } else if coverage < 0.05 {
+        report.warning(path, format!("only {:.1}% of pixels are solid", coverage * 100.0));
+    }
+
+    if width.min(height) < MIN_SIDE || width.max(height) > MAX_SIDE {
+        report.warning(path, format!("unusual size {width}x{height}"));
+    }
+    let aspect = width.max(height) as f32 / width.min(height).max(1) as f32;
+    if aspect > MAX_ASPECT {
+        report.warning(path, format!("unusual aspect ratio {width}x{height}"));
+    }
+
+    let mut hasher = DefaultHasher::new();
+    (width, height).hash(&mut hasher);
+    rgba.as_raw().hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+fn print_report(report: &Report) {
+    let mut issues: Vec<&Issue> = report.issues.iter().collect();
+    issues.sort_by(|a, b| b.severity.cmp(&a.severity).then_with(|| a.path.cmp(&b.path)));
+
+    println!();
+    for issue in issues {
+        let label = match issue.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        println!("{label:>7}  {}: {}", issue.path, issue.message);
+    }
+    println!(
+        "\n{} error(s), {} warning(s)",
+        report.count(Severity::Error),
+        report.count(Severity::Warning)
+    );
+}
+
+/// Names of the sub-directories of `dir`, sorted.
+fn list_dirs(dir: &Path) -> Vec<String> {
+    let mut dirs: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .filter_map(|e| e.file_name().to_str().map(str::to_owned))
+                .collect()
+        })
+        .unwrap_or_default();
+    dirs.sort();
+    dirs
+}
+
+/// Names of the PNG files directly inside `dir`, sorted.
+fn list_pngs(dir: &Path) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_file())
+                .filter(|e| e.path().extension().is_some_and(|ext| ext == "png"))
+                .filter_map(|e| e.file_name().to_str().map(str::to_owned))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
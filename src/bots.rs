@@ -0,0 +1,129 @@
+//! Heuristic pilots for a player fish, used by the attract-mode demo behind
+//! the main menu and by the `simulate` balancing tool (which includes this
+//! file by path, so it only depends on `bevy::math` and `rand`).
+
+use bevy::math::Vec2;
+use rand::{Rng, RngCore};
+
+/// What a bot sees each step.
+pub struct Surroundings<'a> {
+    /// Seconds since the pilot took over.
+    pub time: f32,
+    pub position: Vec2,
+    pub meat: usize,
+    /// Collision radius of the piloted fish.
+    pub radius: f32,
+    pub max_speed: f32,
+    /// Half size of the playfield, centred on the origin.
+    pub bounds: Vec2,
+    pub fish: &'a [NearbyFish],
+}
+
+/// Another fish on the playfield.
+pub struct NearbyFish {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub meat: usize,
+    pub radius: f32,
+}
+
+/// Heuristic player, asked every step where to hold the pointer.
+pub trait Bot: Send + Sync {
+    /// World position to hold the pointer at, or `None` to let go.
+    fn steer(&mut self, view: &Surroundings, rng: &mut dyn RngCore) -> Option<Vec2>;
+}
+
+/// Bot called `name`: `greedy`, `cautious` or `random`.
+pub fn make_bot(name: &str) -> Option<Box<dyn Bot>> {
+    match name {
+        "greedy" => Some(Box::new(GreedyEater)),
+        "cautious" => Some(Box::new(CautiousAvoider)),
+        "random" => Some(Box::new(RandomSwimmer::default())),
+        _ => None,
+    }
+}
+
+/// Where `fish` will be by the time the pilot reaches it at full speed.
+fn intercept(view: &Surroundings, fish: &NearbyFish) -> Vec2 {
+    let eta = fish.position.distance(view.position) / view.max_speed;
+    fish.position + fish.velocity * eta
+}
+
+/// Nearest edible fish, skipping those `avoid` rejects.
+fn nearest_prey<'a>(view: &Surroundings<'a>, avoid: impl Fn(&NearbyFish) -> bool) -> Option<&'a NearbyFish> {
+    view.fish
+        .iter()
+        .filter(|fish| fish.meat < view.meat && !avoid(fish))
+        .min_by(|a, b| {
+            a.position
+                .distance_squared(view.position)
+                .total_cmp(&b.position.distance_squared(view.position))
+        })
+}
+
+/// Chases the nearest edible fish and ignores every threat.
+pub struct GreedyEater;
+
+impl Bot for GreedyEater {
+    fn steer(&mut self, view: &Surroundings, _rng: &mut dyn RngCore) -> Option<Vec2> {
+        match nearest_prey(view, |_| false) {
+            Some(prey) => Some(intercept(view, prey)),
+            // Wait in the middle for the next fish.
+            None => (view.position.length() > 100.0).then_some(Vec2::ZERO),
+        }
+    }
+}
+
+/// Flees fish that could eat it, otherwise eats prey that is not guarded.
+pub struct CautiousAvoider;
+
+impl CautiousAvoider {
+    /// Extra distance (beyond touching) at which a threat is avoided.
+    const DANGER_RANGE: f32 = 250.0;
+}
+
+impl Bot for CautiousAvoider {
+    fn steer(&mut self, view: &Surroundings, _rng: &mut dyn RngCore) -> Option<Vec2> {
+        let threats: Vec<&NearbyFish> = view.fish.iter().filter(|fish| fish.meat >= view.meat).collect();
+
+        // Push away from every nearby threat, harder the closer it is.
+        let mut flee = Vec2::ZERO;
+        for threat in &threats {
+            let away = view.position - threat.position;
+            let gap = away.length() - view.radius - threat.radius;
+            if gap < Self::DANGER_RANGE {
+                flee += away.normalize_or_zero() / gap.max(1.0);
+            }
+        }
+        if flee != Vec2::ZERO {
+            return Some((view.position + flee.normalize() * 200.0).clamp(-view.bounds, view.bounds));
+        }
+
+        let guarded = |prey: &NearbyFish| {
+            threats
+                .iter()
+                .any(|threat| threat.position.distance(prey.position) < Self::DANGER_RANGE + threat.radius)
+        };
+        nearest_prey(view, guarded).map(|prey| intercept(view, prey))
+    }
+}
+
+/// Swims to random points, sometimes drifting.
+#[derive(Default)]
+pub struct RandomSwimmer {
+    target: Option<Vec2>,
+    until: f32,
+}
+
+impl Bot for RandomSwimmer {
+    fn steer(&mut self, view: &Surroundings, rng: &mut dyn RngCore) -> Option<Vec2> {
+        if view.time >= self.until {
+            self.until = view.time + rng.gen_range(1.0..3.0);
+            let bounds = view.bounds;
+            self.target = rng
+                .gen_bool(0.7)
+                .then(|| Vec2::new(rng.gen_range(-bounds.x..bounds.x), rng.gen_range(-bounds.y..bounds.y)));
+        }
+        self.target
+    }
+}
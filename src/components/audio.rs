@@ -0,0 +1,43 @@
+use bevy::prelude::Component;
+use crate::settings::AudioBus;
+use crate::theme::ThemeId;
+
+/// Tag for the looping background music entity of a theme.
+#[derive(Component, Copy, Clone)]
+pub struct BackgroundMusic {
+    /// Theme whose track this entity is playing.
+    pub theme: ThemeId,
+}
+
+/// Linear volume fade applied to a music entity. The entity is despawned once
+/// it has faded out completely.
+#[derive(Component, Copy, Clone, Debug)]
+pub struct MusicFade {
+    /// Current fade factor (0 = silent, 1 = full volume).
+    pub level: f32,
+    /// Level the fade is moving towards.
+    pub target: f32,
+}
+
+/// Routes an audio entity through a mixer bus. Every spawned `AudioPlayer`
+/// carries one so mixer changes reach sounds that are already playing.
+#[derive(Component, Copy, Clone, Debug)]
+pub struct AudioChannel {
+    pub bus: AudioBus,
+    /// The clip's own volume before bus, master and ducking are applied.
+    pub volume: f32,
+}
+
+impl AudioChannel {
+    pub const fn music(volume: f32) -> Self {
+        Self { bus: AudioBus::Music, volume }
+    }
+
+    pub const fn sfx() -> Self {
+        Self { bus: AudioBus::Sfx, volume: 1.0 }
+    }
+
+    pub const fn ui() -> Self {
+        Self { bus: AudioBus::Ui, volume: 1.0 }
+    }
+}
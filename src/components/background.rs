@@ -20,4 +20,18 @@ pub struct BackgroundObscura {
     /// coordinate for horizontal movement and the *x* coordinate for vertical
     /// movement.
     pub base_perp: f32,
-} 
\ No newline at end of file
+} 
+/// Depth layer of a background or foreground sprite, back to front. Sprites
+/// outside the playfield layer are children of that layer's root entity,
+/// which carries this component and the layer's parallax offset.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParallaxLayer {
+    /// Large, faint shapes far behind everything.
+    Silhouettes,
+    /// Drifting obscura fish.
+    Obscura,
+    /// Gameplay and the sea floor flora; never scrolls.
+    Playfield,
+    /// Kelp in front of the playfield.
+    Foreground,
+}
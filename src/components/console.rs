@@ -0,0 +1,9 @@
+use bevy::prelude::Component;
+
+/// Marker for the developer console's root node.
+#[derive(Component, Copy, Clone)]
+pub struct ConsoleUI;
+
+/// Marker for the developer console's text.
+#[derive(Component, Copy, Clone)]
+pub struct ConsoleText;
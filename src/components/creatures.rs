@@ -1,12 +1,17 @@
 use bevy::prelude::Component;
 use bevy::math::Vec2;
-use bevy::prelude::Handle;
-use bevy::prelude::Image;
+
+use crate::assets::ThemeSprite;
 
 /// Marker component for the player's fish sprite.
 #[derive(Component, Copy, Clone)]
 pub struct PlayerFish;
 
+/// Marker for the bot-piloted fish of the main-menu attract mode. It is not a
+/// [`PlayerFish`], so gameplay systems, events and telemetry ignore it.
+#[derive(Component, Copy, Clone)]
+pub struct AttractFish;
+
 // NEW: Component representing the edible "meat" of a fish (area in px²).
 #[derive(Component, Copy, Clone, Debug)]
 pub struct Meat(pub usize);
@@ -16,8 +21,45 @@ pub struct Meat(pub usize);
 #[derive(Component, Copy, Clone, Debug)]
 pub struct BaseSpriteArea(pub f32);
 
+/// Uniform scale the player fish is drawn at. Eases towards the scale that
+/// matches its `Meat` rather than snapping after every bite.
+#[derive(Component, Copy, Clone, Debug)]
+pub struct DisplayScale(pub f32);
+
 #[derive(Component, Copy, Clone, Debug, Default)]
 pub struct Velocity(pub Vec2);
 
+/// Whether the player fish is accelerating towards the pointer this frame.
+#[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Swimming(pub bool);
+
+/// Sprite a fish is drawn with, used to look up its collision alpha mask.
 #[derive(Component, Clone, Debug)]
-pub struct FishTexture(pub Handle<Image>); 
\ No newline at end of file
+pub struct FishTexture(pub ThemeSprite);
+
+/// Enemy fish of a glowing species; lights up in dark water.
+#[derive(Component)]
+pub struct Glowing;
+
+/// Size level of the player fish. Every doubling of the starting `Meat` adds
+/// one level.
+#[derive(Component, Copy, Clone, Debug)]
+pub struct GrowthLevel {
+    pub level: u32,
+    /// `Meat` the fish spawned with.
+    pub start_meat: usize,
+}
+
+impl GrowthLevel {
+    pub fn new(start_meat: usize) -> Self {
+        Self { level: 0, start_meat }
+    }
+
+    /// Level corresponding to the supplied `Meat` value.
+    pub fn level_for(&self, meat: usize) -> u32 {
+        if self.start_meat == 0 || meat <= self.start_meat {
+            return 0;
+        }
+        (meat as f32 / self.start_meat as f32).log2().floor() as u32
+    }
+}
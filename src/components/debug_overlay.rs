@@ -0,0 +1,11 @@
+use bevy::prelude::{Component, Entity};
+
+/// Marker for the developer overlay's text panel.
+#[derive(Component, Copy, Clone)]
+pub struct DebugOverlayText;
+
+/// World-space label showing the `Meat` of `target`.
+#[derive(Component, Copy, Clone)]
+pub struct DebugMeatLabel {
+    pub target: Entity,
+}
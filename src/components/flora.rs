@@ -1,8 +1,19 @@
 use bevy::prelude::*;
 
+use crate::components::ParallaxLayer;
+
 /// Tag for static flora decorations that sit at the sea floor in the main menu.
 #[derive(Component)]
 pub struct BackgroundFlora {
+    /// Depth layer the plant belongs to.
+    pub layer: ParallaxLayer,
+    /// Horizontal position as a share of the playfield half-width (-1‒1), so
+    /// the layout survives window resizes.
+    pub anchor_x: f32,
+    /// How far the sprite's bottom edge sits below the playfield bottom (px).
+    pub floor_offset: f32,
+    /// Unscaled image height in pixels.
+    pub image_height: f32,
     /// Base uniform scale applied at rest.
     pub base_scale: f32,
     /// Amplitude of the pulsating scale animation (fraction of `base_scale`).
@@ -0,0 +1,11 @@
+use bevy::prelude::Component;
+
+/// Marks the button that should receive keyboard / gamepad focus when its
+/// screen opens and nothing was focused there before.
+#[derive(Component, Copy, Clone)]
+pub struct DefaultFocus;
+
+/// Root node of a menu screen. Focus is remembered per scope so returning to a
+/// screen (e.g. re-opening the pause menu) restores the last focused button.
+#[derive(Component, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FocusScope(pub &'static str);
@@ -4,6 +4,9 @@ use bevy::prelude::Component;
 #[derive(Component, Copy, Clone)]
 pub struct GameOverUI;
 
-/// Marker for the "Main Menu" button shown on the Game-Over screen.
-#[derive(Component, Copy, Clone)]
-pub struct GameOverMainMenuButton; 
\ No newline at end of file
+/// Fades the Game-Over overlay in once the death sequence has played.
+#[derive(Component, Copy, Clone, Default)]
+pub struct GameOverFade {
+    /// Seconds since the overlay appeared.
+    pub elapsed: f32,
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::Component;
+
+/// Inner node of the loading splash progress bar; its width is the share of
+/// assets loaded so far.
+#[derive(Component, Copy, Clone)]
+pub struct LoadingBarFill;
+
+/// Label naming the asset category still loading.
+#[derive(Component, Copy, Clone)]
+pub struct LoadingCategoryText;
+
+/// `loaded / total` counter next to [`LoadingCategoryText`].
+#[derive(Component, Copy, Clone)]
+pub struct LoadingCountText;
+
+/// Container of the "continue anyway" button, hidden until loading has taken
+/// too long.
+#[derive(Component, Copy, Clone)]
+pub struct LoadingContinueRow;
+
+/// Marker for the root node of the load error screen.
+#[derive(Component, Copy, Clone)]
+pub struct LoadErrorUI;
@@ -0,0 +1,85 @@
+use bevy::prelude::{Color, Component};
+use crate::constants;
+use crate::settings::{AccessibilityOption, VolumeSetting};
+use crate::theme::ThemeId;
+
+/// What pressing a menu button does. Every button carries one and a single
+/// interaction system turns presses into
+/// [`MenuActionEvent`](crate::events::MenuActionEvent)s, so adding a menu
+/// entry needs no new system.
+#[derive(Component, Copy, Clone, Debug, PartialEq)]
+pub enum MenuAction {
+    /// Start a new game (fish picker).
+    Play,
+    /// Open the theme picker.
+    OpenThemePicker,
+    /// Open the settings menu.
+    OpenSettings,
+    /// Return to the main menu from anywhere.
+    MainMenu,
+    /// Leave the loading splash before every asset has finished loading.
+    SkipLoading,
+    /// Go back to the loading splash and retry the assets that failed.
+    RetryLoading,
+    /// Close the pause menu and keep playing.
+    Resume,
+    /// Exit the application (desktop only).
+    Quit,
+    /// Switch to the given theme and continue to the main menu.
+    SelectTheme(ThemeId),
+    /// Pick a zipped theme pack to import (web only; native builds scan the
+    /// theme pack directory instead).
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    ImportThemePack,
+    /// Start playing with the playable fish at this index (0-based).
+    SelectFish(usize),
+    /// Nudge a volume slider by `step`.
+    AdjustVolume { setting: VolumeSetting, step: f32 },
+    /// Toggle the global mute.
+    ToggleMute,
+    /// Switch to the next available language.
+    CycleLanguage,
+    /// Flip one accessibility switch.
+    ToggleAccessibility(AccessibilityOption),
+    /// Switch to the next particle density.
+    CycleParticleDensity,
+}
+
+/// Background colours a button cycles through on interaction. Each screen
+/// uses one of the shared palettes below.
+#[derive(Component, Copy, Clone, Debug)]
+pub struct ButtonPalette {
+    pub idle: Color,
+    pub hover: Color,
+    pub pressed: Color,
+}
+
+impl ButtonPalette {
+    /// Solid teal buttons used by the main, pause, settings and game-over menus.
+    pub const MENU: Self = Self {
+        idle: constants::IDLE_COLOR,
+        hover: constants::HOVER_COLOR,
+        pressed: constants::PRESSED_COLOR,
+    };
+
+    /// Transparent image buttons of the fish picker.
+    pub const FISH_PICKER: Self = Self {
+        idle: constants::PICKER_IDLE_COLOR,
+        hover: constants::PICKER_HOVER_COLOR,
+        pressed: constants::PICKER_PRESSED_COLOR,
+    };
+
+    /// Transparent image buttons of the theme picker.
+    pub const THEME_PICKER: Self = Self {
+        idle: constants::THEME_PICKER_IDLE_COLOR,
+        hover: constants::THEME_PICKER_HOVER_COLOR,
+        pressed: constants::THEME_PICKER_PRESSED_COLOR,
+    };
+
+    /// Opaque replacement for every palette while high-contrast mode is on.
+    pub const HIGH_CONTRAST: Self = Self {
+        idle: constants::HIGH_CONTRAST_IDLE_COLOR,
+        hover: constants::HIGH_CONTRAST_HOVER_COLOR,
+        pressed: constants::HIGH_CONTRAST_PRESSED_COLOR,
+    };
+}
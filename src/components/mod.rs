@@ -6,16 +6,39 @@ pub mod background;
 pub mod flora;
 pub mod game_over;
 pub mod theme_picker;
+pub mod audio;
+pub mod settings_menu;
+pub mod focus;
+pub mod menu;
+pub mod text;
+pub mod loading;
+pub mod debug_overlay;
+#[cfg(feature = "dev")]
+pub mod console;
 
-pub use main_menu::{QuitButton, PlayButton, MainMenuUI, ThemePickerButton, MainMenuLoadingUI};
-pub use pause_menu::{PauseMenuUI, ContinueButton, QuitGameButton, PauseMainMenuButton};
-pub use fish_picker::{FishPickerUI, FishOption1, FishOption2, FishOption3};
+pub use main_menu::{MainMenuUI, MainMenuLoadingUI};
+pub use pause_menu::PauseMenuUI;
+pub use fish_picker::FishPickerUI;
 pub use creatures::PlayerFish;
+pub use creatures::AttractFish;
 pub use creatures::Meat;
 pub use creatures::BaseSpriteArea;
+pub use creatures::DisplayScale;
 pub use creatures::Velocity;
+pub use creatures::Swimming;
 pub use creatures::FishTexture;
-pub use background::BackgroundObscura;
+pub use creatures::Glowing;
+pub use creatures::GrowthLevel;
+pub use background::{BackgroundObscura, ParallaxLayer};
 pub use flora::BackgroundFlora;
-pub use game_over::{GameOverUI, GameOverMainMenuButton};
-pub use theme_picker::{ThemePickerUI, CrayonButton, ChibiButton, RetroPixelButton}; 
\ No newline at end of file
+pub use game_over::{GameOverUI, GameOverFade};
+pub use theme_picker::ThemePickerUI;
+pub use audio::{BackgroundMusic, MusicFade, AudioChannel};
+pub use settings_menu::{SettingsMenuUI, VolumeValueText, MuteButton, AccessibilityToggle, ParticleDensityButton};
+pub use focus::{DefaultFocus, FocusScope};
+pub use menu::{MenuAction, ButtonPalette};
+pub use text::LocalizedText;
+pub use loading::{LoadingBarFill, LoadingCategoryText, LoadingCountText, LoadingContinueRow, LoadErrorUI};
+pub use debug_overlay::{DebugOverlayText, DebugMeatLabel};
+#[cfg(feature = "dev")]
+pub use console::{ConsoleUI, ConsoleText};
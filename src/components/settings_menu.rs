@@ -0,0 +1,22 @@
+use bevy::prelude::Component;
+use crate::settings::{AccessibilityOption, VolumeSetting};
+
+/// Root node for the settings menu UI.
+#[derive(Component, Copy, Clone)]
+pub struct SettingsMenuUI;
+
+/// Text showing the current percentage of a volume slider.
+#[derive(Component, Copy, Clone)]
+pub struct VolumeValueText(pub VolumeSetting);
+
+/// Button toggling the global mute (its label follows the mute state).
+#[derive(Component, Copy, Clone)]
+pub struct MuteButton;
+
+/// Button flipping one accessibility switch (its label shows On / Off).
+#[derive(Component, Copy, Clone)]
+pub struct AccessibilityToggle(pub AccessibilityOption);
+
+/// Button cycling the particle density (its label shows the current level).
+#[derive(Component, Copy, Clone)]
+pub struct ParticleDensityButton;
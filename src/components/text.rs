@@ -0,0 +1,21 @@
+use bevy::prelude::Component;
+
+/// UI text whose content comes from the string tables. The text and its font
+/// are (re)filled whenever the key, the language or a table changes.
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LocalizedText {
+    /// String table key, e.g. `"menu.play"`.
+    pub key: &'static str,
+    /// Render with the bold face of the resolved font.
+    pub bold: bool,
+}
+
+impl LocalizedText {
+    pub const fn new(key: &'static str) -> Self {
+        Self { key, bold: false }
+    }
+
+    pub const fn bold(key: &'static str) -> Self {
+        Self { key, bold: true }
+    }
+}
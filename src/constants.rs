@@ -17,6 +17,12 @@ pub const PRESSED_COLOR: Color = Color::srgb(0.0, 0.37, 0.42);
 /// Corner radius for rounded UI buttons (in logical pixels).
 pub const BUTTON_RADIUS: f32 = 12.0;
 
+/// Keyboard / gamepad focus ring – warm yellow (#FFD166).
+pub const FOCUS_RING_COLOR: Color = Color::srgb(1.0, 0.82, 0.40);
+
+/// Width of the focus ring around the focused button (in logical pixels).
+pub const FOCUS_RING_WIDTH: f32 = 4.0;
+
 // -----------------------------------------------------------------------------
 // Fish-picker palette (soft underwater hues)
 // -----------------------------------------------------------------------------
@@ -40,4 +46,33 @@ pub const THEME_PICKER_HOVER_COLOR: Color = Color::srgba(0.20, 0.65, 0.90, 0.22)
 /// Theme-picker pressed – semi-transparent deeper blue.
 pub const THEME_PICKER_PRESSED_COLOR: Color = Color::srgba(0.10, 0.45, 0.80, 0.32); 
 
-pub const THEME_PICKER_IDLE_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.0);
\ No newline at end of file
+pub const THEME_PICKER_IDLE_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.0);
+
+// -----------------------------------------------------------------------------
+// Accessibility colours
+// -----------------------------------------------------------------------------
+
+/// High-contrast button idle – black behind white labels.
+pub const HIGH_CONTRAST_IDLE_COLOR: Color = Color::BLACK;
+
+/// High-contrast button hover – deep blue (#003F8C).
+pub const HIGH_CONTRAST_HOVER_COLOR: Color = Color::srgb(0.0, 0.25, 0.55);
+
+/// High-contrast button pressed – darker blue (#002659).
+pub const HIGH_CONTRAST_PRESSED_COLOR: Color = Color::srgb(0.0, 0.15, 0.35);
+
+/// Indicator for fish smaller than the player – Okabe-Ito sky blue (#56B4E9),
+/// distinguishable under every common form of colour blindness.
+pub const EDIBLE_INDICATOR_COLOR: Color = Color::srgb(0.34, 0.71, 0.91);
+
+/// Indicator for fish at least as large as the player – Okabe-Ito orange (#E69F00).
+pub const DANGER_INDICATOR_COLOR: Color = Color::srgb(0.90, 0.62, 0.0);
+
+/// Background of the theme picker card describing a broken theme pack.
+pub const PACK_ERROR_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.55);
+
+/// Loading splash progress bar track.
+pub const LOADING_BAR_TRACK_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.45);
+
+/// Loading splash progress bar fill – matches the menu buttons.
+pub const LOADING_BAR_FILL_COLOR: Color = HOVER_COLOR;
@@ -0,0 +1,77 @@
+//! Gameplay events emitted by the simulation systems.
+//!
+//! Feedback systems (audio, effects, ...) react to these instead of hooking
+//! into the systems that detect what happened.
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::components::MenuAction;
+
+/// The player fish swallowed a smaller fish.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct FishEaten {
+    /// `Meat` of the fish that was eaten.
+    pub prey_meat: usize,
+    /// `Meat` of the player *before* the bite.
+    pub player_meat: usize,
+    /// World-space position where the bite happened.
+    pub position: Vec2,
+}
+
+/// What killed the player fish.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeathCause {
+    /// Swallowed by a bigger fish.
+    Eaten,
+    /// Caught on a fishing hook and hauled out of the water.
+    Hooked,
+    /// Blown up by a sea mine.
+    Exploded,
+}
+
+/// The player fish was eaten by a bigger fish or killed by a hazard.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlayerDied {
+    pub cause: DeathCause,
+    /// The fish or hazard that killed the player.
+    pub killer: Entity,
+    /// `Meat` of the fish that ate the player; 0 for hazards.
+    pub killer_meat: usize,
+    /// `Meat` of the player at the time of death.
+    pub player_meat: usize,
+    /// World-space position of the player when it died.
+    pub position: Vec2,
+}
+
+/// A jellyfish stung the player fish, shrinking it.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlayerStung {
+    /// `Meat` the sting took.
+    pub meat_lost: usize,
+    /// `Meat` of the player *after* the sting.
+    pub player_meat: usize,
+    /// World-space position of the player when it was stung.
+    pub position: Vec2,
+}
+
+/// The player fish grew into a new size level (every doubling of its starting
+/// `Meat` counts as one level).
+#[derive(Event, Clone, Copy, Debug)]
+pub struct PlayerLeveledUp {
+    pub level: u32,
+    /// `Meat` of the player that reached the level.
+    pub player_meat: usize,
+}
+
+/// Interface feedback raised whenever any `Button` is hovered or pressed.
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UiSound {
+    Hover,
+    Click,
+}
+
+/// A menu button carrying a [`MenuAction`] was pressed.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct MenuActionEvent(pub MenuAction);
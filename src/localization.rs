@@ -0,0 +1,192 @@
+//! Localised string tables and the font fallback used to render them.
+//!
+//! Every user-facing string lives in `assets/locales/<code>.lang`, a plain
+//! `key = value` file (`#` starts a comment, `\n` is a line break). UI text
+//! is spawned with a [`LocalizedText`](crate::components::LocalizedText) key
+//! and re-rendered whenever the language or a table changes.
+
+use std::collections::HashMap;
+
+use bevy::asset::{io::Reader, AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::theme::ThemeFonts;
+
+/// Languages the game ships string tables for.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    Russian,
+}
+
+impl Language {
+    /// All languages in the order the settings menu cycles through them.
+    pub const ALL: [Language; 3] = [Language::English, Language::Spanish, Language::Russian];
+
+    /// Locale code, also the string table file name.
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::Russian => "ru",
+        }
+    }
+
+    /// Path of this language's string table inside `assets/`.
+    pub fn table_path(&self) -> String {
+        format!("locales/{}.lang", self.code())
+    }
+
+    /// The language after this one, wrapping around.
+    pub fn next(&self) -> Language {
+        let index = Language::ALL.iter().position(|l| l == self).unwrap_or(0);
+        Language::ALL[(index + 1) % Language::ALL.len()]
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  String table asset
+// -----------------------------------------------------------------------------
+
+/// One locale's `key = value` pairs.
+#[derive(Asset, TypePath, Debug, Default)]
+pub struct StringTable {
+    entries: HashMap<String, String>,
+}
+
+impl StringTable {
+    /// Parse the `.lang` format. Malformed lines are skipped with a warning.
+    pub fn parse(source: &str) -> Self {
+        let mut entries = HashMap::new();
+        for (number, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                warn!("string table line {} has no '=': {line}", number + 1);
+                continue;
+            };
+            entries.insert(key.trim().to_string(), value.trim().replace("\\n", "\n"));
+        }
+        Self { entries }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+}
+
+/// Loads `.lang` files into [`StringTable`] assets.
+#[derive(Default)]
+pub struct StringTableLoader;
+
+impl AssetLoader for StringTableLoader {
+    type Asset = StringTable;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<StringTable, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let source = String::from_utf8(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(StringTable::parse(&source))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["lang"]
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  Current language & lookup
+// -----------------------------------------------------------------------------
+
+/// Selected language plus the loaded string table handle for every language.
+#[derive(Resource)]
+pub struct Localization {
+    pub language: Language,
+    pub tables: HashMap<Language, Handle<StringTable>>,
+    /// English table compiled into the binary so text is readable before the
+    /// asset tables finish loading (e.g. on the loading splash).
+    builtin: StringTable,
+}
+
+impl Localization {
+    pub fn new(tables: HashMap<Language, Handle<StringTable>>) -> Self {
+        Self {
+            language: Language::default(),
+            tables,
+            builtin: StringTable::parse(include_str!("../assets/locales/en.lang")),
+        }
+    }
+}
+
+/// System parameter resolving string keys in the current language.
+#[derive(SystemParam)]
+pub struct Strings<'w> {
+    localization: Res<'w, Localization>,
+    tables: Res<'w, Assets<StringTable>>,
+}
+
+impl Strings<'_> {
+    /// Look `key` up in the current language, falling back to English and
+    /// finally to the key itself so missing entries are easy to spot.
+    pub fn get(&self, key: &str) -> String {
+        let lookup = |language: Language| {
+            self.localization
+                .tables
+                .get(&language)
+                .and_then(|handle| self.tables.get(handle))
+                .and_then(|table| table.get(key))
+        };
+
+        lookup(self.localization.language)
+            .or_else(|| lookup(Language::English))
+            .or_else(|| self.localization.builtin.get(key))
+            .unwrap_or(key)
+            .to_string()
+    }
+
+    /// Whether the language changed since the calling system last ran.
+    pub fn is_changed(&self) -> bool {
+        self.localization.is_changed()
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  Font fallback chain
+// -----------------------------------------------------------------------------
+
+/// Characters the theme fonts are expected to cover: Latin, Latin-1
+/// supplement and extended-A, general punctuation.
+const THEME_FONT_COVERAGE: &[(char, char)] = &[('\u{0000}', '\u{017F}'), ('\u{2000}', '\u{206F}')];
+
+/// Catch-all face for scripts the theme fonts lack (Greek, Cyrillic, Hebrew,
+/// Arabic, ...).
+const FALLBACK_FONT: &str = "fonts/DejaVuSans.ttf";
+const FALLBACK_FONT_BOLD: &str = "fonts/DejaVuSans-Bold.ttf";
+
+/// Pick the font able to render `text`: the theme's own font when it covers
+/// every character, otherwise the fallback face.
+pub fn font_for<'a>(text: &str, bold: bool, fonts: &'a ThemeFonts) -> &'a str {
+    let covered = text.chars().all(|c| {
+        c.is_whitespace() || THEME_FONT_COVERAGE.iter().any(|(start, end)| (*start..=*end).contains(&c))
+    });
+
+    match (covered, bold) {
+        (true, false) => &fonts.regular,
+        (true, true) => &fonts.bold,
+        (false, false) => FALLBACK_FONT,
+        (false, true) => FALLBACK_FONT_BOLD,
+    }
+}
@@ -3,26 +3,89 @@ use bevy::prelude::*;
 use bevy_light_2d::prelude::*;
 
 mod assets;
+mod balance;
+mod bots;
 mod components;
 mod constants;
+mod events;
+mod localization;
+mod settings;
 mod states;
 mod systems;
 mod alpha_masks;
+mod atlas;
 mod theme;
+mod theme_pack;
+mod telemetry;
 
 use assets::*;
 use states::*;
 use systems::*;
 use alpha_masks::AlphaMasks;
-use theme::Theme;
+use events::{FishEaten, MenuActionEvent, PlayerDied, PlayerLeveledUp, PlayerStung, UiSound};
+use settings::{Accessibility, AudioMixer, ParticleDensity};
+use telemetry::Telemetry;
+use theme::ThemeRegistry;
+use theme_pack::THEME_PACK_SOURCE;
+use localization::{StringTable, StringTableLoader};
 
 fn main() {
-    App::new()
+    #[allow(unused_mut)]
+    let mut themes = ThemeRegistry::load();
+    // User theme packs (native: folders on disk; web: zips imported in the picker)
+    #[cfg(not(target_arch = "wasm32"))]
+    theme_pack::scan_theme_packs(&mut themes);
+
+    let mut app = App::new();
+    #[cfg(target_arch = "wasm32")]
+    app.init_resource::<theme_pack::PackUploads>().add_systems(
+        Update,
+        (theme_pack_upload_dialog_system, import_theme_pack_uploads_system)
+            .run_if(in_state(GameState::ThemePicker)),
+    );
+
+    #[cfg(feature = "dev")]
+    app.init_resource::<Console>()
+        .init_resource::<Cheats>()
+        .insert_resource(ConsoleCommands::builtin())
+        // Typing must not reach menu focus or hotkeys.
+        .add_systems(
+            PreUpdate,
+            console_input_system
+                .after(bevy::input::InputSystem)
+                .before(bevy::ui::UiSystem::Focus),
+        )
+        .add_systems(Update, (console_execute_system, console_ui_system).chain());
+
+    app
         // Deep-water navy background
         .insert_resource(ClearColor(constants::CLEAR_COLOR))
         .insert_resource(SelectedFish::default())
         .insert_resource(AlphaMasks::default())
-        .insert_resource(Theme::Crayon)
+        .insert_resource(ThemeResidency::detect())
+        .insert_resource(themes.default_theme())
+        .insert_resource(themes)
+        .init_resource::<AudioMixer>()
+        .init_resource::<Accessibility>()
+        .init_resource::<AudioDuck>()
+        .init_resource::<MenuFocus>()
+        .init_resource::<LoadingProgress>()
+        .init_resource::<LastDeathCause>()
+        .init_resource::<ThemeChosen>()
+        .init_resource::<Playfield>()
+        .init_resource::<DebugOverlay>()
+        .init_resource::<GameRng>()
+        .init_resource::<ScreenShake>()
+        .init_resource::<HitStop>()
+        .init_resource::<ParticleDensity>()
+        .init_resource::<Particles>()
+        .init_resource::<ParallaxView>()
+        .init_resource::<Depth>()
+        .init_resource::<balance::Balance>()
+        .insert_resource(Telemetry::detect())
+        .insert_resource(AttractMode::detect())
+        // Must be registered before the asset plugin builds its sources.
+        .register_asset_source(THEME_PACK_SOURCE, theme_pack::asset_source())
         .add_plugins(DefaultPlugins.set(bevy::window::WindowPlugin {
             primary_window: Some(bevy::window::Window {
                 title: "Fishy Redux!".to_string(),
@@ -31,36 +94,115 @@ fn main() {
             ..default()
         }))
         .add_plugins(Light2dPlugin)
+        // Thicker outlines for the accessibility fish indicators
+        .insert_gizmo_config(
+            FishIndicatorGizmos,
+            GizmoConfig {
+                line: GizmoLineConfig {
+                    width: FISH_INDICATOR_LINE_WIDTH,
+                    ..default()
+                },
+                ..default()
+            },
+        )
+        // Developer overlay lines (F3)
+        .init_gizmo_group::<DebugOverlayGizmos>()
+        // Localised string tables (`assets/locales/*.lang`)
+        .init_asset::<StringTable>()
+        .init_asset_loader::<StringTableLoader>()
         // Global game state machines
         .init_state::<GameState>()
         .init_state::<InGameState>()
+        // Gameplay events consumed by feedback systems (audio, ...)
+        .add_event::<FishEaten>()
+        .add_event::<PlayerStung>()
+        .add_event::<PlayerDied>()
+        .add_event::<PlayerLeveledUp>()
+        .add_event::<UiSound>()
+        .add_event::<MenuActionEvent>()
         // Asset loading (runs once at PreStartup to ensure resources exist before state transitions)
         .add_systems(
             bevy::app::PreStartup,
             (
-                load_playable_fish_textures,
-                load_enemy_fish_assets,
-                load_obscura_assets,
-                load_flora_assets,
-                load_theme_select_audio_assets,
-                // Preload *all* assets for every theme upfront.
-                preload_all_assets,
+                // Embedded atlas pages must exist before sprites load from them.
+                register_atlas_pages,
+                (
+                    create_placeholder_sprite,
+                    create_effect_sprites,
+                    load_hazard_sprites,
+                    load_playable_fish_textures,
+                    load_enemy_fish_assets,
+                    load_obscura_assets,
+                    load_flora_assets,
+                    load_theme_select_audio_assets,
+                    load_theme_audio_bank,
+                    load_string_tables,
+                    // Only the selected theme up front; the rest stream in later.
+                    preload_selected_theme,
+                ),
+            )
+                .chain(),
+        )
+        // Camera, parallax layer roots and arena margins exist for all states
+        .add_systems(Startup, (setup_camera, spawn_parallax_roots, spawn_arena_margins))
+        // ---------------- Window resizes ----------------
+        .add_systems(PreUpdate, update_playfield_system)
+        .add_systems(
+            Update,
+            (
+                resize_gradient_background_system,
+                relayout_flora_system,
+                relayout_arena_margins_system,
+            )
+                .run_if(resource_changed::<Playfield>),
+        )
+        // Background theme prefetching and releasing themes on low memory devices
+        .add_systems(
+            Update,
+            (
+                release_inactive_themes_system.run_if(resource_changed::<theme::Theme>),
+                prefetch_background_themes_system
+                    .after(release_inactive_themes_system)
+                    .run_if(not(in_state(GameState::PreMainMenu))),
+                // Stand-ins for sprites that failed to load
+                substitute_failed_sprites_system,
             ),
         )
-        // Camera exists for all states
-        .add_systems(Startup, setup_camera)
+        // Asset load tracking, on the splash and while a newly selected
+        // theme streams in behind the main menu
+        .add_systems(
+            Update,
+            (
+                update_loading_progress_system,
+                load_failure_check_system.after(update_loading_progress_system),
+            )
+                .run_if(in_state(GameState::PreMainMenu).or(in_state(GameState::MainMenu))),
+        )
         // ---------------- Loading splash ----------------
-        .add_systems(OnEnter(GameState::PreMainMenu), spawn_loading_splash_ui)
+        .add_systems(
+            OnEnter(GameState::PreMainMenu),
+            (reset_loading_progress_system, spawn_loading_splash_ui),
+        )
         .add_systems(
             Update,
             (
                 loading_text_animation_system,
+                loading_progress_ui_system.after(update_loading_progress_system),
                 // Transition once all required assets are loaded.
-                check_assets_ready_system,
+                check_assets_ready_system.after(update_loading_progress_system),
             )
                 .run_if(in_state(GameState::PreMainMenu)),
         )
         .add_systems(OnExit(GameState::PreMainMenu), cleanup_loading_splash)
+        // ---------------- Load error ----------------
+        .add_systems(
+            OnEnter(GameState::LoadError),
+            (spawn_gradient_background, spawn_load_error_ui),
+        )
+        .add_systems(
+            OnExit(GameState::LoadError),
+            (cleanup_load_error, cleanup_gradient_background),
+        )
         // ---------------- Theme picker ----------------
         .add_systems(
             OnEnter(GameState::ThemePicker),
@@ -74,13 +216,15 @@ fn main() {
         .add_systems(
             Update,
             (
-                crayon_button_system,
-                chibi_button_system,
-                retro_button_system,
+                theme_hover_preview_system,
                 background_obscura_movement_system,
                 background_obscura_despawn_system,
                 background_obscura_spawn_system,
                 background_flora_animation_system,
+                // Rebuild the picker when a theme pack is imported.
+                (cleanup_theme_picker, setup_theme_picker_ui)
+                    .chain()
+                    .run_if(resource_changed::<ThemeRegistry>),
             )
                 .run_if(in_state(GameState::ThemePicker)),
         )
@@ -96,6 +240,8 @@ fn main() {
                 load_enemy_fish_assets,
                 load_obscura_assets,
                 load_flora_assets,
+                load_hazard_sprites,
+                load_theme_audio_bank,
             ),
         )
         // ---------------- Main menu ----------------
@@ -109,13 +255,9 @@ fn main() {
                 spawn_menu_loading_indicator,
             ),
         )
-        // Button interaction systems only run while we're in the menu
         .add_systems(
             Update,
             (
-                quit_button_system,
-                play_button_system,
-                theme_picker_button_system,
                 play_pending_theme_select_sfx_system,
                 // background fish systems run while in main menu
                 background_obscura_movement_system,
@@ -133,16 +275,62 @@ fn main() {
             )
                 .run_if(in_state(GameState::MainMenu)),
         )
+        // Attract mode: a bot plays behind the menu after a while idle
+        .add_systems(
+            Update,
+            (
+                attract_idle_system,
+                (
+                    attract_spawn_system,
+                    attract_pilot_system,
+                    moving_fish_movement_system,
+                    attract_collision_system,
+                )
+                    .chain()
+                    .run_if(attract_active),
+            )
+                .chain()
+                .run_if(in_state(GameState::MainMenu)),
+        )
         // Cleanup menu when leaving state
         .add_systems(
             OnExit(GameState::MainMenu),
             (
                 cleanup_menu,
+                cleanup_attract_mode,
                 cleanup_gradient_background,
                 cleanup_background_obscura,
                 cleanup_background_flora,
             ),
         )
+        // ---------------- Settings ----------------
+        .add_systems(
+            OnEnter(GameState::Settings),
+            (
+                spawn_gradient_background,
+                spawn_background_obscura_initial,
+                setup_settings_ui,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                esc_to_main_menu_from_settings_system,
+                update_settings_labels_system,
+                background_obscura_movement_system,
+                background_obscura_despawn_system,
+                background_obscura_spawn_system,
+            )
+                .run_if(in_state(GameState::Settings)),
+        )
+        .add_systems(
+            OnExit(GameState::Settings),
+            (
+                cleanup_settings_menu,
+                cleanup_gradient_background,
+                cleanup_background_obscura,
+            ),
+        )
         // --------------- Fish selection ----------------
         // Spawn gradient background first, then the fish picker UI when entering InGame
         .add_systems(
@@ -161,9 +349,6 @@ fn main() {
         .add_systems(
             Update,
             (
-                fish_option1_system,
-                fish_option2_system,
-                fish_option3_system,
                 esc_to_main_menu_from_picker_system,
                 // Animate flora decorations while in the picker menu
                 background_flora_animation_system,
@@ -221,8 +406,23 @@ fn main() {
         // -------- In-game and pause handling --------
         .add_systems(OnEnter(InGameState::PauseMenu), setup_pause_menu_ui)
         .add_systems(OnExit(InGameState::PauseMenu), cleanup_pause_menu)
-        // Game over overlay
+        // Death sequence (slow motion; the killer keeps swimming), then the
+        // game over overlay fades in
+        .add_systems(
+            Update,
+            (devoured_system, moving_fish_movement_system)
+                .run_if(in_state(GameState::InGame))
+                .run_if(in_state(InGameState::Dying)),
+        )
+        .add_systems(OnExit(InGameState::Dying), end_slow_motion_system)
+        .add_systems(Update, record_death_cause_system.run_if(on_event::<PlayerDied>))
         .add_systems(OnEnter(InGameState::GameOver), setup_game_over_ui)
+        .add_systems(
+            Update,
+            game_over_fade_system
+                .run_if(in_state(GameState::InGame))
+                .run_if(in_state(InGameState::GameOver)),
+        )
         .add_systems(OnExit(InGameState::GameOver), cleanup_game_over)
         // Input handling
         .add_systems(
@@ -234,10 +434,6 @@ fn main() {
                 esc_to_resume_system
                     .run_if(in_state(GameState::InGame))
                     .run_if(in_state(InGameState::PauseMenu)),
-                continue_button_system.run_if(in_state(InGameState::PauseMenu)),
-                pause_main_menu_button_system.run_if(in_state(InGameState::PauseMenu)),
-                pause_quit_button_system.run_if(in_state(InGameState::PauseMenu)),
-                game_over_main_menu_button_system.run_if(in_state(InGameState::GameOver)),
             ),
         )
         .add_systems(
@@ -248,9 +444,20 @@ fn main() {
                 background_obscura_spawn_system_ingame,
                 background_flora_animation_system,
                 moving_fish_spawn_system,
-                moving_fish_movement_system,
+                // The angler light lures fish once they have moved, before
+                // anything is eaten. Chained here because the movement system
+                // is also registered for the menu demo and the death sequence,
+                // so ordering against it by name would be ambiguous.
+                (
+                    moving_fish_movement_system,
+                    lure_attraction_system
+                        .after(animate_angler_light_system)
+                        .before(collision_detection_system),
+                )
+                    .chain(),
                 collision_detection_system,
                 update_meat_score_ui,
+                fish_indicator_system.after(collision_detection_system),
             )
                 .run_if(in_state(GameState::InGame))
                 .run_if(in_state(InGameState::Playing)),
@@ -259,12 +466,198 @@ fn main() {
             OnExit(GameState::InGame),
             reset_in_game_state_system
         )
+        // ---------------- Menu buttons ----------------
+        // Every menu button carries a `MenuAction`; one system handles them all.
+        .add_systems(
+            Update,
+            (
+                menu_button_interaction_system,
+                menu_action_system.after(menu_button_interaction_system),
+            ),
+        )
+        // ---------------- Keyboard / gamepad menu focus ----------------
+        // Runs right after Bevy updates mouse `Interaction`s so keyboard presses
+        // reach the button systems in the same frame.
+        .add_systems(
+            PreUpdate,
+            (
+                menu_focus_tracking_system,
+                menu_focus_navigation_system,
+                focus_ring_system,
+            )
+                .chain()
+                .after(bevy::ui::UiSystem::Focus),
+        )
+        // ---------------- Eat and death feedback ----------------
+        .add_systems(
+            Update,
+            (
+                (eat_feedback_system, sting_feedback_system, death_feedback_system)
+                    .after(collision_detection_system)
+                    .after(hazard_collision_system),
+                chomp_squash_system
+                    .after(update_player_fish_scale)
+                    .after(player_fish_orientation_system)
+                    .run_if(in_state(GameState::InGame))
+                    .run_if(in_state(InGameState::Playing)),
+                hit_stop_system,
+                screen_shake_system,
+            ),
+        )
+        .add_systems(OnExit(GameState::InGame), cleanup_feedback_effects)
+        // ---------------- Ambient particles ----------------
+        // Emitters queue particles; the emit system then draws them from the pool.
+        .add_systems(
+            Update,
+            (
+                (
+                    flora_bubble_system,
+                    plankton_system,
+                    mouth_bubble_system
+                        .run_if(in_state(GameState::InGame))
+                        .run_if(in_state(InGameState::Playing)),
+                ),
+                particle_emit_system,
+                particle_update_system,
+            )
+                .chain()
+                .after(eat_feedback_system)
+                .after(sting_feedback_system)
+                .after(death_feedback_system)
+                .run_if(not(in_state(GameState::PreMainMenu)))
+                .run_if(not(in_state(InGameState::PauseMenu))),
+        )
+        .add_systems(OnEnter(GameState::InGame), release_particles_system)
+        .add_systems(OnExit(GameState::InGame), release_particles_system)
+        // ---------------- Depth lighting ----------------
+        // (The angler light's lure runs with the enemy fish, under Gameplay.)
+        .add_systems(OnEnter(InGameState::Playing), spawn_god_rays)
+        .add_systems(
+            Update,
+            (
+                (depth_system, ambient_light_system).chain(),
+                (attach_glow_lights_system, glow_light_system).chain(),
+                god_ray_system
+                    .after(depth_system)
+                    .run_if(in_state(GameState::InGame)),
+            ),
+        )
+        .add_systems(OnExit(GameState::InGame), cleanup_lighting)
+        // ---------------- Hazards ----------------
+        // Spawned by the enemy fish director in place of a fish. They keep
+        // moving during the death sequence, where a hook that caught the
+        // player follows it out of the water.
+        .add_systems(
+            Update,
+            (hazard_motion_system, hook_line_system, mine_lamp_system)
+                .chain()
+                .run_if(in_state(GameState::InGame))
+                .run_if(in_state(InGameState::Playing).or(in_state(InGameState::Dying))),
+        )
+        .add_systems(
+            Update,
+            (
+                hazard_collision_system
+                    .after(hazard_motion_system)
+                    .after(player_fish_movement_system)
+                    .after(collision_detection_system),
+                sting_recovery_system,
+            )
+                .run_if(in_state(GameState::InGame))
+                .run_if(in_state(InGameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            hooked_system
+                .after(devoured_system)
+                .before(hook_line_system)
+                .run_if(in_state(GameState::InGame))
+                .run_if(in_state(InGameState::Dying)),
+        )
+        .add_systems(OnExit(GameState::InGame), cleanup_hazards)
+        // ---------------- Parallax layers ----------------
+        // After the camera shake and every fish movement so the layers see
+        // this frame's view.
+        .add_systems(
+            Update,
+            parallax_system
+                .after(screen_shake_system)
+                .after(player_fish_movement_system)
+                .after(attract_pilot_system),
+        )
+        // ---------------- Run telemetry ----------------
+        .add_systems(
+            Update,
+            (
+                telemetry_run_start_system,
+                telemetry_sample_system,
+                telemetry_fish_spawned_system,
+                telemetry_near_miss_system,
+                telemetry_gameplay_events_system,
+            )
+                .chain()
+                .after(collision_detection_system)
+                .after(hazard_collision_system)
+                .run_if(in_state(GameState::InGame))
+                .run_if(in_state(InGameState::Playing)),
+        )
+        .add_systems(OnExit(GameState::InGame), telemetry_run_quit_system)
+        // ---------------- Developer overlay ----------------
+        .add_systems(
+            Update,
+            (
+                debug_overlay_hotkey_system,
+                debug_frame_time_system,
+                (
+                    debug_collision_gizmos_system,
+                    debug_frame_graph_system,
+                    debug_overlay_text_system,
+                    debug_meat_labels_system,
+                )
+                    .after(debug_overlay_hotkey_system)
+                    .after(debug_frame_time_system)
+                    .after(collision_detection_system),
+            ),
+        )
+        // ---------------- Audio ----------------
+        // Sound effects are driven purely by events so they run in every state.
+        .add_systems(
+            Update,
+            (
+                button_ui_sound_event_system,
+                ui_sfx_system.after(button_ui_sound_event_system),
+                gameplay_sfx_system.after(collision_detection_system),
+                background_music_system
+                    .run_if(not(in_state(GameState::PreMainMenu))),
+                music_fade_system,
+                audio_duck_system,
+                mute_hotkey_system,
+                apply_audio_mixer_system
+                    .after(music_fade_system)
+                    .after(audio_duck_system)
+                    .after(mute_hotkey_system),
+            ),
+        )
+        // Scale freshly spawned audio before Bevy creates its sink. Bevy's audio
+        // playback runs after transform propagation in `PostUpdate`.
+        .add_systems(
+            PostUpdate,
+            init_audio_channel_volume_system
+                .before(bevy::transform::TransformSystem::TransformPropagate),
+        )
+        // Fill localised text (including text spawned this frame) before UI
+        // layout measures it.
+        .add_systems(
+            PostUpdate,
+            localize_text_system.before(bevy::ui::UiSystem::Prepare),
+        )
         .run();
 }
 
-/// 2-D camera used for both UI and gameplay.
+/// 2-D camera used for both UI and gameplay, showing the fixed-size
+/// playfield whatever the window size. Its ambient light darkens with depth.
 fn setup_camera(mut commands: Commands) {
-    commands.spawn(Camera2d);
+    commands.spawn((Camera2d, playfield_projection(), AmbientLight2d::default()));
 }
 
 // ------------------------------------------------------------
@@ -272,46 +665,26 @@ fn setup_camera(mut commands: Commands) {
 // finished loading and transitions to the real main menu.
 // ------------------------------------------------------------
 
+#[allow(clippy::needless_pass_by_value)]
 fn check_assets_ready_system(
-    asset_server: Res<AssetServer>,
-    obscura: Res<assets::ObscuraAssets>,
-    flora: Res<assets::FloraAssets>,
-    playable: Res<assets::PlayableFishTextures>,
-    enemy: Res<assets::EnemyFishAssets>,
-    audio: Res<assets::ThemeSelectAudio>,
-    prefetched: Res<assets::PrefetchedAssets>,
+    progress: Res<LoadingProgress>,
+    attract: Res<AttractMode>,
+    chosen: Res<ThemeChosen>,
     mut next_state: ResMut<NextState<states::GameState>>,
 ) {
     use states::GameState;
-    use bevy::asset::LoadState;
-
-    let obscura_ready = obscura.images.iter().all(|h| asset_server.is_loaded(h));
-    let flora_ready = flora.images.iter().all(|h| asset_server.is_loaded(h));
-
-    let playable_ready = asset_server.is_loaded(&playable.fish1)
-        && asset_server.is_loaded(&playable.fish2)
-        && asset_server.is_loaded(&playable.fish3);
-
-    let enemy_ready = enemy.images.iter().all(|h| asset_server.is_loaded(h));
-
-    let audio_ready = asset_server.is_loaded(&audio.crayon)
-        && asset_server.is_loaded(&audio.chibi)
-        && asset_server.is_loaded(&audio.retro);
-
-    // Ensure every prefetched asset reached the Loaded state.
-    let prefetched_ready = prefetched.handles.iter().all(|h| {
-        matches!(asset_server.get_load_state(h.id()), Some(LoadState::Loaded))
-    });
 
-    if obscura_ready
-        && flora_ready
-        && playable_ready
-        && enemy_ready
-        && audio_ready
-        && prefetched_ready
-    {
-        info!("All assets loaded – switching to ThemePicker state");
-        next_state.set(GameState::ThemePicker);
+    // Required failures are handled by `load_failure_check_system`.
+    if progress.is_complete() && progress.required_failures().next().is_none() {
+        // The landing page embed goes straight to the demo behind the menu,
+        // as does a theme switch.
+        let next = if attract.embedded {
+            GameState::MainMenu
+        } else {
+            chosen.next_state()
+        };
+        info!("All assets loaded – switching to {next:?} state");
+        next_state.set(next);
     }
 }
 
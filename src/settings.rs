@@ -0,0 +1,215 @@
+//! Player-adjustable settings shared across screens.
+
+use bevy::prelude::*;
+
+/// Mixer bus an audio entity is routed through. Every bus is additionally
+/// scaled by the master volume.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AudioBus {
+    Music,
+    Sfx,
+    Ui,
+}
+
+impl AudioBus {
+    /// String table key of the label shown in the settings menu.
+    pub const fn label_key(&self) -> &'static str {
+        match self {
+            AudioBus::Music => "settings.music",
+            AudioBus::Sfx => "settings.sfx",
+            AudioBus::Ui => "settings.ui",
+        }
+    }
+}
+
+/// One adjustable volume slider in the settings menu.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum VolumeSetting {
+    Master,
+    Bus(AudioBus),
+}
+
+impl VolumeSetting {
+    /// All sliders in the order they are shown in the settings menu.
+    pub const ALL: [VolumeSetting; 4] = [
+        VolumeSetting::Master,
+        VolumeSetting::Bus(AudioBus::Music),
+        VolumeSetting::Bus(AudioBus::Sfx),
+        VolumeSetting::Bus(AudioBus::Ui),
+    ];
+
+    /// String table key of the slider's label.
+    pub const fn label_key(&self) -> &'static str {
+        match self {
+            VolumeSetting::Master => "settings.master",
+            VolumeSetting::Bus(bus) => bus.label_key(),
+        }
+    }
+}
+
+/// Volume levels (0.0‒1.0) for the master output and each [`AudioBus`].
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct AudioMixer {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub ui: f32,
+    /// Global mute toggled with the mute hotkey. Volumes are kept so unmuting
+    /// restores the previous mix.
+    pub muted: bool,
+}
+
+impl Default for AudioMixer {
+    fn default() -> Self {
+        Self {
+            master: 0.8,
+            music: 0.7,
+            sfx: 1.0,
+            ui: 0.8,
+            muted: false,
+        }
+    }
+}
+
+impl AudioMixer {
+    /// Volume of a single bus, *not* including master.
+    pub fn bus(&self, bus: AudioBus) -> f32 {
+        match bus {
+            AudioBus::Music => self.music,
+            AudioBus::Sfx => self.sfx,
+            AudioBus::Ui => self.ui,
+        }
+    }
+
+    /// Mutable access to a single bus volume.
+    pub fn bus_mut(&mut self, bus: AudioBus) -> &mut f32 {
+        match bus {
+            AudioBus::Music => &mut self.music,
+            AudioBus::Sfx => &mut self.sfx,
+            AudioBus::Ui => &mut self.ui,
+        }
+    }
+
+    /// Mutable access to the level behind a settings slider.
+    pub fn level_mut(&mut self, setting: VolumeSetting) -> &mut f32 {
+        match setting {
+            VolumeSetting::Master => &mut self.master,
+            VolumeSetting::Bus(bus) => self.bus_mut(bus),
+        }
+    }
+
+    /// Current level behind a settings slider.
+    pub fn level(&self, setting: VolumeSetting) -> f32 {
+        match setting {
+            VolumeSetting::Master => self.master,
+            VolumeSetting::Bus(bus) => self.bus(bus),
+        }
+    }
+
+    /// Effective linear gain for a bus: master × bus, or silence while muted.
+    pub fn gain(&self, bus: AudioBus) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.master * self.bus(bus)
+        }
+    }
+}
+
+/// One switch in the accessibility section of the settings menu.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AccessibilityOption {
+    /// Mark every fish as edible or dangerous relative to the player.
+    FishIndicators,
+    /// Opaque, high-contrast button colours.
+    HighContrast,
+    /// Disable decorative wiggle / pulse animations.
+    ReducedMotion,
+}
+
+impl AccessibilityOption {
+    /// All switches in the order they are shown in the settings menu.
+    pub const ALL: [AccessibilityOption; 3] = [
+        AccessibilityOption::FishIndicators,
+        AccessibilityOption::HighContrast,
+        AccessibilityOption::ReducedMotion,
+    ];
+
+    /// String table key of the switch's label.
+    pub const fn label_key(&self) -> &'static str {
+        match self {
+            AccessibilityOption::FishIndicators => "settings.fish_indicators",
+            AccessibilityOption::HighContrast => "settings.high_contrast",
+            AccessibilityOption::ReducedMotion => "settings.reduced_motion",
+        }
+    }
+}
+
+/// Accessibility switches, all off by default.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct Accessibility {
+    pub fish_indicators: bool,
+    pub high_contrast: bool,
+    pub reduced_motion: bool,
+}
+
+impl Accessibility {
+    pub fn get(&self, option: AccessibilityOption) -> bool {
+        match option {
+            AccessibilityOption::FishIndicators => self.fish_indicators,
+            AccessibilityOption::HighContrast => self.high_contrast,
+            AccessibilityOption::ReducedMotion => self.reduced_motion,
+        }
+    }
+
+    pub fn toggle(&mut self, option: AccessibilityOption) {
+        let value = match option {
+            AccessibilityOption::FishIndicators => &mut self.fish_indicators,
+            AccessibilityOption::HighContrast => &mut self.high_contrast,
+            AccessibilityOption::ReducedMotion => &mut self.reduced_motion,
+        };
+        *value = !*value;
+    }
+}
+
+/// How many ambient and effect particles are emitted.
+#[derive(Resource, Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum ParticleDensity {
+    Off,
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl ParticleDensity {
+    /// Factor applied to every emitter's rate and burst size.
+    pub fn multiplier(&self) -> f32 {
+        match self {
+            ParticleDensity::Off => 0.0,
+            ParticleDensity::Low => 0.4,
+            ParticleDensity::Normal => 1.0,
+            ParticleDensity::High => 1.8,
+        }
+    }
+
+    /// Next level for the settings button, wrapping around.
+    pub fn next(&self) -> Self {
+        match self {
+            ParticleDensity::Off => ParticleDensity::Low,
+            ParticleDensity::Low => ParticleDensity::Normal,
+            ParticleDensity::Normal => ParticleDensity::High,
+            ParticleDensity::High => ParticleDensity::Off,
+        }
+    }
+
+    /// String table key of the level's name.
+    pub const fn label_key(&self) -> &'static str {
+        match self {
+            ParticleDensity::Off => "settings.off",
+            ParticleDensity::Low => "settings.particles_low",
+            ParticleDensity::Normal => "settings.particles_normal",
+            ParticleDensity::High => "settings.particles_high",
+        }
+    }
+}
@@ -10,8 +10,12 @@ pub enum GameState {
     ThemePicker,
     /// Main menu with buttons once a theme has been chosen.
     MainMenu,
+    /// Settings screen reached from the main menu (audio mix, ...).
+    Settings,
     /// Actual gameplay running.
     InGame,
+    /// A required asset failed to load; offers retrying or another theme.
+    LoadError,
 }
 
 /// Sub-state while the game is running.
@@ -24,6 +28,8 @@ pub enum InGameState {
     Playing,
     /// Pause menu overlay.
     PauseMenu,
+    /// The player was eaten; the death sequence plays before the overlay.
+    Dying,
     /// Game over overlay shown when the player is eaten.
     GameOver,
 } 
\ No newline at end of file
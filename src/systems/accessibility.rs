@@ -0,0 +1,47 @@
+use crate::{components::*, constants, settings::Accessibility};
+use bevy::prelude::*;
+
+use super::in_game::MovingFish;
+
+/// Gizmo group for the fish indicators so they get their own line width.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct FishIndicatorGizmos;
+
+/// Width of the indicator outlines (in logical pixels).
+pub const FISH_INDICATOR_LINE_WIDTH: f32 = 3.0;
+
+/// Outline every ambient fish so its danger is readable without judging
+/// sprite sizes: a blue circle for fish the player can eat, an orange diamond
+/// for fish that would eat the player. Colour *and* shape differ so neither is
+/// needed alone. Recomputed every frame, so indicators flip as the player grows.
+#[allow(clippy::needless_pass_by_value)]
+pub fn fish_indicator_system(
+    mut gizmos: Gizmos<FishIndicatorGizmos>,
+    accessibility: Res<Accessibility>,
+    player: Query<&Meat, With<PlayerFish>>,
+    fish: Query<(&GlobalTransform, &Meat), With<MovingFish>>,
+) {
+    if !accessibility.fish_indicators {
+        return;
+    }
+    let Ok(player_meat) = player.single() else {
+        return;
+    };
+
+    for (transform, meat) in &fish {
+        let position = transform.translation().truncate();
+        // Same size estimate the collision check uses, padded to clear the sprite.
+        let radius = (meat.0 as f32).sqrt() * 0.6;
+
+        // Mirrors the rule in `collision_detection_system`.
+        if meat.0 < player_meat.0 {
+            gizmos.circle_2d(position, radius, constants::EDIBLE_INDICATOR_COLOR);
+        } else {
+            gizmos.rect_2d(
+                Isometry2d::new(position, Rot2::degrees(45.0)),
+                Vec2::splat(radius * std::f32::consts::SQRT_2),
+                constants::DANGER_INDICATOR_COLOR,
+            );
+        }
+    }
+}
@@ -0,0 +1,331 @@
+//! Attract mode: after a while without input on the main menu, a bot plays
+//! the real game behind the translucent menu with the current theme's
+//! sprites. Any input hands the screen back to the menu.
+//!
+//! The web build starts the demo straight away, skipping the theme picker,
+//! when the page URL carries an `attract` query parameter (for embedding the
+//! game as a demo, e.g. `index.html?attract`). After the first input the
+//! embed waits for the idle countdown like everywhere else.
+
+use bevy::input::gamepad::Gamepad;
+use bevy::input::mouse::MouseWheel;
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+use bevy::window::CursorMoved;
+use rand::seq::SliceRandom;
+
+use crate::alpha_masks::AlphaMasks;
+use crate::assets::{EnemyFishAssets, PlayableFishTextures};
+use crate::balance::{Balance, VIRTUAL_SIZE};
+use crate::bots::{make_bot, Bot, NearbyFish, Surroundings};
+use crate::components::*;
+use crate::systems::in_game::{
+    broad_phase_radius, pixel_perfect_overlap, spawn_single_moving_fish, MovingFish,
+};
+use crate::systems::resize::Playfield;
+
+/// Seconds without input on the main menu before the demo starts.
+const IDLE_DELAY: f32 = 20.0;
+
+/// Seconds between the demo fish dying (or retiring) and the next one.
+const RESPAWN_DELAY: f32 = 2.0;
+
+/// The demo fish retires once it covers this fraction of the virtual
+/// playfield, before it crowds out the menu.
+const RETIRE_AREA: f32 = 0.05;
+
+/// Bot piloting the demo fish (see [`make_bot`]).
+const PILOT: &str = "cautious";
+
+/// Menu backdrop while the demo plays, keeping the buttons readable.
+const MENU_BACKDROP: Color = Color::srgba(0.0, 0.02, 0.08, 0.45);
+
+/// Query parameter of the page URL that embeds the game as a demo.
+#[cfg(target_arch = "wasm32")]
+const EMBED_QUERY: &str = "attract";
+
+/// Idle tracking and the state of the demo.
+#[derive(Resource)]
+pub struct AttractMode {
+    /// Embedded as a demo: skip the theme picker and start the demo without
+    /// waiting.
+    pub embedded: bool,
+    /// Someone has used the embed, so the demo now waits for
+    /// [`IDLE_DELAY`] like anywhere else.
+    interacted: bool,
+    pub active: bool,
+    /// Seconds since the last input on the main menu.
+    idle: f32,
+    pilot: Box<dyn Bot>,
+    /// Seconds the current demo fish has been swimming.
+    time: f32,
+    /// Seconds until the next demo fish, while there is none.
+    respawn: f32,
+    /// Seconds until the next enemy fish.
+    next_spawn: f32,
+}
+
+impl AttractMode {
+    /// Embedded when the page asks for it (web only).
+    pub fn detect() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let embedded = false;
+        #[cfg(target_arch = "wasm32")]
+        let embedded = web_sys::window()
+            .and_then(|window| window.location().search().ok())
+            .is_some_and(|search| {
+                search
+                    .trim_start_matches('?')
+                    .split('&')
+                    .any(|pair| pair.split('=').next() == Some(EMBED_QUERY))
+            });
+
+        if embedded {
+            info!("Embedded page: starting in attract mode");
+        }
+        let mut attract = Self {
+            embedded,
+            interacted: false,
+            active: false,
+            idle: 0.0,
+            pilot: make_bot(PILOT).expect("attract pilot is a known bot"),
+            time: 0.0,
+            respawn: 0.0,
+            next_spawn: 0.0,
+        };
+        attract.reset();
+        attract
+    }
+
+    /// Stop the demo and restart the idle countdown. An embed nobody has
+    /// used yet counts as already idle.
+    fn reset(&mut self) {
+        self.active = false;
+        self.idle = if self.embedded && !self.interacted { IDLE_DELAY } else { 0.0 };
+    }
+}
+
+/// Run condition: the demo is playing.
+pub fn attract_active(attract: Res<AttractMode>) -> bool {
+    attract.active
+}
+
+/// Every fish the demo spawns: the pilot and the enemy fish around it.
+type DemoFish = Or<(With<AttractFish>, With<MovingFish>)>;
+
+/// Start the demo once the menu has been idle for [`IDLE_DELAY`] and stop it
+/// on any key, button, touch, scroll or pointer movement. The menu gets a
+/// translucent backdrop while the demo plays.
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+pub fn attract_idle_system(
+    mut commands: Commands,
+    mut attract: ResMut<AttractMode>,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    gamepads: Query<&Gamepad>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    demo_fish: Query<Entity, DemoFish>,
+    mut menu: Query<&mut BackgroundColor, With<MainMenuUI>>,
+) {
+    let pointer_moved = cursor_moved.read().count() + mouse_wheel.read().count() > 0;
+    let input = pointer_moved
+        || keys.get_just_pressed().next().is_some()
+        || mouse_buttons.get_just_pressed().next().is_some()
+        || touches.any_just_pressed()
+        || gamepads.iter().any(|gamepad| gamepad.get_just_pressed().next().is_some());
+
+    if input {
+        if attract.active {
+            for entity in &demo_fish {
+                commands.entity(entity).despawn();
+            }
+        }
+        attract.active = false;
+        attract.interacted = true;
+        attract.idle = 0.0;
+    } else {
+        attract.idle += time.delta_secs();
+        if !attract.active && attract.idle >= IDLE_DELAY {
+            attract.active = true;
+            attract.respawn = 0.0;
+            attract.next_spawn = 0.0;
+        }
+    }
+
+    let backdrop = if attract.active { MENU_BACKDROP } else { Color::NONE };
+    for mut background in &mut menu {
+        background.set_if_neq(BackgroundColor(backdrop));
+    }
+}
+
+/// Spawn enemy fish like gameplay does, and a fresh demo fish (one of the
+/// theme's playable fish, picked at random) whenever there is none.
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+pub fn attract_spawn_system(
+    mut commands: Commands,
+    mut attract: ResMut<AttractMode>,
+    time: Res<Time>,
+    enemy_assets: Res<EnemyFishAssets>,
+    textures: Res<PlayableFishTextures>,
+    images: Res<Assets<Image>>,
+    balance: Res<Balance>,
+    pilot: Query<(), With<AttractFish>>,
+) {
+    // The demo is not meant to be reproducible, so it leaves `GameRng` alone.
+    let mut rng = rand::thread_rng();
+    let dt = time.delta_secs();
+
+    attract.next_spawn -= dt;
+    if attract.next_spawn <= 0.0 {
+        spawn_single_moving_fish(&mut commands, &enemy_assets, &images, &balance, &mut rng);
+        attract.next_spawn = balance.next_spawn_delay(&mut rng);
+    }
+
+    if !pilot.is_empty() {
+        return;
+    }
+    attract.respawn -= dt;
+    if attract.respawn > 0.0 {
+        return;
+    }
+
+    let choices = [&textures.fish1, &textures.fish2, &textures.fish3];
+    let Some(handle) = choices.choose(&mut rng).copied() else {
+        return;
+    };
+    let Some(image) = images.get(handle) else {
+        return; // Not loaded yet; try again next frame.
+    };
+    let size = image.size_f32();
+    let scale = balance.player_start_scale(size);
+    let meat = (size.x * size.y * scale * scale) as usize;
+
+    commands.spawn((
+        Sprite::from_image(handle.clone()),
+        Transform::from_scale(Vec3::splat(scale)),
+        AttractFish,
+        Meat(meat),
+        BaseSpriteArea(size.x * size.y),
+        Velocity::default(),
+        FishTexture(handle.clone().into()),
+    ));
+    attract.pilot = make_bot(PILOT).expect("attract pilot is a known bot");
+    attract.time = 0.0;
+    attract.respawn = RESPAWN_DELAY;
+}
+
+/// Let the bot steer the demo fish with the player's movement rules, keep it
+/// in the arena and size it to its `Meat`.
+#[allow(clippy::needless_pass_by_value)]
+pub fn attract_pilot_system(
+    mut attract: ResMut<AttractMode>,
+    time: Res<Time>,
+    balance: Res<Balance>,
+    mut nearby: Local<Vec<NearbyFish>>,
+    mut pilot: Query<(&mut Transform, &mut Velocity, &mut Sprite, &Meat, &BaseSpriteArea), With<AttractFish>>,
+    fish: Query<(&Transform, &MovingFish, &Meat), Without<AttractFish>>,
+) {
+    let Ok((mut transform, mut velocity, mut sprite, meat, base_area)) = pilot.single_mut() else {
+        return;
+    };
+    let dt = time.delta_secs();
+    let attract = &mut *attract;
+    attract.time += dt;
+
+    nearby.clear();
+    nearby.extend(fish.iter().map(|(fish_tf, fish, fish_meat)| NearbyFish {
+        position: fish_tf.translation.truncate(),
+        velocity: fish.velocity,
+        meat: fish_meat.0,
+        radius: broad_phase_radius(fish_meat.0),
+    }));
+
+    let bounds = Playfield::arena_half_size();
+    let position = transform.translation.truncate();
+    let view = Surroundings {
+        time: attract.time,
+        position,
+        meat: meat.0,
+        radius: broad_phase_radius(meat.0),
+        max_speed: balance.max_speed,
+        bounds,
+        fish: &nearby,
+    };
+    if let Some(target) = attract.pilot.steer(&view, &mut rand::thread_rng()) {
+        let direction = target - position;
+        if direction.length_squared() > 1e-4 {
+            velocity.0 = balance.accelerate(velocity.0, direction.normalize(), dt);
+        }
+    }
+
+    let position = (position + velocity.0 * dt).clamp(-bounds, bounds);
+    transform.translation = position.extend(transform.translation.z);
+    velocity.0 = balance.damp(velocity.0, dt);
+
+    // Same orientation and growth rules as the player fish.
+    if velocity.0.x.abs() >= 0.1 {
+        sprite.flip_x = velocity.0.x > 0.0;
+    }
+    if base_area.0 > 0.0 {
+        transform.scale = Vec3::splat((meat.0 as f32 / base_area.0).sqrt());
+    }
+}
+
+/// Demo fish data the collision test reads and grows.
+type PilotBody = (Entity, &'static Transform, &'static FishTexture, &'static mut Meat);
+/// Enemy fish data the collision test reads.
+type EnemyBody = (Entity, &'static Transform, &'static FishTexture, &'static Meat);
+
+/// Eat smaller fish and die to bigger ones, like the player. The demo fish
+/// also retires once it reaches [`RETIRE_AREA`]; either way a new one follows
+/// after [`RESPAWN_DELAY`].
+#[allow(clippy::needless_pass_by_value)]
+pub fn attract_collision_system(
+    mut commands: Commands,
+    mut pilot: Query<PilotBody, (With<AttractFish>, Without<MovingFish>)>,
+    enemies: Query<EnemyBody, (With<MovingFish>, Without<AttractFish>)>,
+    images: Res<Assets<Image>>,
+    mut masks: ResMut<AlphaMasks>,
+    balance: Res<Balance>,
+) {
+    let Ok((pilot_entity, pilot_tf, pilot_tex, mut pilot_meat)) = pilot.single_mut() else {
+        return;
+    };
+
+    for (entity, enemy_tf, enemy_tex, enemy_meat) in &enemies {
+        let radii = broad_phase_radius(pilot_meat.0) + broad_phase_radius(enemy_meat.0);
+        let distance_sq = enemy_tf.translation.truncate().distance_squared(pilot_tf.translation.truncate());
+        if distance_sq > radii * radii
+            || !pixel_perfect_overlap(pilot_tf, &pilot_tex.0, enemy_tf, &enemy_tex.0, &images, &mut masks)
+        {
+            continue;
+        }
+
+        if enemy_meat.0 < pilot_meat.0 {
+            pilot_meat.0 += balance.meat_gain(enemy_meat.0);
+            commands.entity(entity).despawn();
+            if pilot_meat.0 as f32 > VIRTUAL_SIZE.x * VIRTUAL_SIZE.y * RETIRE_AREA {
+                commands.entity(pilot_entity).despawn();
+                return;
+            }
+        } else {
+            commands.entity(pilot_entity).despawn();
+            return;
+        }
+    }
+}
+
+/// Clear the demo when leaving the main menu.
+pub fn cleanup_attract_mode(
+    mut commands: Commands,
+    mut attract: ResMut<AttractMode>,
+    query: Query<Entity, DemoFish>,
+) {
+    for e in &query {
+        commands.entity(e).despawn();
+    }
+    attract.reset();
+}
@@ -0,0 +1,285 @@
+use crate::assets::ThemeAudioBank;
+use crate::components::*;
+use crate::events::{FishEaten, PlayerDied, PlayerLeveledUp, UiSound};
+use crate::settings::{AudioBus, AudioMixer};
+use crate::states::{GameState, InGameState};
+use crate::theme::Theme;
+use bevy::audio::{AudioPlayer, AudioSink, AudioSinkPlayback, PlaybackSettings, Volume};
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+/// Base volume of the looping music track relative to sound effects.
+const MUSIC_VOLUME: f32 = 0.35;
+
+/// How quickly music fades in/out when the theme changes (fade level per second).
+const MUSIC_FADE_SPEED: f32 = 0.75;
+
+/// Fraction of their normal volume that music and effects drop to while paused.
+const PAUSE_DUCK_LEVEL: f32 = 0.3;
+
+/// How quickly ducking ramps in/out (duck level per second).
+const DUCK_SPEED: f32 = 3.0;
+
+/// Hotkey toggling the global mute.
+const MUTE_KEY: KeyCode = KeyCode::KeyM;
+
+/// Prey smaller than this fraction of the player uses the small eat clip.
+const EAT_SMALL_RATIO: f32 = 0.2;
+
+/// Prey larger than this fraction of the player uses the large eat clip.
+const EAT_LARGE_RATIO: f32 = 0.6;
+
+// -----------------------------------------------------------------------------
+// Gameplay sound effects
+// -----------------------------------------------------------------------------
+
+/// Pick the eat clip and playback speed for a bite. Smaller prey use a lighter
+/// clip and play slightly faster (higher pitched) than bigger prey.
+fn eat_clip(bank: &ThemeAudioBank, prey_meat: usize, player_meat: usize) -> (Handle<AudioSource>, f32) {
+    let ratio = prey_meat as f32 / player_meat.max(1) as f32;
+    let clip = if ratio < EAT_SMALL_RATIO {
+        bank.eat_small.clone()
+    } else if ratio < EAT_LARGE_RATIO {
+        bank.eat_medium.clone()
+    } else {
+        bank.eat_large.clone()
+    };
+    // 1.25× for tiny snacks down to 0.85× for prey almost as big as the player.
+    let speed = 1.25 - 0.4 * ratio.clamp(0.0, 1.0);
+    (clip, speed)
+}
+
+/// Play eat / death / level-up sounds in response to gameplay events.
+pub fn gameplay_sfx_system(
+    mut commands: Commands,
+    bank: Option<Res<ThemeAudioBank>>,
+    mut eaten: EventReader<FishEaten>,
+    mut died: EventReader<PlayerDied>,
+    mut leveled: EventReader<PlayerLeveledUp>,
+) {
+    let Some(bank) = bank else {
+        eaten.clear();
+        died.clear();
+        leveled.clear();
+        return;
+    };
+
+    for event in eaten.read() {
+        let (clip, speed) = eat_clip(&bank, event.prey_meat, event.player_meat);
+        commands.spawn((
+            AudioPlayer::new(clip),
+            PlaybackSettings::DESPAWN.with_speed(speed),
+            AudioChannel::sfx(),
+        ));
+    }
+
+    for _ in leveled.read() {
+        commands.spawn((
+            AudioPlayer::new(bank.level_up.clone()),
+            PlaybackSettings::DESPAWN,
+            AudioChannel::sfx(),
+        ));
+    }
+
+    // Only one death sound even if several fish got us in the same frame.
+    if died.read().last().is_some() {
+        commands.spawn((
+            AudioPlayer::new(bank.death.clone()),
+            PlaybackSettings::DESPAWN,
+            AudioChannel::sfx(),
+        ));
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Interface sounds
+// -----------------------------------------------------------------------------
+
+/// Turn button interaction changes into [`UiSound`] events so individual menu
+/// systems don't have to care about audio.
+pub fn button_ui_sound_event_system(
+    interactions: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    mut events: EventWriter<UiSound>,
+) {
+    for interaction in &interactions {
+        match *interaction {
+            Interaction::Pressed => {
+                events.write(UiSound::Click);
+            }
+            Interaction::Hovered => {
+                events.write(UiSound::Hover);
+            }
+            Interaction::None => {}
+        }
+    }
+}
+
+/// Play the current theme's click / hover clips for [`UiSound`] events.
+pub fn ui_sfx_system(
+    mut commands: Commands,
+    bank: Option<Res<ThemeAudioBank>>,
+    mut events: EventReader<UiSound>,
+) {
+    let Some(bank) = bank else {
+        events.clear();
+        return;
+    };
+
+    for event in events.read() {
+        let clip = match event {
+            UiSound::Click => bank.ui_click.clone(),
+            UiSound::Hover => bank.ui_hover.clone(),
+        };
+        commands.spawn((AudioPlayer::new(clip), PlaybackSettings::DESPAWN, AudioChannel::ui()));
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Background music with crossfade between themes
+// -----------------------------------------------------------------------------
+
+/// Make sure the selected theme's music is playing. When the theme changes the
+/// old track fades out while the new one fades in.
+pub fn background_music_system(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    bank: Option<Res<ThemeAudioBank>>,
+    mut music: Query<(&BackgroundMusic, &mut MusicFade)>,
+) {
+    let Some(bank) = bank else {
+        return;
+    };
+    // The bank is reloaded on theme change; wait until it matches.
+    if bank.theme != theme.id {
+        return;
+    }
+
+    let mut current_playing = false;
+    for (track, mut fade) in &mut music {
+        if track.theme == theme.id {
+            fade.target = 1.0;
+            current_playing = true;
+        } else {
+            fade.target = 0.0;
+        }
+    }
+
+    if !current_playing {
+        commands.spawn((
+            AudioPlayer::new(bank.music.clone()),
+            PlaybackSettings::LOOP.with_volume(Volume::Linear(0.0)),
+            AudioChannel::music(MUSIC_VOLUME),
+            BackgroundMusic { theme: theme.id },
+            MusicFade { level: 0.0, target: 1.0 },
+        ));
+    }
+}
+
+/// Advance music fades. Fully faded-out tracks are despawned; the volume
+/// itself is applied by [`apply_audio_mixer_system`].
+pub fn music_fade_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut music: Query<(Entity, &mut MusicFade), With<BackgroundMusic>>,
+) {
+    let step = MUSIC_FADE_SPEED * time.delta_secs();
+
+    for (entity, mut fade) in &mut music {
+        if fade.level < fade.target {
+            fade.level = (fade.level + step).min(fade.target);
+        } else if fade.level > fade.target {
+            fade.level = (fade.level - step).max(fade.target);
+        }
+
+        if fade.level <= 0.0 && fade.target <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Mixer: bus volumes, pause ducking and global mute
+// -----------------------------------------------------------------------------
+
+/// Current ducking factor applied to music and effects (1 = no ducking).
+#[derive(Resource)]
+pub struct AudioDuck {
+    pub level: f32,
+}
+
+impl Default for AudioDuck {
+    fn default() -> Self {
+        Self { level: 1.0 }
+    }
+}
+
+/// Final linear volume for an audio entity given the mixer state.
+fn mixed_volume(
+    channel: &AudioChannel,
+    mixer: &AudioMixer,
+    duck: &AudioDuck,
+    fade: Option<&MusicFade>,
+) -> f32 {
+    let duck = match channel.bus {
+        AudioBus::Music | AudioBus::Sfx => duck.level,
+        // Menu sounds stay at full volume so the pause menu still feels responsive.
+        AudioBus::Ui => 1.0,
+    };
+    let fade = fade.map_or(1.0, |f| f.level);
+    channel.volume * mixer.gain(channel.bus) * duck * fade
+}
+
+/// Ramp the duck level down while the pause menu is open and back up afterwards.
+#[allow(clippy::needless_pass_by_value)]
+pub fn audio_duck_system(
+    time: Res<Time>,
+    game_state: Res<State<GameState>>,
+    in_game_state: Res<State<InGameState>>,
+    mut duck: ResMut<AudioDuck>,
+) {
+    let paused = *game_state.get() == GameState::InGame
+        && *in_game_state.get() == InGameState::PauseMenu;
+    let target = if paused { PAUSE_DUCK_LEVEL } else { 1.0 };
+
+    let step = DUCK_SPEED * time.delta_secs();
+    if duck.level < target {
+        duck.level = (duck.level + step).min(target);
+    } else if duck.level > target {
+        duck.level = (duck.level - step).max(target);
+    }
+}
+
+/// Toggle the global mute when the mute hotkey is pressed (works on every screen).
+#[allow(clippy::needless_pass_by_value)]
+pub fn mute_hotkey_system(keys: Res<ButtonInput<KeyCode>>, mut mixer: ResMut<AudioMixer>) {
+    if keys.just_pressed(MUTE_KEY) {
+        mixer.muted = !mixer.muted;
+        info!("Audio {}", if mixer.muted { "muted" } else { "unmuted" });
+    }
+}
+
+/// Scale the initial volume of freshly spawned audio entities so they never
+/// start playing at full volume. Must run before Bevy creates the sinks.
+#[allow(clippy::needless_pass_by_value)]
+pub fn init_audio_channel_volume_system(
+    mixer: Res<AudioMixer>,
+    duck: Res<AudioDuck>,
+    mut query: Query<(&AudioChannel, &mut PlaybackSettings, Option<&MusicFade>), Added<AudioChannel>>,
+) {
+    for (channel, mut settings, fade) in &mut query {
+        settings.volume = Volume::Linear(mixed_volume(channel, &mixer, &duck, fade));
+    }
+}
+
+/// Push the current mix to every playing sink so volume changes, fades and
+/// ducking apply live.
+#[allow(clippy::needless_pass_by_value)]
+pub fn apply_audio_mixer_system(
+    mixer: Res<AudioMixer>,
+    duck: Res<AudioDuck>,
+    mut query: Query<(&AudioChannel, &mut AudioSink, Option<&MusicFade>)>,
+) {
+    for (channel, mut sink, fade) in &mut query {
+        sink.set_volume(Volume::Linear(mixed_volume(channel, &mixer, &duck, fade)));
+    }
+}
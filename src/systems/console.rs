@@ -0,0 +1,476 @@
+//! Drop-down developer console (`dev` feature), opened with the backquote key.
+//!
+//! Each command implements [`ConsoleCommand`] and is listed in
+//! [`ConsoleCommands::builtin`]; adding one is a matter of writing the struct
+//! and appending it there. Type `help` for the list.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::ecs::world::CommandQueue;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::assets::{
+    load_enemy_fish_assets, load_flora_assets, load_hazard_sprites, load_obscura_assets,
+    load_playable_fish_textures, load_theme_audio_bank, EnemyFishAssets,
+};
+use crate::balance::Balance;
+use crate::components::*;
+use crate::states::{GameState, InGameState};
+use crate::systems::in_game::{spawn_moving_fish, Cheats, GameRng};
+use crate::systems::ThemeChosen;
+use crate::theme::{Theme, ThemeRegistry};
+
+/// Key opening and closing the console.
+const CONSOLE_KEY: KeyCode = KeyCode::Backquote;
+
+/// Output lines kept in the console.
+const CONSOLE_LINES: usize = 12;
+
+const ALL_GAME_STATES: [GameState; 6] = [
+    GameState::PreMainMenu,
+    GameState::ThemePicker,
+    GameState::MainMenu,
+    GameState::Settings,
+    GameState::InGame,
+    GameState::LoadError,
+];
+
+const ALL_IN_GAME_STATES: [InGameState; 5] = [
+    InGameState::FishPicker,
+    InGameState::Playing,
+    InGameState::PauseMenu,
+    InGameState::Dying,
+    InGameState::GameOver,
+];
+
+/// A console command. `run` gets the whitespace-separated arguments after the
+/// command name and exclusive access to the world; its message is printed to
+/// the console either way.
+pub trait ConsoleCommand: Send + Sync + 'static {
+    /// Word typed to invoke the command.
+    fn name(&self) -> &'static str;
+    /// Argument synopsis and one-line description shown by `help`.
+    fn usage(&self) -> &'static str;
+    fn run(&self, args: &[&str], world: &mut World) -> Result<String, String>;
+}
+
+/// Every registered console command.
+#[derive(Resource)]
+pub struct ConsoleCommands(Vec<Box<dyn ConsoleCommand>>);
+
+impl ConsoleCommands {
+    /// The commands shipped with the game.
+    pub fn builtin() -> Self {
+        let mut commands = Self(Vec::new());
+        commands.register(SetMeat);
+        commands.register(SpawnEnemy);
+        commands.register(ToggleInvincible);
+        commands.register(ToggleFreezeSpawning);
+        commands.register(SwitchTheme);
+        commands.register(JumpToState);
+        commands.register(SetSeed);
+        commands
+    }
+
+    pub fn register(&mut self, command: impl ConsoleCommand) {
+        self.0.push(Box::new(command));
+    }
+
+    fn get(&self, name: &str) -> Option<&dyn ConsoleCommand> {
+        self.0.iter().find(|command| command.name() == name).map(|command| &**command)
+    }
+
+    fn help(&self) -> String {
+        let mut lines = vec!["help".to_string()];
+        lines.extend(self.0.iter().map(|command| format!("{} {}", command.name(), command.usage())));
+        lines.join("\n")
+    }
+}
+
+/// Console state: visibility, the line being typed, submitted lines waiting to
+/// run and the output shown.
+#[derive(Resource, Default)]
+pub struct Console {
+    pub open: bool,
+    input: String,
+    pending: Vec<String>,
+    output: VecDeque<String>,
+}
+
+impl Console {
+    fn print(&mut self, text: &str) {
+        for line in text.lines() {
+            if self.output.len() == CONSOLE_LINES {
+                self.output.pop_front();
+            }
+            self.output.push_back(line.to_string());
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  Input & execution
+// -----------------------------------------------------------------------------
+
+/// Toggle the console and collect typed text. While it is open every key
+/// press is swallowed so menu focus, pause and mute hotkeys stay quiet; runs
+/// in `PreUpdate` before the UI focus systems for that reason.
+pub fn console_input_system(
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut typed: EventReader<KeyboardInput>,
+    mut console: ResMut<Console>,
+) {
+    if keys.just_pressed(CONSOLE_KEY) {
+        console.open = !console.open;
+        keys.clear_just_pressed(CONSOLE_KEY);
+        typed.clear();
+        return;
+    }
+    if !console.open {
+        typed.clear();
+        return;
+    }
+
+    for event in typed.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                if !line.trim().is_empty() {
+                    console.pending.push(line);
+                }
+            }
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Escape => console.open = false,
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => console.input.push_str(text),
+            _ => {}
+        }
+    }
+    keys.reset_all();
+}
+
+/// Run submitted console lines.
+pub fn console_execute_system(world: &mut World) {
+    let lines = std::mem::take(&mut world.resource_mut::<Console>().pending);
+    if lines.is_empty() {
+        return;
+    }
+
+    world.resource_scope(|world, commands: Mut<ConsoleCommands>| {
+        for line in lines {
+            let mut words = line.split_whitespace();
+            let Some(name) = words.next() else {
+                continue;
+            };
+            let args: Vec<&str> = words.collect();
+
+            let output = match (name, commands.get(name)) {
+                ("help", _) => commands.help(),
+                (_, Some(command)) => match command.run(&args, world) {
+                    Ok(message) => message,
+                    Err(message) => format!("error: {message}\nusage: {name} {}", command.usage()),
+                },
+                (_, None) => format!("unknown command {name:?}, try help"),
+            };
+
+            info!("console: {line} -> {output}");
+            let mut console = world.resource_mut::<Console>();
+            console.print(&format!("> {line}"));
+            console.print(&output);
+        }
+    });
+}
+
+// -----------------------------------------------------------------------------
+//  UI
+// -----------------------------------------------------------------------------
+
+/// Show the console as a panel across the top of the screen while open.
+#[allow(clippy::needless_pass_by_value)]
+pub fn console_ui_system(
+    mut commands: Commands,
+    console: Res<Console>,
+    roots: Query<Entity, With<ConsoleUI>>,
+    mut texts: Query<&mut Text, With<ConsoleText>>,
+) {
+    if !console.open {
+        for entity in &roots {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let mut text = console.output.iter().cloned().collect::<Vec<_>>().join("\n");
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text.push_str(&format!("> {}_", console.input));
+
+    if let Ok(mut current) = texts.single_mut() {
+        if current.0 != text {
+            current.0 = text;
+        }
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            // Above every menu, including the debug overlay panel.
+            GlobalZIndex(i32::MAX),
+            ConsoleUI,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 1.0, 0.8)),
+                ConsoleText,
+            ));
+        });
+}
+
+// -----------------------------------------------------------------------------
+//  Commands
+// -----------------------------------------------------------------------------
+
+fn parse<T: std::str::FromStr>(arg: Option<&&str>, what: &str) -> Result<T, String> {
+    let arg = arg.ok_or_else(|| format!("missing {what}"))?;
+    arg.parse().map_err(|_| format!("invalid {what} {arg:?}"))
+}
+
+/// Case-insensitive match of `name` against the `Debug` names of `variants`.
+fn parse_variant<T: Copy + Debug>(name: &str, variants: &[T]) -> Option<T> {
+    variants
+        .iter()
+        .copied()
+        .find(|variant| format!("{variant:?}").eq_ignore_ascii_case(name))
+}
+
+fn variant_names<T: Debug>(variants: &[T]) -> String {
+    variants.iter().map(|variant| format!("{variant:?}")).collect::<Vec<_>>().join(", ")
+}
+
+/// World position under the mouse cursor, if it is over the window.
+fn cursor_world_position(world: &mut World) -> Option<Vec2> {
+    let cursor = world.query::<&Window>().iter(world).next()?.cursor_position()?;
+    let (camera, transform) = world.query::<(&Camera, &GlobalTransform)>().iter(world).next()?;
+    camera.viewport_to_world_2d(transform, cursor).ok()
+}
+
+fn toggle_cheat(world: &mut World, name: &str, flag: impl Fn(&mut Cheats) -> &mut bool) -> String {
+    let mut cheats = world.resource_mut::<Cheats>();
+    let flag = flag(&mut cheats);
+    *flag = !*flag;
+    format!("{name} {}", if *flag { "on" } else { "off" })
+}
+
+/// `meat <amount>`
+struct SetMeat;
+
+impl ConsoleCommand for SetMeat {
+    fn name(&self) -> &'static str {
+        "meat"
+    }
+
+    fn usage(&self) -> &'static str {
+        "<amount> – set the player's meat"
+    }
+
+    fn run(&self, args: &[&str], world: &mut World) -> Result<String, String> {
+        let amount: usize = parse(args.first(), "amount")?;
+        let mut players = world.query_filtered::<&mut Meat, With<PlayerFish>>();
+        let mut meat = players.single_mut(world).map_err(|_| "no player fish".to_string())?;
+        meat.0 = amount;
+        Ok(format!("player meat = {amount}"))
+    }
+}
+
+/// `spawn <meat> [sprite]`
+struct SpawnEnemy;
+
+impl ConsoleCommand for SpawnEnemy {
+    fn name(&self) -> &'static str {
+        "spawn"
+    }
+
+    fn usage(&self) -> &'static str {
+        "<meat> [sprite] – spawn an enemy fish at the cursor"
+    }
+
+    fn run(&self, args: &[&str], world: &mut World) -> Result<String, String> {
+        let meat: f32 = parse(args.first(), "meat")?;
+        if !meat.is_finite() || meat <= 0.0 {
+            return Err(format!("meat must be a positive number, got {meat}"));
+        }
+        let sprites = world.get_resource::<EnemyFishAssets>().map(|assets| assets.images.clone()).unwrap_or_default();
+        if sprites.is_empty() {
+            return Err("no enemy fish sprites loaded".to_string());
+        }
+        let index: usize = match args.get(1) {
+            Some(_) => parse(args.get(1), "sprite index")?,
+            None => world.resource_mut::<GameRng>().0.gen_range(0..sprites.len()),
+        };
+        let source = sprites
+            .get(index)
+            .cloned()
+            .ok_or_else(|| format!("sprite index {index} out of range 0..{}", sprites.len()))?;
+        let position = cursor_world_position(world).unwrap_or(Vec2::ZERO);
+
+        let images = world.resource::<Assets<Image>>();
+        let size = source.size(images).ok_or("sprite not loaded yet")?;
+        let scale = (meat / (size.x * size.y)).sqrt();
+
+        world.resource_scope(|world, mut rng: Mut<GameRng>| {
+            let rightwards = rng.0.gen_bool(0.5);
+            let mut queue = CommandQueue::default();
+            let mut commands = Commands::new(&mut queue, world);
+            let (images, balance) = (world.resource::<Assets<Image>>(), world.resource::<Balance>());
+            spawn_moving_fish(&mut commands, source, images, balance, scale, position, rightwards, &mut rng.0);
+            queue.apply(world);
+        });
+        Ok(format!("spawned sprite {index} with meat {meat} at ({:.0}, {:.0})", position.x, position.y))
+    }
+}
+
+/// `god`
+struct ToggleInvincible;
+
+impl ConsoleCommand for ToggleInvincible {
+    fn name(&self) -> &'static str {
+        "god"
+    }
+
+    fn usage(&self) -> &'static str {
+        "– toggle invincibility"
+    }
+
+    fn run(&self, _args: &[&str], world: &mut World) -> Result<String, String> {
+        Ok(toggle_cheat(world, "invincibility", |cheats| &mut cheats.invincible))
+    }
+}
+
+/// `freeze`
+struct ToggleFreezeSpawning;
+
+impl ConsoleCommand for ToggleFreezeSpawning {
+    fn name(&self) -> &'static str {
+        "freeze"
+    }
+
+    fn usage(&self) -> &'static str {
+        "– toggle enemy spawning"
+    }
+
+    fn run(&self, _args: &[&str], world: &mut World) -> Result<String, String> {
+        Ok(toggle_cheat(world, "spawn freeze", |cheats| &mut cheats.freeze_spawning))
+    }
+}
+
+/// `theme <id>`
+struct SwitchTheme;
+
+impl ConsoleCommand for SwitchTheme {
+    fn name(&self) -> &'static str {
+        "theme"
+    }
+
+    fn usage(&self) -> &'static str {
+        "<id> – switch theme and reload, then return to the main menu"
+    }
+
+    fn run(&self, args: &[&str], world: &mut World) -> Result<String, String> {
+        let key = args.first().ok_or("missing theme id")?;
+        let registry = world.resource::<ThemeRegistry>();
+        let theme = registry.iter().find(|theme| theme.key == *key).cloned().ok_or_else(|| {
+            let keys: Vec<&str> = registry.iter().map(|theme| theme.key.as_str()).collect();
+            format!("unknown theme {key:?} (available: {})", keys.join(", "))
+        })?;
+        let name = theme.name.clone();
+        world.insert_resource::<Theme>(theme);
+
+        // Same loaders that run when leaving the theme picker.
+        for result in [
+            world.run_system_once(load_playable_fish_textures),
+            world.run_system_once(load_enemy_fish_assets),
+            world.run_system_once(load_obscura_assets),
+            world.run_system_once(load_flora_assets),
+            world.run_system_once(load_hazard_sprites),
+            world.run_system_once(load_theme_audio_bank),
+        ] {
+            result.map_err(|e| e.to_string())?;
+        }
+        // The splash checks the new theme's required assets on the way back.
+        world.resource_mut::<ThemeChosen>().0 = true;
+        world.resource_mut::<NextState<GameState>>().set(GameState::PreMainMenu);
+        Ok(format!("switched to {name}"))
+    }
+}
+
+/// `state <GameState> [InGameState]`
+struct JumpToState;
+
+impl ConsoleCommand for JumpToState {
+    fn name(&self) -> &'static str {
+        "state"
+    }
+
+    fn usage(&self) -> &'static str {
+        "<GameState> [InGameState] – jump to a state"
+    }
+
+    fn run(&self, args: &[&str], world: &mut World) -> Result<String, String> {
+        let name = args.first().ok_or("missing state")?;
+        let state = parse_variant(name, &ALL_GAME_STATES)
+            .ok_or_else(|| format!("unknown state {name:?} ({})", variant_names(&ALL_GAME_STATES)))?;
+        world.resource_mut::<NextState<GameState>>().set(state);
+
+        let Some(name) = args.get(1) else {
+            return Ok(format!("state = {state:?}"));
+        };
+        let in_game = parse_variant(name, &ALL_IN_GAME_STATES)
+            .ok_or_else(|| format!("unknown in-game state {name:?} ({})", variant_names(&ALL_IN_GAME_STATES)))?;
+        world.resource_mut::<NextState<InGameState>>().set(in_game);
+        Ok(format!("state = {state:?} / {in_game:?}"))
+    }
+}
+
+/// `seed <u64>`
+struct SetSeed;
+
+impl ConsoleCommand for SetSeed {
+    fn name(&self) -> &'static str {
+        "seed"
+    }
+
+    fn usage(&self) -> &'static str {
+        "<number> – reseed the enemy spawning RNG"
+    }
+
+    fn run(&self, args: &[&str], world: &mut World) -> Result<String, String> {
+        let seed: u64 = parse(args.first(), "seed")?;
+        world.insert_resource(GameRng::seeded(seed));
+        Ok(format!("seed = {seed}"))
+    }
+}
@@ -0,0 +1,265 @@
+//! Developer overlay for balancing: collision shapes, `Meat` labels, spawner
+//! timers, the current states and a frame-time graph. Toggled with F3; F4
+//! additionally draws the cached alpha masks.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::alpha_masks::AlphaMasks;
+use crate::components::*;
+use crate::states::{GameState, InGameState};
+use crate::systems::in_game::{broad_phase_radius, MovingFish, MovingFishSpawner};
+use crate::systems::main_menu::BackgroundObscuraSpawner;
+use crate::systems::resize::Playfield;
+
+/// Hotkey toggling the overlay.
+const OVERLAY_KEY: KeyCode = KeyCode::F3;
+
+/// Hotkey toggling the alpha-mask view while the overlay is shown.
+const MASKS_KEY: KeyCode = KeyCode::F4;
+
+/// Frames kept for the frame-time graph.
+const FRAME_HISTORY: usize = 240;
+
+/// Frame-time graph size and the frame time at its top edge.
+const GRAPH_SIZE: Vec2 = Vec2::new(480.0, 120.0);
+const GRAPH_MAX_MS: f32 = 50.0;
+
+/// Alpha masks are drawn on a grid of at most this many cells per side.
+const MASK_CELLS: u32 = 32;
+
+const BROAD_PHASE_COLOR: Color = Color::srgb(0.2, 1.0, 0.4);
+const BOUNDS_COLOR: Color = Color::srgb(1.0, 0.9, 0.2);
+const MASK_COLOR: Color = Color::srgba(1.0, 0.2, 0.8, 0.6);
+const GRAPH_COLOR: Color = Color::srgb(0.3, 0.9, 1.0);
+const GRAPH_GUIDE_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.3);
+
+/// Gizmo group for the overlay so its lines can be drawn on top of the game.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct DebugOverlayGizmos;
+
+/// Overlay switches and the recent frame times.
+#[derive(Resource, Default)]
+pub struct DebugOverlay {
+    pub enabled: bool,
+    pub show_masks: bool,
+    frame_times: VecDeque<f32>,
+}
+
+/// Toggle the overlay (F3) and the alpha-mask view (F4).
+#[allow(clippy::needless_pass_by_value)]
+pub fn debug_overlay_hotkey_system(keys: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<DebugOverlay>) {
+    if keys.just_pressed(OVERLAY_KEY) {
+        overlay.enabled = !overlay.enabled;
+        info!("Debug overlay {}", if overlay.enabled { "on" } else { "off" });
+    }
+    if overlay.enabled && keys.just_pressed(MASKS_KEY) {
+        overlay.show_masks = !overlay.show_masks;
+    }
+}
+
+/// Record the last frame's duration for the graph.
+#[allow(clippy::needless_pass_by_value)]
+pub fn debug_frame_time_system(time: Res<Time<Real>>, mut overlay: ResMut<DebugOverlay>) {
+    if overlay.frame_times.len() == FRAME_HISTORY {
+        overlay.frame_times.pop_front();
+    }
+    overlay.frame_times.push_back(time.delta_secs() * 1000.0);
+}
+
+/// Fish the overlay annotates: the player and the enemy fish.
+type AnnotatedFish = Or<(With<PlayerFish>, With<MovingFish>)>;
+
+/// Draw each fish's broad-phase circle, the sprite bounds used by the pixel
+/// test and, optionally, its cached alpha mask.
+#[allow(clippy::needless_pass_by_value)]
+pub fn debug_collision_gizmos_system(
+    mut gizmos: Gizmos<DebugOverlayGizmos>,
+    overlay: Res<DebugOverlay>,
+    images: Res<Assets<Image>>,
+    masks: Res<AlphaMasks>,
+    fish: Query<(&Transform, &FishTexture, &Meat), AnnotatedFish>,
+) {
+    if !overlay.enabled {
+        return;
+    }
+
+    for (transform, texture, meat) in &fish {
+        let center = transform.translation.truncate();
+        gizmos.circle_2d(center, broad_phase_radius(meat.0), BROAD_PHASE_COLOR);
+
+        // Same rectangle `pixel_perfect_overlap` samples: unrotated, scaled sprite.
+        let Some(size) = texture.0.size(&images) else {
+            continue;
+        };
+        let scale = transform.scale.truncate().abs();
+        gizmos.rect_2d(center, size * scale, BOUNDS_COLOR);
+
+        if !overlay.show_masks {
+            continue;
+        }
+        let Some(mask) = masks.0.get(&texture.0) else {
+            continue;
+        };
+        let step = (mask.width.max(mask.height) / MASK_CELLS).max(1);
+        let cell = Vec2::splat(step as f32) * scale;
+        let origin = center - Vec2::new(mask.width as f32, mask.height as f32) * scale / 2.0;
+        for y in (0..mask.height).step_by(step as usize) {
+            for x in (0..mask.width).step_by(step as usize) {
+                if mask.is_opaque(x, y) {
+                    // Mask rows grow upwards, matching the pixel test.
+                    let position = origin + Vec2::new(x as f32, y as f32) * scale + cell / 2.0;
+                    gizmos.rect_2d(position, cell * 0.8, MASK_COLOR);
+                }
+            }
+        }
+    }
+}
+
+/// Plot recent frame times in the bottom-left corner, with guides at 60 and
+/// 30 FPS.
+#[allow(clippy::needless_pass_by_value)]
+pub fn debug_frame_graph_system(
+    mut gizmos: Gizmos<DebugOverlayGizmos>,
+    overlay: Res<DebugOverlay>,
+    playfield: Res<Playfield>,
+) {
+    if !overlay.enabled {
+        return;
+    }
+
+    let origin = -playfield.half_size() + Vec2::splat(20.0);
+    let y_for = |ms: f32| origin.y + (ms / GRAPH_MAX_MS).min(1.0) * GRAPH_SIZE.y;
+
+    gizmos.rect_2d(origin + GRAPH_SIZE / 2.0, GRAPH_SIZE, GRAPH_GUIDE_COLOR);
+    for guide_ms in [1000.0 / 60.0, 1000.0 / 30.0] {
+        let y = y_for(guide_ms);
+        gizmos.line_2d(Vec2::new(origin.x, y), Vec2::new(origin.x + GRAPH_SIZE.x, y), GRAPH_GUIDE_COLOR);
+    }
+
+    let dx = GRAPH_SIZE.x / (FRAME_HISTORY - 1) as f32;
+    gizmos.linestrip_2d(
+        overlay
+            .frame_times
+            .iter()
+            .enumerate()
+            .map(|(i, ms)| Vec2::new(origin.x + i as f32 * dx, y_for(*ms))),
+        GRAPH_COLOR,
+    );
+}
+
+/// Show the states, spawner timers and frame-time summary in a text panel.
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn debug_overlay_text_system(
+    mut commands: Commands,
+    overlay: Res<DebugOverlay>,
+    game_state: Res<State<GameState>>,
+    in_game_state: Res<State<InGameState>>,
+    fish_spawner: Option<Res<MovingFishSpawner>>,
+    obscura_spawner: Option<Res<BackgroundObscuraSpawner>>,
+    fish: Query<(), With<MovingFish>>,
+    mut panels: Query<(Entity, &mut Text), With<DebugOverlayText>>,
+) {
+    if !overlay.enabled {
+        for (entity, _) in &panels {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let timer = |timer: Option<&Timer>| match timer {
+        Some(timer) => format!("{:.2} s", timer.remaining_secs()),
+        None => "-".to_string(),
+    };
+    let frames = &overlay.frame_times;
+    let average = frames.iter().sum::<f32>() / frames.len().max(1) as f32;
+    let worst = frames.iter().copied().fold(0.0, f32::max);
+
+    let text = format!(
+        "state: {:?} / {:?}\n\
+         fish: {} (next in {})\n\
+         obscura next in {}\n\
+         frame: {average:.1} ms avg, {worst:.1} ms max\n\
+         F4: alpha masks {}",
+        game_state.get(),
+        in_game_state.get(),
+        fish.iter().count(),
+        timer(fish_spawner.as_ref().map(|s| &s.timer)),
+        timer(obscura_spawner.as_ref().map(|s| &s.timer)),
+        if overlay.show_masks { "on" } else { "off" },
+    );
+
+    match panels.single_mut() {
+        Ok((_, mut panel)) => {
+            if panel.0 != text {
+                panel.0 = text;
+            }
+        }
+        Err(_) => {
+            commands.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.0),
+                    left: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                // Above every menu and overlay.
+                GlobalZIndex(i32::MAX),
+                DebugOverlayText,
+            ));
+        }
+    }
+}
+
+/// Keep a `Meat` label above every fish while the overlay is shown.
+#[allow(clippy::type_complexity, clippy::needless_pass_by_value)]
+pub fn debug_meat_labels_system(
+    mut commands: Commands,
+    overlay: Res<DebugOverlay>,
+    fish: Query<(Entity, &Transform, &Meat), AnnotatedFish>,
+    mut labels: Query<(Entity, &DebugMeatLabel, &mut Text2d, &mut Transform), Without<Meat>>,
+) {
+    let mut labelled = Vec::new();
+    for (entity, label, mut text, mut transform) in &mut labels {
+        let Some((_, fish_tf, meat)) = fish.get(label.target).ok().filter(|_| overlay.enabled) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let value = meat.0.to_string();
+        if text.0 != value {
+            text.0 = value;
+        }
+        let radius = broad_phase_radius(meat.0);
+        transform.translation = fish_tf.translation.truncate().extend(10.0) + Vec3::Y * (radius + 12.0);
+        labelled.push(label.target);
+    }
+
+    if !overlay.enabled {
+        return;
+    }
+    for (entity, transform, meat) in &fish {
+        if labelled.contains(&entity) {
+            continue;
+        }
+        let radius = broad_phase_radius(meat.0);
+        commands.spawn((
+            Text2d::new(meat.0.to_string()),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Transform::from_translation(transform.translation.truncate().extend(10.0) + Vec3::Y * (radius + 12.0)),
+            DebugMeatLabel { target: entity },
+        ));
+    }
+}
@@ -0,0 +1,470 @@
+//! Eat, sting and death feedback: the player's chomp squash-and-stretch,
+//! bursts of bubbles and crumbs at the bite point, hit-stop and screen shake
+//! on big eats, stings and explosions, and the death sequence that plays
+//! before the Game Over overlay fades in.
+//!
+//! Like the gameplay sounds, these react to [`FishEaten`], [`PlayerStung`]
+//! and [`PlayerDied`] events rather than hooking into collision detection.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::*;
+use crate::events::{DeathCause, FishEaten, PlayerDied, PlayerStung};
+use crate::settings::{Accessibility, ParticleDensity};
+use crate::states::InGameState;
+use crate::systems::particles::{burst_count, ParticleKind, ParticleSpec, Particles, EFFECT_Z};
+use crate::systems::resize::Playfield;
+
+/// Length of the chomp squash-and-stretch, in seconds.
+const CHOMP_DURATION: f32 = 0.3;
+
+/// Peak stretch of the chomp, as a fraction of the fish's scale.
+const CHOMP_STRETCH: f32 = 0.18;
+
+/// A bite counts as big when the prey has at least this fraction of the
+/// player's `Meat`.
+const BIG_EAT_FRACTION: f32 = 0.4;
+
+/// Real seconds gameplay freezes for on a big eat and on death.
+const BIG_EAT_HIT_STOP: f32 = 0.07;
+const DEATH_HIT_STOP: f32 = 0.15;
+
+/// Screen shake trauma (0‒1) added by a big eat, a sting and by death.
+const BIG_EAT_TRAUMA: f32 = 0.45;
+const STING_TRAUMA: f32 = 0.3;
+const DEATH_TRAUMA: f32 = 0.8;
+
+/// Camera offset at full trauma, in world units.
+const MAX_SHAKE: f32 = 24.0;
+
+/// Trauma lost per second.
+const TRAUMA_DECAY: f32 = 1.5;
+
+/// Real seconds the death sequence lasts before the Game Over overlay.
+const DEATH_DURATION: f32 = 1.6;
+
+/// Game speed while the death sequence plays.
+const DEATH_SLOW_MOTION: f32 = 0.3;
+
+/// Turns the player fish spins through while being swallowed.
+const DEATH_SPIN_TURNS: f32 = 1.5;
+
+/// How far a hooked player fish is hauled up, in playfield heights: enough
+/// to leave the water from the sea floor.
+const HOOKED_HAUL: f32 = 1.5;
+
+/// Wriggle of a hooked player fish: amplitude (radians) and rate (rad/s).
+const HOOKED_WRIGGLE: f32 = 0.35;
+const HOOKED_WRIGGLE_RATE: f32 = 18.0;
+
+/// Seconds the Game Over overlay takes to fade in, and its final backdrop.
+const GAME_OVER_FADE: f32 = 0.6;
+const GAME_OVER_BACKDROP_ALPHA: f32 = 0.7;
+
+/// Per-second velocity loss of burst particles.
+const BURST_DRAG: f32 = 2.5;
+
+/// Bubbles and pieces of debris thrown out by an exploding mine, before
+/// the particle density setting.
+const EXPLOSION_PARTICLES: f32 = 24.0;
+
+const DEBRIS_COLORS: [Color; 3] = [
+    Color::srgb(0.25, 0.25, 0.28),
+    Color::srgb(0.95, 0.6, 0.2),
+    Color::srgb(1.0, 0.85, 0.4),
+];
+
+const CRUMB_COLORS: [Color; 3] = [
+    Color::srgb(0.95, 0.55, 0.45),
+    Color::srgb(0.85, 0.35, 0.35),
+    Color::srgb(1.0, 0.8, 0.6),
+];
+
+/// Camera shake driven by decaying "trauma"; the offset grows with its square.
+#[derive(Resource, Default)]
+pub struct ScreenShake {
+    trauma: f32,
+    /// Offset currently applied to the camera.
+    offset: Vec2,
+}
+
+impl ScreenShake {
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).min(1.0);
+    }
+}
+
+/// Brief freeze of gameplay time. Counts down in real time while
+/// `Time<Virtual>` is paused.
+#[derive(Resource, Default)]
+pub struct HitStop {
+    remaining: f32,
+    /// Whether virtual time was paused by the hit-stop (and is ours to resume).
+    paused: bool,
+}
+
+impl HitStop {
+    pub fn start(&mut self, seconds: f32) {
+        self.remaining = self.remaining.max(seconds);
+    }
+}
+
+/// Squash-and-stretch of the player fish after a bite.
+#[derive(Component)]
+pub struct Chomp {
+    elapsed: f32,
+}
+
+/// The player fish being swallowed by `killer` (or hauled out on a hook, or
+/// blown apart) during the death sequence.
+#[derive(Component)]
+pub struct Devoured {
+    cause: DeathCause,
+    killer: Entity,
+    /// Real seconds since the bite.
+    elapsed: f32,
+    start: Vec2,
+    start_scale: Vec3,
+    /// Last known position of the killer, kept if it swims off-screen.
+    target: Vec2,
+}
+
+/// Throw out bubbles and crumbs at `position`, more of them for bigger prey.
+fn emit_bite_burst(
+    particles: &mut Particles,
+    density: ParticleDensity,
+    position: Vec2,
+    prey_meat: usize,
+    rng: &mut impl Rng,
+) {
+    let size = (prey_meat as f32).sqrt();
+    let count = burst_count((4.0 + size / 25.0).min(14.0), density);
+    let radius = (size * 0.2).max(1.0);
+
+    for _ in 0..count {
+        let offset = Vec2::from_angle(rng.gen_range(0.0..TAU)) * rng.gen_range(0.0..radius);
+        let diameter = rng.gen_range(6.0..(size * 0.08).clamp(8.0, 28.0));
+        particles.emit(ParticleSpec {
+            velocity: offset.normalize_or_zero() * rng.gen_range(40.0..140.0) + Vec2::Y * 40.0,
+            buoyancy: rng.gen_range(60.0..120.0),
+            drag: BURST_DRAG,
+            ..ParticleSpec::new(
+                ParticleKind::Bubble,
+                (position + offset).extend(EFFECT_Z),
+                diameter,
+                rng.gen_range(0.8..1.4),
+            )
+        });
+    }
+
+    for _ in 0..count {
+        let side = rng.gen_range(3.0..(size * 0.04).clamp(4.0, 12.0));
+        particles.emit(ParticleSpec {
+            velocity: Vec2::from_angle(rng.gen_range(0.0..TAU)) * rng.gen_range(80.0..220.0),
+            buoyancy: -rng.gen_range(80.0..160.0),
+            drag: BURST_DRAG,
+            color: Some(CRUMB_COLORS[rng.gen_range(0..CRUMB_COLORS.len())]),
+            ..ParticleSpec::new(ParticleKind::Crumb, position.extend(EFFECT_Z), side, rng.gen_range(0.5..0.9))
+        });
+    }
+}
+
+/// Throw out bubbles and debris in every direction where a mine went off.
+fn emit_explosion(particles: &mut Particles, density: ParticleDensity, position: Vec2, rng: &mut impl Rng) {
+    for _ in 0..burst_count(EXPLOSION_PARTICLES, density) {
+        let direction = Vec2::from_angle(rng.gen_range(0.0..TAU));
+        particles.emit(ParticleSpec {
+            velocity: direction * rng.gen_range(150.0..420.0),
+            buoyancy: rng.gen_range(60.0..140.0),
+            drag: BURST_DRAG,
+            ..ParticleSpec::new(
+                ParticleKind::Bubble,
+                position.extend(EFFECT_Z),
+                rng.gen_range(8.0..30.0),
+                rng.gen_range(0.8..1.6),
+            )
+        });
+        let scatter = Vec2::from_angle(rng.gen_range(-0.4..0.4));
+        particles.emit(ParticleSpec {
+            velocity: direction.rotate(scatter) * rng.gen_range(200.0..500.0),
+            buoyancy: -rng.gen_range(80.0..200.0),
+            drag: BURST_DRAG,
+            color: Some(DEBRIS_COLORS[rng.gen_range(0..DEBRIS_COLORS.len())]),
+            ..ParticleSpec::new(
+                ParticleKind::Crumb,
+                position.extend(EFFECT_Z),
+                rng.gen_range(4.0..12.0),
+                rng.gen_range(0.6..1.2),
+            )
+        });
+    }
+}
+
+/// Chomp, burst and (for big eats) hit-stop and shake for every bite.
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+pub fn eat_feedback_system(
+    mut commands: Commands,
+    mut eaten: EventReader<FishEaten>,
+    mut particles: ResMut<Particles>,
+    density: Res<ParticleDensity>,
+    accessibility: Res<Accessibility>,
+    mut shake: ResMut<ScreenShake>,
+    mut hit_stop: ResMut<HitStop>,
+    player: Query<Entity, With<PlayerFish>>,
+) {
+    let mut rng = rand::thread_rng();
+    for event in eaten.read() {
+        if let Ok(player) = player.single() {
+            commands.entity(player).insert(Chomp { elapsed: 0.0 });
+        }
+        emit_bite_burst(&mut particles, *density, event.position, event.prey_meat, &mut rng);
+
+        if event.prey_meat as f32 >= event.player_meat as f32 * BIG_EAT_FRACTION {
+            hit_stop.start(BIG_EAT_HIT_STOP);
+            if !accessibility.reduced_motion {
+                shake.add_trauma(BIG_EAT_TRAUMA);
+            }
+        }
+    }
+}
+
+/// A burst where the jellyfish stung and a jolt of the camera.
+#[allow(clippy::needless_pass_by_value)]
+pub fn sting_feedback_system(
+    mut stung: EventReader<PlayerStung>,
+    mut particles: ResMut<Particles>,
+    density: Res<ParticleDensity>,
+    accessibility: Res<Accessibility>,
+    mut shake: ResMut<ScreenShake>,
+) {
+    let mut rng = rand::thread_rng();
+    for event in stung.read() {
+        emit_bite_burst(&mut particles, *density, event.position, event.meat_lost, &mut rng);
+        if !accessibility.reduced_motion {
+            shake.add_trauma(STING_TRAUMA);
+        }
+    }
+}
+
+/// Start the death sequence: freeze, shake, burst and slow motion while the
+/// killer swallows the player.
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+pub fn death_feedback_system(
+    mut commands: Commands,
+    mut died: EventReader<PlayerDied>,
+    mut particles: ResMut<Particles>,
+    density: Res<ParticleDensity>,
+    accessibility: Res<Accessibility>,
+    mut shake: ResMut<ScreenShake>,
+    mut hit_stop: ResMut<HitStop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    player: Query<(Entity, &Transform), With<PlayerFish>>,
+) {
+    let Some(event) = died.read().last() else {
+        return;
+    };
+    let Ok((entity, transform)) = player.single() else {
+        return;
+    };
+
+    commands.entity(entity).remove::<Chomp>().insert(Devoured {
+        cause: event.cause,
+        killer: event.killer,
+        elapsed: 0.0,
+        start: event.position,
+        start_scale: transform.scale,
+        target: event.position,
+    });
+    let mut rng = rand::thread_rng();
+    match event.cause {
+        DeathCause::Exploded => emit_explosion(&mut particles, *density, event.position, &mut rng),
+        DeathCause::Eaten | DeathCause::Hooked => {
+            emit_bite_burst(&mut particles, *density, event.position, event.player_meat, &mut rng);
+        }
+    }
+
+    hit_stop.start(DEATH_HIT_STOP);
+    if !accessibility.reduced_motion {
+        shake.add_trauma(DEATH_TRAUMA);
+    }
+    virtual_time.set_relative_speed(DEATH_SLOW_MOTION);
+}
+
+/// Pull the player fish into its killer, shrinking, spinning and fading (or
+/// haul it up out of the water when hooked), then show the Game Over
+/// overlay.
+#[allow(clippy::needless_pass_by_value)]
+pub fn devoured_system(
+    time: Res<Time<Real>>,
+    accessibility: Res<Accessibility>,
+    playfield: Res<Playfield>,
+    mut next_state: ResMut<NextState<InGameState>>,
+    mut player: Query<(&mut Devoured, &mut Transform, &mut Sprite), With<PlayerFish>>,
+    killers: Query<&Transform, Without<PlayerFish>>,
+) {
+    let Ok((mut devoured, mut transform, mut sprite)) = player.single_mut() else {
+        // Nothing to animate (e.g. the state was forced); skip ahead.
+        next_state.set(InGameState::GameOver);
+        return;
+    };
+
+    devoured.elapsed += time.delta_secs();
+    let t = (devoured.elapsed / DEATH_DURATION).min(1.0);
+    // Ease in: slow at first, then gulped down (or yanked up).
+    let pull = t * t;
+
+    if devoured.cause == DeathCause::Hooked {
+        // The hook follows the fish (see `hooked_system`).
+        let position = devoured.start + Vec2::Y * pull * playfield.height() * HOOKED_HAUL;
+        transform.translation = position.extend(transform.translation.z);
+        // Fully visible, even when caught mid sting flash.
+        sprite.color.set_alpha(1.0);
+        if !accessibility.reduced_motion {
+            let wriggle = HOOKED_WRIGGLE * (devoured.elapsed * HOOKED_WRIGGLE_RATE).sin();
+            transform.rotation = Quat::from_rotation_z(wriggle);
+        }
+    } else {
+        if let Ok(killer) = killers.get(devoured.killer) {
+            devoured.target = killer.translation.truncate();
+        }
+        let position = devoured.start.lerp(devoured.target, pull);
+        transform.translation = position.extend(transform.translation.z);
+        transform.scale = devoured.start_scale * (1.0 - pull);
+        if !accessibility.reduced_motion {
+            transform.rotation = Quat::from_rotation_z(pull * DEATH_SPIN_TURNS * TAU);
+        }
+        sprite.color.set_alpha(1.0 - pull);
+    }
+
+    if t >= 1.0 {
+        next_state.set(InGameState::GameOver);
+    }
+}
+
+/// Back to normal speed once the death sequence is over.
+pub fn end_slow_motion_system(mut virtual_time: ResMut<Time<Virtual>>) {
+    virtual_time.set_relative_speed(1.0);
+}
+
+/// Wobble the player fish after a bite, on top of its eased size.
+#[allow(clippy::needless_pass_by_value)]
+pub fn chomp_squash_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    accessibility: Res<Accessibility>,
+    mut player: Query<(Entity, &mut Chomp, &mut Transform), With<PlayerFish>>,
+) {
+    for (entity, mut chomp, mut transform) in &mut player {
+        chomp.elapsed += time.delta_secs();
+        if chomp.elapsed >= CHOMP_DURATION || accessibility.reduced_motion {
+            commands.entity(entity).remove::<Chomp>();
+            continue;
+        }
+
+        // One stretch-then-squash cycle that dies out.
+        let t = chomp.elapsed / CHOMP_DURATION;
+        let wobble = CHOMP_STRETCH * (t * TAU).sin() * (1.0 - t);
+        transform.scale.x *= 1.0 + wobble;
+        transform.scale.y *= 1.0 - wobble;
+    }
+}
+
+/// Pause gameplay time while a hit-stop lasts.
+#[allow(clippy::needless_pass_by_value)]
+pub fn hit_stop_system(
+    time: Res<Time<Real>>,
+    mut hit_stop: ResMut<HitStop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if hit_stop.remaining > 0.0 {
+        if !virtual_time.is_paused() {
+            virtual_time.pause();
+            hit_stop.paused = true;
+        }
+        hit_stop.remaining -= time.delta_secs();
+    } else if hit_stop.paused {
+        virtual_time.unpause();
+        hit_stop.paused = false;
+    }
+}
+
+/// Jolt the camera while there is trauma left (never with reduced motion).
+#[allow(clippy::needless_pass_by_value)]
+pub fn screen_shake_system(
+    time: Res<Time<Real>>,
+    accessibility: Res<Accessibility>,
+    mut shake: ResMut<ScreenShake>,
+    mut cameras: Query<&mut Transform, With<Camera2d>>,
+) {
+    if shake.trauma <= 0.0 && shake.offset == Vec2::ZERO {
+        return;
+    }
+    shake.trauma = (shake.trauma - TRAUMA_DECAY * time.delta_secs()).max(0.0);
+
+    let offset = if accessibility.reduced_motion || shake.trauma <= 0.0 {
+        Vec2::ZERO
+    } else {
+        let mut rng = rand::thread_rng();
+        let jolt = Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
+        jolt * shake.trauma * shake.trauma * MAX_SHAKE
+    };
+    let delta = offset - shake.offset;
+    shake.offset = offset;
+    for mut transform in &mut cameras {
+        transform.translation += delta.extend(0.0);
+    }
+}
+
+/// Fade the Game Over backdrop and text in, then reveal its button.
+#[allow(clippy::needless_pass_by_value)]
+pub fn game_over_fade_system(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut overlays: Query<(Entity, &mut GameOverFade, &mut BackgroundColor)>,
+    children: Query<&Children>,
+    mut texts: Query<&mut TextColor>,
+    mut buttons: Query<&mut Visibility, With<Button>>,
+) {
+    for (entity, mut fade, mut background) in &mut overlays {
+        fade.elapsed += time.delta_secs();
+        let t = (fade.elapsed / GAME_OVER_FADE).min(1.0);
+
+        background.0 = Color::BLACK.with_alpha(GAME_OVER_BACKDROP_ALPHA * t);
+        for child in children.iter_descendants(entity) {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0.set_alpha(t);
+            }
+        }
+
+        if t >= 1.0 {
+            for child in children.iter_descendants(entity) {
+                if let Ok(mut visibility) = buttons.get_mut(child) {
+                    *visibility = Visibility::Inherited;
+                }
+            }
+            commands.entity(entity).remove::<GameOverFade>();
+        }
+    }
+}
+
+/// Drop leftover effects when leaving gameplay: camera shake, hit-stop and
+/// slow motion.
+pub fn cleanup_feedback_effects(
+    mut shake: ResMut<ScreenShake>,
+    mut hit_stop: ResMut<HitStop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut cameras: Query<&mut Transform, With<Camera2d>>,
+) {
+    for mut transform in &mut cameras {
+        transform.translation -= shake.offset.extend(0.0);
+    }
+    *shake = ScreenShake::default();
+    if hit_stop.paused {
+        virtual_time.unpause();
+    }
+    *hit_stop = HitStop::default();
+    virtual_time.set_relative_speed(1.0);
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use crate::{components::*, constants};
+use bevy::input::gamepad::{Gamepad, GamepadButton};
+use bevy::input::mouse::MouseMotion;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+/// Left-stick deflection required before it counts as a navigation press.
+const STICK_THRESHOLD: f32 = 0.6;
+
+/// Keyboard / gamepad focus shared by every menu.
+#[derive(Resource, Default)]
+pub struct MenuFocus {
+    /// Currently focused button, if any.
+    pub entity: Option<Entity>,
+    /// Whether the focus ring is shown. It appears once the keyboard or a
+    /// gamepad is used and hides again when the mouse moves.
+    pub visible: bool,
+    /// Last focused button position per [`FocusScope`], used to restore focus
+    /// when a screen is rebuilt.
+    remembered: HashMap<FocusScope, Vec2>,
+    /// Buttons pressed via keyboard / gamepad last frame that need releasing.
+    pressed: Vec<Entity>,
+    /// Whether the left stick was neutral last frame (edge detection).
+    stick_neutral: bool,
+}
+
+/// Walk up the hierarchy to find the [`FocusScope`] a button belongs to.
+fn scope_of(
+    mut entity: Entity,
+    parents: &Query<&ChildOf>,
+    scopes: &Query<&FocusScope>,
+) -> Option<FocusScope> {
+    loop {
+        if let Ok(scope) = scopes.get(entity) {
+            return Some(*scope);
+        }
+        entity = parents.get(entity).ok()?.parent();
+    }
+}
+
+/// What focus navigation needs to know about each button.
+type ButtonLayout = (
+    Entity,
+    &'static GlobalTransform,
+    &'static ComputedNode,
+    &'static InheritedVisibility,
+);
+
+/// Screen-space centre of every visible, laid-out button.
+fn focus_candidates(
+    buttons: &Query<ButtonLayout, With<Button>>,
+) -> Vec<(Entity, Vec2)> {
+    buttons
+        .iter()
+        .filter(|(_, _, node, visibility)| visibility.get() && node.size() != Vec2::ZERO)
+        .map(|(entity, transform, _, _)| (entity, transform.translation().truncate()))
+        .collect()
+}
+
+/// Pick the best candidate in `dir` (UI space, +y is down) from `from`.
+/// Candidates straight ahead win over ones that are closer but off to the side.
+fn nearest_in_direction(from: Vec2, dir: Vec2, candidates: &[(Entity, Vec2)], current: Entity) -> Option<Entity> {
+    candidates
+        .iter()
+        .filter(|(entity, _)| *entity != current)
+        .filter_map(|(entity, pos)| {
+            let delta = *pos - from;
+            let along = delta.dot(dir);
+            if along <= 1.0 {
+                return None;
+            }
+            let across = (delta - dir * along).length();
+            Some((*entity, along + across * 2.0))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// Collect this frame's navigation direction from arrow keys, D-pad and left stick.
+fn navigation_input(
+    keys: &ButtonInput<KeyCode>,
+    gamepads: &Query<&Gamepad>,
+    stick_neutral: &mut bool,
+) -> Option<Vec2> {
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keys.just_pressed(key) || gamepads.iter().any(|g| g.just_pressed(button))
+    };
+
+    if pressed(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+        return Some(Vec2::new(0.0, -1.0));
+    }
+    if pressed(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+        return Some(Vec2::new(0.0, 1.0));
+    }
+    if pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+        return Some(Vec2::new(-1.0, 0.0));
+    }
+    if pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) {
+        return Some(Vec2::new(1.0, 0.0));
+    }
+
+    // Left stick: trigger once per deflection, must return to neutral first.
+    let stick = gamepads
+        .iter()
+        .map(|g| g.left_stick())
+        .find(|s| s.length() > STICK_THRESHOLD);
+    match stick {
+        Some(s) if *stick_neutral => {
+            *stick_neutral = false;
+            if s.x.abs() > s.y.abs() {
+                Some(Vec2::new(s.x.signum(), 0.0))
+            } else {
+                // Stick +y is up, UI +y is down.
+                Some(Vec2::new(0.0, -s.y.signum()))
+            }
+        }
+        Some(_) => None,
+        None => {
+            *stick_neutral = true;
+            None
+        }
+    }
+}
+
+/// Keep [`MenuFocus`] pointing at a live button: follow mouse hover, and when
+/// a screen opens restore its remembered focus or fall back to [`DefaultFocus`].
+#[allow(clippy::too_many_arguments)]
+pub fn menu_focus_tracking_system(
+    mut focus: ResMut<MenuFocus>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    hovered: Query<(Entity, &Interaction), (Changed<Interaction>, With<Button>)>,
+    buttons: Query<ButtonLayout, With<Button>>,
+    defaults: Query<(), With<DefaultFocus>>,
+    parents: Query<&ChildOf>,
+    scopes: Query<&FocusScope>,
+) {
+    if mouse_motion.read().count() > 0 {
+        focus.visible = false;
+    }
+
+    // The mouse takes over focus when it hovers a button.
+    for (entity, interaction) in &hovered {
+        if *interaction == Interaction::Hovered {
+            focus.entity = Some(entity);
+        }
+    }
+
+    let candidates = focus_candidates(&buttons);
+    let focused_alive = focus
+        .entity
+        .is_some_and(|e| candidates.iter().any(|(c, _)| *c == e));
+
+    if !focused_alive {
+        focus.entity = None;
+
+        // Prefer the position remembered for this screen, then the default button.
+        let remembered = candidates.iter().find_map(|(entity, _)| {
+            let scope = scope_of(*entity, &parents, &scopes)?;
+            focus.remembered.get(&scope).copied()
+        });
+
+        focus.entity = match remembered {
+            Some(pos) => candidates
+                .iter()
+                .min_by(|a, b| a.1.distance_squared(pos).total_cmp(&b.1.distance_squared(pos)))
+                .map(|(entity, _)| *entity),
+            None => candidates
+                .iter()
+                .find(|(entity, _)| defaults.contains(*entity))
+                // Fall back to the top-left button in reading order.
+                .or_else(|| {
+                    candidates
+                        .iter()
+                        .min_by(|a, b| a.1.y.total_cmp(&b.1.y).then(a.1.x.total_cmp(&b.1.x)))
+                })
+                .map(|(entity, _)| *entity),
+        };
+    }
+
+    // Remember where focus sits so the screen can restore it later.
+    if let Some(entity) = focus.entity {
+        if let Some((_, pos)) = candidates.iter().find(|(c, _)| *c == entity) {
+            if let Some(scope) = scope_of(entity, &parents, &scopes) {
+                focus.remembered.insert(scope, *pos);
+            }
+        }
+    }
+}
+
+/// Move focus with arrow keys / D-pad / left stick and activate the focused
+/// button with Enter, Space or the gamepad South (A) button.
+///
+/// Activation presses the button through its [`Interaction`] so every existing
+/// button system reacts exactly as it does to a mouse click.
+#[allow(clippy::needless_pass_by_value)]
+pub fn menu_focus_navigation_system(
+    mut focus: ResMut<MenuFocus>,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    buttons: Query<ButtonLayout, With<Button>>,
+    mut interactions: Query<&mut Interaction, With<Button>>,
+) {
+    // Release buttons pressed by the previous activation, mirroring a mouse click.
+    for entity in std::mem::take(&mut focus.pressed) {
+        if let Ok(mut interaction) = interactions.get_mut(entity) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    let Some(current) = focus.entity else {
+        return;
+    };
+
+    let mut stick_neutral = focus.stick_neutral;
+    let direction = navigation_input(&keys, &gamepads, &mut stick_neutral);
+    focus.stick_neutral = stick_neutral;
+
+    if let Some(dir) = direction {
+        focus.visible = true;
+        let candidates = focus_candidates(&buttons);
+        if let Some((_, from)) = candidates.iter().find(|(e, _)| *e == current) {
+            if let Some(next) = nearest_in_direction(*from, dir, &candidates, current) {
+                focus.entity = Some(next);
+            }
+        }
+        return;
+    }
+
+    let activate = keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space])
+        || gamepads.iter().any(|g| g.just_pressed(GamepadButton::South));
+
+    if activate {
+        focus.visible = true;
+        if let Ok(mut interaction) = interactions.get_mut(current) {
+            *interaction = Interaction::Pressed;
+            focus.pressed.push(current);
+        }
+    }
+}
+
+/// Draw the focus ring around the focused button while it is visible.
+#[allow(clippy::needless_pass_by_value)]
+pub fn focus_ring_system(
+    mut commands: Commands,
+    focus: Res<MenuFocus>,
+    mut outlines: Query<(Entity, &mut Outline), With<Button>>,
+    buttons: Query<Entity, (With<Button>, Without<Outline>)>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+
+    let ring_target = focus.entity.filter(|_| focus.visible);
+
+    for (entity, mut outline) in &mut outlines {
+        let color = if Some(entity) == ring_target {
+            constants::FOCUS_RING_COLOR
+        } else {
+            Color::NONE
+        };
+        if outline.color != color {
+            outline.color = color;
+        }
+    }
+
+    // First time a button receives focus it gets its outline component.
+    if let Some(target) = ring_target {
+        if buttons.contains(target) {
+            commands.entity(target).insert(Outline::new(
+                Val::Px(constants::FOCUS_RING_WIDTH),
+                Val::Px(constants::FOCUS_RING_WIDTH),
+                constants::FOCUS_RING_COLOR,
+            ));
+        }
+    }
+}
@@ -0,0 +1,498 @@
+//! Environmental hazards that hurt the player whatever its size: jellyfish
+//! that sting and shrink it, fishing hooks that drop on a line from the
+//! surface and haul it out, and sea mines that explode.
+//!
+//! The enemy fish director (`moving_fish_spawn_system`) spawns a hazard in
+//! place of a fish now and then, drawn with the theme's [`HazardSprites`].
+//! Every hazard gives warning for [`Balance::hazard_telegraph`] seconds
+//! before it turns dangerous: it shows translucent and pulsing, a hook's
+//! line flashes where it is about to drop and a mine's lamp blinks faster and
+//! faster. Contact is tested against the hazard's alpha mask, like a fish's.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_light_2d::prelude::*;
+use rand::Rng;
+
+use crate::alpha_masks::AlphaMasks;
+use crate::assets::{EffectSprites, HazardSprites, ThemeSprite};
+use crate::balance::{
+    Balance, HOOK_DROP_SPEED, HOOK_REEL_SPEED, HOOK_WAIT, JELLYFISH_RISE, MINE_LIFETIME, MINE_SINK_SPEED, STING_COOLDOWN,
+};
+use crate::components::*;
+use crate::events::{DeathCause, PlayerDied, PlayerStung};
+use crate::settings::Accessibility;
+use crate::states::InGameState;
+use crate::systems::in_game::{broad_phase_radius, pixel_perfect_overlap, Cheats};
+use crate::systems::lighting::Depth;
+use crate::systems::resize::Playfield;
+use crate::theme::Theme;
+
+/// On-screen height of each hazard, in world units.
+const JELLYFISH_HEIGHT: f32 = 110.0;
+const HOOK_HEIGHT: f32 = 80.0;
+const MINE_HEIGHT: f32 = 90.0;
+
+/// Hazards sit just in front of the enemy fish and hook lines just behind
+/// them; a hook that caught the player is drawn over it.
+const HAZARD_Z: f32 = -0.4;
+const LINE_Z: f32 = -0.45;
+const CAUGHT_Z: f32 = 0.1;
+
+/// Opacity range of a hazard, or a hook's warning marker, while it gives
+/// warning.
+const TELEGRAPH_ALPHA: (f32, f32) = (0.25, 0.55);
+
+/// Rate of the warning pulse (Hz).
+const TELEGRAPH_PULSE: f32 = 2.0;
+
+/// Jellyfish: pulse rate (Hz) and how far the bell squashes as it pushes.
+/// Its rise speed, like the hook and mine timings, is in [`crate::balance`].
+const JELLYFISH_PULSE: f32 = 0.8;
+const JELLYFISH_SQUASH: f32 = 0.12;
+
+/// Width of the fishing line and of the warning marker before the drop.
+const LINE_WIDTH: f32 = 2.0;
+const WARNING_WIDTH: f32 = 6.0;
+const LINE_ALPHA: f32 = 0.8;
+
+/// How far lines reach above the playfield, so they never end in view.
+const LINE_OVERHANG: f32 = 400.0;
+
+/// Mine: bob amplitude while it floats at rest.
+const MINE_BOB: f32 = 8.0;
+
+/// Blink rate (Hz) of a mine's lamp at the start and end of its warning.
+const MINE_BLINK: (f32, f32) = (1.0, 4.0);
+const MINE_LAMP_COLOR: Color = Color::srgb(1.0, 0.2, 0.15);
+const MINE_LAMP_SIZE: f32 = 14.0;
+const MINE_LAMP_INTENSITY: f32 = 2.0;
+
+/// Rate (Hz) the player flashes at while it cannot be stung again.
+const STING_FLASH: f32 = 8.0;
+
+/// The three kinds of hazard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HazardKind {
+    Jellyfish,
+    Hook,
+    Mine,
+}
+
+/// Environmental hazard. Harmless until its warning time is up.
+#[derive(Component)]
+pub struct Hazard {
+    pub kind: HazardKind,
+    /// Sprite it is drawn with, for the collision alpha mask.
+    sprite: ThemeSprite,
+    /// Unscaled sprite size in pixels.
+    size: Vec2,
+    scale: f32,
+    /// Seconds since it appeared.
+    age: f32,
+    /// Seconds of warning before it becomes dangerous.
+    telegraph: f32,
+    /// Depth a hook drops to and a mine settles at.
+    rest_y: f32,
+    phase: f32,
+    /// A hook that caught the player and is hauling it out.
+    caught: bool,
+}
+
+impl Hazard {
+    /// Whether touching the hazard hurts.
+    pub fn armed(&self) -> bool {
+        self.age >= self.telegraph && !self.caught
+    }
+
+    /// Half the drawn size, in world units.
+    fn half_size(&self) -> Vec2 {
+        self.size * self.scale / 2.0
+    }
+}
+
+/// Fishing line (or, before the drop, warning marker) above a hook.
+#[derive(Component)]
+pub struct HookLine {
+    hook: Entity,
+}
+
+/// Blinking lamp on a mine.
+#[derive(Component)]
+pub struct MineLamp;
+
+/// The player fish recovering from a sting; it can't be stung again yet.
+#[derive(Component)]
+pub struct Stung {
+    remaining: f32,
+}
+
+/// Move `current` towards `target` by at most `max_step`.
+fn move_towards(current: f32, target: f32, max_step: f32) -> f32 {
+    current + (target - current).clamp(-max_step, max_step)
+}
+
+/// Opacity of the warning pulse `age` seconds in.
+fn telegraph_alpha(age: f32) -> f32 {
+    let pulse = 0.5 + 0.5 * (age * TELEGRAPH_PULSE * TAU).sin();
+    TELEGRAPH_ALPHA.0 + (TELEGRAPH_ALPHA.1 - TELEGRAPH_ALPHA.0) * pulse
+}
+
+/// Spawn a random hazard: a jellyfish fading in somewhere in the arena,
+/// a hook waiting above the surface or a mine sinking in from it.
+pub fn spawn_hazard(
+    commands: &mut Commands,
+    sprites: &HazardSprites,
+    effects: &EffectSprites,
+    images: &Assets<Image>,
+    balance: &Balance,
+    rng: &mut impl Rng,
+) {
+    let kind = match rng.gen_range(0..3) {
+        0 => HazardKind::Jellyfish,
+        1 => HazardKind::Hook,
+        _ => HazardKind::Mine,
+    };
+    let (source, height) = match kind {
+        HazardKind::Jellyfish => (&sprites.jellyfish, JELLYFISH_HEIGHT),
+        HazardKind::Hook => (&sprites.hook, HOOK_HEIGHT),
+        HazardKind::Mine => (&sprites.mine, MINE_HEIGHT),
+    };
+    let size = source.size(images).unwrap_or(Vec2::splat(64.0));
+    let scale = height / size.y;
+
+    let half = Playfield::arena_half_size();
+    // A theme's sprite may be wider than the arena; centre it then.
+    let reach = half.x - size.x * scale / 2.0;
+    let x = if reach > 0.0 { rng.gen_range(-reach..reach) } else { 0.0 };
+    let (y, rest_y) = match kind {
+        HazardKind::Jellyfish => {
+            let y = rng.gen_range(-half.y * 0.8..half.y * 0.3);
+            (y, y)
+        }
+        HazardKind::Hook | HazardKind::Mine => {
+            (half.y + height / 2.0, rng.gen_range(-half.y * 0.6..half.y * 0.4))
+        }
+    };
+
+    let mut sprite = source.sprite();
+    sprite.color.set_alpha(TELEGRAPH_ALPHA.0);
+    let hazard = commands
+        .spawn((
+            sprite,
+            Transform::from_xyz(x, y, HAZARD_Z).with_scale(Vec3::splat(scale)),
+            Hazard {
+                kind,
+                sprite: source.clone(),
+                size,
+                scale,
+                age: 0.0,
+                telegraph: balance.hazard_telegraph,
+                rest_y,
+                phase: rng.gen_range(0.0..TAU),
+                caught: false,
+            },
+        ))
+        .id();
+
+    match kind {
+        HazardKind::Jellyfish => {}
+        HazardKind::Hook => {
+            commands.spawn((
+                Sprite {
+                    custom_size: Some(Vec2::ZERO),
+                    ..default()
+                },
+                Transform::from_xyz(x, half.y, LINE_Z),
+                HookLine { hook: hazard },
+            ));
+        }
+        HazardKind::Mine => {
+            // Child of the mine, so sized against its scale.
+            commands.spawn((
+                Sprite {
+                    image: effects.speck.clone(),
+                    color: MINE_LAMP_COLOR,
+                    custom_size: Some(Vec2::splat(MINE_LAMP_SIZE / scale)),
+                    ..default()
+                },
+                PointLight2d {
+                    color: MINE_LAMP_COLOR,
+                    radius: MINE_HEIGHT * 2.0,
+                    intensity: 0.0,
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 0.0, 0.1),
+                MineLamp,
+                ChildOf(hazard),
+            ));
+        }
+    }
+}
+
+/// Move each hazard along its path, pulse it while it gives warning and
+/// remove it once it has left the arena.
+#[allow(clippy::needless_pass_by_value)]
+pub fn hazard_motion_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    accessibility: Res<Accessibility>,
+    mut hazards: Query<(Entity, &mut Hazard, &mut Transform, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+    let half = Playfield::arena_half_size();
+
+    for (entity, mut hazard, mut transform, mut sprite) in &mut hazards {
+        // A hook that caught the player follows it (see `hooked_system`).
+        if hazard.caught {
+            continue;
+        }
+        hazard.age += dt;
+        let age = hazard.age;
+        // Negative while the hazard still gives warning.
+        let armed_for = age - hazard.telegraph;
+        let half_size = hazard.half_size();
+
+        sprite.color.set_alpha(if armed_for < 0.0 { telegraph_alpha(age) } else { 1.0 });
+
+        let y = transform.translation.y;
+        let gone = match hazard.kind {
+            HazardKind::Jellyfish => {
+                // Rise in pulses once armed, the bell squashing as it pushes.
+                let beat = (age * JELLYFISH_PULSE * TAU + hazard.phase).sin().max(0.0);
+                if armed_for >= 0.0 {
+                    transform.translation.y += JELLYFISH_RISE * beat * dt;
+                }
+                let squash = if accessibility.reduced_motion {
+                    0.0
+                } else {
+                    JELLYFISH_SQUASH * beat
+                };
+                transform.scale.x = hazard.scale * (1.0 + squash);
+                transform.scale.y = hazard.scale * (1.0 - squash);
+                y - half_size.y > half.y
+            }
+            HazardKind::Hook => {
+                // Waits above the surface while its line warns, drops, then
+                // is reeled back in.
+                if armed_for >= HOOK_WAIT {
+                    transform.translation.y += HOOK_REEL_SPEED * dt;
+                    y - half_size.y > half.y
+                } else {
+                    if armed_for >= 0.0 {
+                        transform.translation.y = move_towards(y, hazard.rest_y, HOOK_DROP_SPEED * dt);
+                    }
+                    false
+                }
+            }
+            HazardKind::Mine => {
+                // Sinks in and bobs at its depth, then sinks away.
+                if armed_for >= MINE_LIFETIME {
+                    transform.translation.y -= MINE_SINK_SPEED * dt;
+                    y + half_size.y < -half.y
+                } else {
+                    let bob = if accessibility.reduced_motion {
+                        0.0
+                    } else {
+                        MINE_BOB * (age * 1.2 + hazard.phase).sin()
+                    };
+                    transform.translation.y = move_towards(y, hazard.rest_y + bob, MINE_SINK_SPEED * dt);
+                    false
+                }
+            }
+        };
+
+        if gone {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Flash a warning marker where a hook is about to drop, then draw its line
+/// from the surface. Lines whose hook is gone are removed.
+#[allow(clippy::needless_pass_by_value)]
+pub fn hook_line_system(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    playfield: Res<Playfield>,
+    hooks: Query<(&Hazard, &Transform)>,
+    mut lines: Query<(Entity, &HookLine, &mut Sprite, &mut Transform), Without<Hazard>>,
+) {
+    let top = playfield.half_size().y + LINE_OVERHANG;
+    let (r, g, b) = theme.hazards.line;
+
+    for (entity, line, mut sprite, mut transform) in &mut lines {
+        let Ok((hook, hook_tf)) = hooks.get(line.hook) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        let (bottom, width, alpha) = if hook.age < hook.telegraph {
+            // Down to where the hook will stop.
+            (hook.rest_y, WARNING_WIDTH, telegraph_alpha(hook.age))
+        } else {
+            // Tied to the top of the hook.
+            (hook_tf.translation.y + hook.half_size().y, LINE_WIDTH, LINE_ALPHA)
+        };
+        sprite.custom_size = Some(Vec2::new(width, (top - bottom).max(0.0)));
+        sprite.color = Color::srgba(r, g, b, alpha);
+        transform.translation = Vec3::new(hook_tf.translation.x, (top + bottom) / 2.0, LINE_Z);
+    }
+}
+
+/// Blink each mine's lamp faster and faster while it arms, then keep it lit.
+/// Its light only shows once the water has darkened.
+#[allow(clippy::needless_pass_by_value)]
+pub fn mine_lamp_system(
+    depth: Res<Depth>,
+    mines: Query<&Hazard>,
+    mut lamps: Query<(&ChildOf, &mut Sprite, &mut PointLight2d), With<MineLamp>>,
+) {
+    for (child_of, mut sprite, mut light) in &mut lamps {
+        let Ok(mine) = mines.get(child_of.parent()) else {
+            continue;
+        };
+        let lit = if mine.age < mine.telegraph {
+            // Blink cycles so far, with the rate rising linearly.
+            let (start, end) = MINE_BLINK;
+            let cycles = start * mine.age + (end - start) * mine.age * mine.age / (2.0 * mine.telegraph);
+            if cycles.fract() < 0.5 {
+                1.0
+            } else {
+                0.15
+            }
+        } else {
+            1.0
+        };
+        sprite.color.set_alpha(lit);
+        light.intensity = MINE_LAMP_INTENSITY * lit * depth.get();
+    }
+}
+
+/// Sting, hook or blow up the player fish when it touches an armed hazard,
+/// whatever its size. A sting shrinks it by [`Balance::sting_shrink`] (never
+/// below its starting `Meat`) and knocks it away; hooks and mines start the
+/// death sequence.
+#[allow(clippy::too_many_arguments, clippy::type_complexity, clippy::needless_pass_by_value)]
+pub fn hazard_collision_system(
+    mut commands: Commands,
+    mut player: Query<
+        (Entity, &Transform, &FishTexture, &mut Meat, &mut Velocity, &GrowthLevel, Has<Stung>),
+        (With<PlayerFish>, Without<Hazard>),
+    >,
+    mut hazards: Query<(Entity, &mut Hazard, &Transform), Without<PlayerFish>>,
+    images: Res<Assets<Image>>,
+    mut masks: ResMut<AlphaMasks>,
+    mut next_state: ResMut<NextState<InGameState>>,
+    mut stung_events: EventWriter<PlayerStung>,
+    mut died_events: EventWriter<PlayerDied>,
+    balance: Res<Balance>,
+    cheats: Option<Res<Cheats>>,
+) {
+    // Invincible, or a bigger fish got the player first this frame.
+    if cheats.is_some_and(|cheats| cheats.invincible) || matches!(*next_state, NextState::Pending(_)) {
+        return;
+    }
+    let Ok((player_entity, player_tf, player_tex, mut meat, mut velocity, growth, mut stung)) =
+        player.single_mut()
+    else {
+        return;
+    };
+    let position = player_tf.translation.truncate();
+    let player_radius = broad_phase_radius(meat.0);
+
+    for (entity, mut hazard, hazard_tf) in &mut hazards {
+        if !hazard.armed() || (hazard.kind == HazardKind::Jellyfish && stung) {
+            continue;
+        }
+        let away = position - hazard_tf.translation.truncate();
+        let radii = player_radius + hazard.half_size().max_element();
+        if away.length_squared() > radii * radii
+            || !pixel_perfect_overlap(player_tf, &player_tex.0, hazard_tf, &hazard.sprite, &images, &mut masks)
+        {
+            continue;
+        }
+
+        let cause = match hazard.kind {
+            HazardKind::Jellyfish => {
+                let shrunk = ((meat.0 as f32 * (1.0 - balance.sting_shrink)) as usize).max(growth.start_meat);
+                let meat_lost = meat.0.saturating_sub(shrunk);
+                meat.0 -= meat_lost;
+                velocity.0 = away.normalize_or(Vec2::Y) * balance.max_speed;
+                commands.entity(player_entity).insert(Stung {
+                    remaining: STING_COOLDOWN,
+                });
+                stung = true;
+                stung_events.write(PlayerStung {
+                    meat_lost,
+                    player_meat: meat.0,
+                    position,
+                });
+                continue;
+            }
+            HazardKind::Hook => {
+                hazard.caught = true;
+                DeathCause::Hooked
+            }
+            HazardKind::Mine => {
+                commands.entity(entity).despawn();
+                DeathCause::Exploded
+            }
+        };
+        died_events.write(PlayerDied {
+            cause,
+            killer: entity,
+            killer_meat: 0,
+            player_meat: meat.0,
+            position,
+        });
+        next_state.set(InGameState::Dying);
+        return;
+    }
+}
+
+/// Flash the player fish while it recovers from a sting.
+#[allow(clippy::needless_pass_by_value)]
+pub fn sting_recovery_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut player: Query<(Entity, &mut Stung, &mut Sprite), With<PlayerFish>>,
+) {
+    for (entity, mut stung, mut sprite) in &mut player {
+        stung.remaining -= time.delta_secs();
+        if stung.remaining <= 0.0 {
+            sprite.color.set_alpha(1.0);
+            commands.entity(entity).remove::<Stung>();
+        } else {
+            let dim = (stung.remaining * STING_FLASH * TAU).sin() > 0.0;
+            sprite.color.set_alpha(if dim { 0.45 } else { 1.0 });
+        }
+    }
+}
+
+/// Keep a hook that caught the player in its mouth while the fish is hauled
+/// out during the death sequence.
+#[allow(clippy::needless_pass_by_value)]
+pub fn hooked_system(
+    player: Query<&Transform, With<PlayerFish>>,
+    mut hooks: Query<(&Hazard, &mut Transform), Without<PlayerFish>>,
+) {
+    let Ok(player) = player.single() else {
+        return;
+    };
+    for (hook, mut transform) in &mut hooks {
+        if hook.caught {
+            let mouth = player.translation.truncate() + Vec2::Y * hook.half_size().y * 0.5;
+            transform.translation = mouth.extend(CAUGHT_Z);
+        }
+    }
+}
+
+/// Remove every hazard and line when gameplay ends.
+#[allow(clippy::type_complexity)]
+pub fn cleanup_hazards(mut commands: Commands, query: Query<Entity, Or<(With<Hazard>, With<HookLine>)>>) {
+    for e in &query {
+        commands.entity(e).despawn();
+    }
+}
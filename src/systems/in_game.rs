@@ -1,36 +1,35 @@
 use super::main_menu::spawn_menu_button;
+use crate::systems::hazards::spawn_hazard;
+use crate::systems::lighting::{Depth, LURE_DEPTH_REACH};
 use crate::systems::main_menu::BackgroundObscuraSpawner;
+use crate::systems::parallax::ParallaxRoots;
+use crate::systems::resize::Playfield;
 use crate::{
-    assets::{EnemyFishAssets, ObscuraAssets, PlayableFishTextures},
+    assets::{
+        EffectSprites, EnemyFishAssets, HazardSprites, ObscuraAssets, PlayableFishTextures, ThemeSprite,
+    },
     components::*,
-    constants,
     states::{GameState, InGameState},
 };
 use bevy::input::ButtonInput;
 use bevy::prelude::*;
-use rand::distributions::Distribution;
-use rand::Rng;
-use rand_distr::Exp1;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use bevy_light_2d::prelude::*;
 use bevy::input::touch::Touches;
 
 // ------------------------------------------------------------
 // New imports for Game Over overlay & score icon
 // ------------------------------------------------------------
-use crate::components::{GameOverMainMenuButton, GameOverUI};
+use crate::components::GameOverUI;
 use crate::alpha_masks::{AlphaMasks, AlphaMask};
+use crate::events::{DeathCause, FishEaten, PlayerDied, PlayerLeveledUp};
 use crate::theme::Theme;
-
-/// Base transform scale for a freshly spawned player fish.
-const PLAYER_BASE_SCALE: f32 = 0.05;
-
-/// Movement tuning parameters
-const MAX_SPEED: f32 = 500.0; // world units / second
-const ACCELERATION: f32 = 800.0; // world units / (second²) when mouse held
-const WATER_RESISTANCE: f32 = 1.0; // proportion of velocity lost per second
+use crate::settings::Accessibility;
+use crate::balance::Balance;
 
 /// Spawn Pause Menu UI.
-pub fn setup_pause_menu_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn setup_pause_menu_ui(mut commands: Commands) {
     // Semi-transparent overlay
     commands
         .spawn((
@@ -45,14 +44,15 @@ pub fn setup_pause_menu_ui(mut commands: Commands, asset_server: Res<AssetServer
             },
             BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
             PauseMenuUI,
+            FocusScope("pause_menu"),
         ))
         .with_children(|parent| {
-            spawn_menu_button(parent, &asset_server, "Continue", ContinueButton);
+            spawn_menu_button(parent, LocalizedText::new("menu.continue"), (MenuAction::Resume, DefaultFocus));
             // Button navigating back to the main menu
-            spawn_menu_button(parent, &asset_server, "Main Menu", PauseMainMenuButton);
+            spawn_menu_button(parent, LocalizedText::new("menu.main_menu"), MenuAction::MainMenu);
             // Skip Quit on web builds where closing the tab is easier / preferred.
             if !cfg!(target_arch = "wasm32") {
-                spawn_menu_button(parent, &asset_server, "Quit", QuitGameButton);
+                spawn_menu_button(parent, LocalizedText::new("menu.quit"), MenuAction::Quit);
             }
         });
 }
@@ -86,63 +86,6 @@ pub fn esc_to_resume_system(
     }
 }
 
-/// Handle Continue button to resume gameplay.
-pub fn continue_button_system(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<ContinueButton>),
-    >,
-    mut next_state: ResMut<NextState<InGameState>>, // Change nested state
-) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = constants::PRESSED_COLOR.into();
-                next_state.set(InGameState::Playing);
-            }
-            Interaction::Hovered => *color = constants::HOVER_COLOR.into(),
-            Interaction::None => *color = constants::IDLE_COLOR.into(),
-        }
-    }
-}
-
-/// Quit from pause menu.
-pub fn pause_quit_button_system(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<QuitGameButton>),
-    >,
-    mut exit: EventWriter<AppExit>,
-) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = constants::PRESSED_COLOR.into();
-                exit.send(AppExit::Success);
-            }
-            Interaction::Hovered => *color = constants::HOVER_COLOR.into(),
-            Interaction::None => *color = constants::IDLE_COLOR.into(),
-        }
-    }
-}
-
-/// Handle the "Main Menu" button in the pause menu.
-pub fn pause_main_menu_button_system(
-    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<PauseMainMenuButton>)>,
-    mut next_state: ResMut<NextState<GameState>>, // Switch top-level state
-) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = constants::PRESSED_COLOR.into();
-                next_state.set(GameState::MainMenu);
-            }
-            Interaction::Hovered => *color = constants::HOVER_COLOR.into(),
-            Interaction::None => *color = constants::IDLE_COLOR.into(),
-        }
-    }
-}
-
 // ---------------------------------------------------------------------
 // Fish-picker UI (shown when entering InGameState::FishPicker)
 // ---------------------------------------------------------------------
@@ -163,6 +106,7 @@ pub fn setup_fish_picker_ui(mut commands: Commands, textures: Res<PlayableFishTe
                 ..default()
             },
             FishPickerUI,
+            FocusScope("fish_picker"),
         ))
         .with_children(|parent| {
             // Spawn each fish option as an image button.
@@ -181,10 +125,12 @@ pub fn setup_fish_picker_ui(mut commands: Commands, textures: Res<PlayableFishTe
                     ..default()
                 },
                 ImageNode::new(textures.fish1.clone()),
-                BackgroundColor(constants::PICKER_IDLE_COLOR.into()),
+                BackgroundColor(ButtonPalette::FISH_PICKER.idle),
                 BorderColor(Color::BLACK),
                 BorderRadius::all(Val::Px(12.0)),
-                FishOption1,
+                ButtonPalette::FISH_PICKER,
+                MenuAction::SelectFish(0),
+                DefaultFocus,
             ));
 
             // Fish option 2
@@ -198,10 +144,11 @@ pub fn setup_fish_picker_ui(mut commands: Commands, textures: Res<PlayableFishTe
                     ..default()
                 },
                 ImageNode::new(textures.fish2.clone()),
-                BackgroundColor(constants::PICKER_IDLE_COLOR.into()),
+                BackgroundColor(ButtonPalette::FISH_PICKER.idle),
                 BorderColor(Color::BLACK),
                 BorderRadius::all(Val::Px(12.0)),
-                FishOption2,
+                ButtonPalette::FISH_PICKER,
+                MenuAction::SelectFish(1),
             ));
 
             // Fish option 3
@@ -215,10 +162,11 @@ pub fn setup_fish_picker_ui(mut commands: Commands, textures: Res<PlayableFishTe
                     ..default()
                 },
                 ImageNode::new(textures.fish3.clone()),
-                BackgroundColor(constants::PICKER_IDLE_COLOR.into()),
+                BackgroundColor(ButtonPalette::FISH_PICKER.idle),
                 BorderColor(Color::BLACK),
                 BorderRadius::all(Val::Px(12.0)),
-                FishOption3,
+                ButtonPalette::FISH_PICKER,
+                MenuAction::SelectFish(2),
             ));
         });
 }
@@ -231,113 +179,16 @@ pub fn cleanup_fish_picker(mut commands: Commands, query: Query<Entity, With<Fis
 }
 
 // ---------------------------------------------------------------------
-// Fish selection interaction
+// Fish selection
 // ---------------------------------------------------------------------
 
 /// Holds the texture handle of the fish chosen by the player.
 #[derive(Resource, Default, Clone)]
 pub struct SelectedFish(pub Option<Handle<Image>>);
 
-/// Generic system to handle interaction on a specific fish option.
-fn fish_option_interaction<M: Component>(
-    mut interaction_query: Query<
-        (&Interaction, Entity, &mut BackgroundColor),
-        (Changed<Interaction>, With<M>),
-    >,
-    _commands: Commands,
-    textures: Res<PlayableFishTextures>,
-    mut next_state: ResMut<NextState<InGameState>>, // Transition to Playing
-    mut selected: ResMut<SelectedFish>,             // store chosen fish
-) {
-    for (interaction, _entity, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = constants::PICKER_PRESSED_COLOR.into();
-
-                // Determine which fish texture corresponds to this marker type.
-                let handle = if std::any::TypeId::of::<M>() == std::any::TypeId::of::<FishOption1>()
-                {
-                    textures.fish1.clone()
-                } else if std::any::TypeId::of::<M>() == std::any::TypeId::of::<FishOption2>() {
-                    textures.fish2.clone()
-                } else {
-                    textures.fish3.clone()
-                };
-
-                selected.0 = Some(handle);
-
-                // We can despawn root UI here to avoid flicker but cleanup runs on state exit.
-                // commands.entity(entity).despawn_recursive();
-
-                next_state.set(InGameState::Playing);
-            }
-            Interaction::Hovered => {
-                *color = constants::PICKER_HOVER_COLOR.into();
-            }
-            Interaction::None => {
-                *color = constants::PICKER_IDLE_COLOR.into();
-            }
-        }
-    }
-}
-
-/// Wire up individual interaction systems for each fish option.
-pub fn fish_option1_system(
-    mut interaction_query: Query<
-        (&Interaction, Entity, &mut BackgroundColor),
-        (Changed<Interaction>, With<FishOption1>),
-    >,
-    commands: Commands,
-    textures: Res<PlayableFishTextures>,
-    next_state: ResMut<NextState<InGameState>>,
-    selected: ResMut<SelectedFish>,
-) {
-    fish_option_interaction::<FishOption1>(
-        interaction_query,
-        commands,
-        textures,
-        next_state,
-        selected,
-    );
-}
-
-pub fn fish_option2_system(
-    mut interaction_query: Query<
-        (&Interaction, Entity, &mut BackgroundColor),
-        (Changed<Interaction>, With<FishOption2>),
-    >,
-    commands: Commands,
-    textures: Res<PlayableFishTextures>,
-    next_state: ResMut<NextState<InGameState>>,
-    selected: ResMut<SelectedFish>,
-) {
-    fish_option_interaction::<FishOption2>(
-        interaction_query,
-        commands,
-        textures,
-        next_state,
-        selected,
-    );
-}
-
-pub fn fish_option3_system(
-    mut interaction_query: Query<
-        (&Interaction, Entity, &mut BackgroundColor),
-        (Changed<Interaction>, With<FishOption3>),
-    >,
-    commands: Commands,
-    textures: Res<PlayableFishTextures>,
-    next_state: ResMut<NextState<InGameState>>,
-    selected: ResMut<SelectedFish>,
-) {
-    fish_option_interaction::<FishOption3>(
-        interaction_query,
-        commands,
-        textures,
-        next_state,
-        selected,
-    );
-}
+/// What killed the player last, for the game over message.
+#[derive(Resource, Default, Clone, Copy)]
+pub struct LastDeathCause(pub Option<DeathCause>);
 
 // ---------------------------------------------------------------------
 // Player fish spawning & cleanup
@@ -347,10 +198,10 @@ pub fn fish_option3_system(
 pub fn spawn_player_fish_sprite(
     mut commands: Commands,
     images: Res<Assets<Image>>, // Needed to query image dimensions.
-    windows: Query<&Window>,
     selected: Res<SelectedFish>,
     textures: Res<PlayableFishTextures>,
     theme: Res<Theme>,
+    balance: Res<Balance>,
     existing_fish: Query<Entity, With<PlayerFish>>, // avoid duplicates
 ) {
     // Prevent spawning duplicate player fish when resuming from pause.
@@ -364,18 +215,7 @@ pub fn spawn_player_fish_sprite(
             let width = image.texture_descriptor.size.width as f32;
             let height = image.texture_descriptor.size.height as f32;
 
-            // Determine desired minimum rendered size based on window.
-            let window = windows.single().expect("No primary window");
-
-            let min_width = 64.0_f32.max(window.width() / 100.0);
-            let min_height = 64.0_f32.max(window.height() / 100.0);
-
-            // Scale factors required to reach the minimum size.
-            let scale_w = min_width / width;
-            let scale_h = min_height / height;
-
-            // Ensure uniform scaling and at least PLAYER_BASE_SCALE.
-            let desired_scale = scale_w.max(scale_h).max(PLAYER_BASE_SCALE);
+            let desired_scale = balance.player_start_scale(Vec2::new(width, height));
 
             let scaled_width = width * desired_scale;
             let scaled_height = height * desired_scale;
@@ -391,17 +231,15 @@ pub fn spawn_player_fish_sprite(
                 .insert(PlayerFish)
                 .insert(Meat(area))
                 .insert(BaseSpriteArea(base_area))
+                .insert(DisplayScale(desired_scale))
                 .insert(Velocity::default())
-                .insert(FishTexture(handle.clone()))
+                .insert(Swimming::default())
+                .insert(FishTexture(handle.clone().into()))
+                .insert(GrowthLevel::new(area))
                 .id();
 
-            if handle.id() == textures.fish3.id() {
-                // Theme-specific lure position (unscaled, px from top-left)
-                let (ox_px, oy_px) = match *theme {
-                    Theme::Chibi => (280.0, 205.0),
-                    Theme::Crayon => (350.0, 290.0),
-                    Theme::Retro => (180.0, 455.0),
-                };
+            // Theme-specific lure position (unscaled, px from top-left)
+            if let (true, Some((ox_px, oy_px))) = (handle.id() == textures.fish3.id(), theme.lure_offset) {
                 let offset_x = -(width / 2.0 - ox_px);
                 let offset_y = height / 2.0 - oy_px;
 
@@ -416,7 +254,12 @@ pub fn spawn_player_fish_sprite(
                             ..default()
                         },
                         Transform::from_xyz(offset_x, offset_y, 1.0),
-                        AnglerLight { base_x: offset_x, base_y: offset_y, base_radius: radius },
+                        AnglerLight {
+                            base_x: offset_x,
+                            base_y: offset_y,
+                            base_radius: radius,
+                            spawn_scale: desired_scale,
+                        },
                     ));
                 });
             }
@@ -429,23 +272,40 @@ pub fn spawn_player_fish_sprite(
     }
 }
 
+/// Rate (per second) at which the player's drawn size catches up with its
+/// `Meat` after a bite.
+const GROWTH_EASE_RATE: f32 = 6.0;
+
 /// System that keeps a player's fish visual scale in sync with its Meat value.
-/// If the meat changes (e.g., by eating in future gameplay), the sprite will grow
-/// or shrink accordingly so that `scaled_width * scaled_height == meat`.
+/// If the meat changes (e.g. by eating), the sprite eases towards the size
+/// where `scaled_width * scaled_height == meat` instead of snapping. Effects
+/// such as the chomp squash are applied on top of this every frame.
+#[allow(clippy::needless_pass_by_value)]
 pub fn update_player_fish_scale(
-    mut query: Query<(&Meat, &BaseSpriteArea, &mut Transform), With<PlayerFish>>,
+    time: Res<Time>,
+    mut query: Query<(&Meat, &BaseSpriteArea, &mut DisplayScale, &mut Transform), With<PlayerFish>>,
 ) {
-    for (meat, base_area, mut transform) in &mut query {
+    // Frame-rate independent exponential easing.
+    let ease = 1.0 - (-GROWTH_EASE_RATE * time.delta_secs()).exp();
+
+    for (meat, base_area, mut display, mut transform) in &mut query {
         if base_area.0 <= 0.0 {
             continue; // Avoid division by zero or invalid data.
         }
 
         // Desired uniform scale so that sprite area equals `meat`.
         let desired_scale = (meat.0 as f32 / base_area.0).sqrt();
+        if (desired_scale - display.0).abs() > 1e-4 {
+            display.0 += (desired_scale - display.0) * ease;
+        } else {
+            display.0 = desired_scale;
+        }
 
-        // Update the transform if it differs significantly to avoid tiny jitter.
-        if (desired_scale - transform.scale.x).abs() > f32::EPSILON {
-            transform.scale = Vec3::splat(desired_scale);
+        // Update the transform only when it differs to avoid needless change
+        // detection.
+        let scale = Vec3::splat(display.0);
+        if transform.scale != scale {
+            transform.scale = scale;
         }
     }
 }
@@ -478,10 +338,12 @@ pub fn esc_to_main_menu_from_picker_system(
 /// Accelerate the fish towards the mouse cursor while the left button is held or clicked.
 pub fn player_fish_acceleration_system(
     windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
     mouse_buttons: Res<ButtonInput<MouseButton>>, // Mouse input state
     touches: Res<Touches>,                        // NEW: active touches
     time: Res<Time>,
-    mut query: Query<(&Transform, &mut Velocity), With<PlayerFish>>,
+    balance: Res<Balance>,
+    mut query: Query<(&Transform, &mut Velocity, &mut Swimming), With<PlayerFish>>,
 ) {
     // ---------------------------------------------------------------------
     // Determine whether the player is providing *any* form of pointing
@@ -490,14 +352,19 @@ pub fn player_fish_acceleration_system(
     // ---------------------------------------------------------------------
 
     let touch_active = touches.iter().next().is_some();
+    let input_active = mouse_buttons.pressed(MouseButton::Left) || touch_active;
+
+    // Reported to effects (mouth bubbles) whatever happens below.
+    for (_, _, mut swimming) in &mut query {
+        swimming.set_if_neq(Swimming(input_active));
+    }
 
-    if !mouse_buttons.pressed(MouseButton::Left) && !touch_active {
+    if !input_active {
         return; // no acceleration unless input is active
     }
 
-    let window = match windows.single() {
-        Ok(w) => w,
-        Err(_) => return,
+    let (Ok(window), Ok((camera, camera_transform))) = (windows.single(), cameras.single()) else {
+        return;
     };
 
     // ---------------------------------------------------------------------
@@ -512,25 +379,17 @@ pub fn player_fish_acceleration_system(
         window.cursor_position()
     };
 
-    // Convert to world coordinates and apply acceleration towards that point.
-    if let Some(cursor) = cursor_screen {
-        let cursor_world = Vec2::new(
-            cursor.x - window.width() / 2.0,
-            window.height() / 2.0 - cursor.y,
-        );
+    // Convert to world coordinates (the camera scales the virtual playfield
+    // to the window) and apply acceleration towards that point.
+    let cursor_world = cursor_screen.and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok());
+    if let Some(cursor_world) = cursor_world {
 
-        for (transform, mut vel) in &mut query {
+        for (transform, mut vel, _) in &mut query {
             let pos = Vec2::new(transform.translation.x, transform.translation.y);
             let dir_vec = cursor_world - pos;
             if dir_vec.length_squared() > 1e-4 {
-                let dir = dir_vec.normalize();
-                // Apply acceleration scaled by frame time
-                vel.0 += dir * ACCELERATION * time.delta_secs();
-                // Clamp to max speed
-                let speed = vel.0.length();
-                if speed > MAX_SPEED {
-                    vel.0 = vel.0.normalize() * MAX_SPEED;
-                }
+                // Accelerate by frame time, clamped to the max speed
+                vel.0 = balance.accelerate(vel.0, dir_vec.normalize(), time.delta_secs());
             }
         }
     }
@@ -539,21 +398,18 @@ pub fn player_fish_acceleration_system(
 /// Apply velocity to position and simulate water resistance.
 pub fn player_fish_movement_system(
     time: Res<Time>,
+    balance: Res<Balance>,
     mut query: Query<(&mut Velocity, &mut Transform), With<PlayerFish>>,
 ) {
+    let arena = Playfield::arena_half_size();
     for (mut vel, mut transform) in &mut query {
-        // Update position
-        transform.translation.x += vel.0.x * time.delta_secs();
-        transform.translation.y += vel.0.y * time.delta_secs();
+        // Update position, staying inside the arena
+        let position = transform.translation.truncate() + vel.0 * time.delta_secs();
+        transform.translation = position.clamp(-arena, arena).extend(transform.translation.z);
 
-        // Apply simple linear damping representing water resistance
-        let decay = (1.0 - WATER_RESISTANCE * time.delta_secs()).max(0.0);
-        vel.0 *= decay;
-
-        // Threshold small velocities to zero to avoid jitter
-        if vel.0.length_squared() < 0.01 {
-            vel.0 = Vec2::ZERO;
-        }
+        // Simple linear damping representing water resistance; small
+        // velocities snap to zero to avoid jitter
+        vel.0 = balance.damp(vel.0, time.delta_secs());
     }
 }
 
@@ -580,18 +436,21 @@ pub fn player_fish_orientation_system(
 /// ---------------------------------------------------------------------
 
 /// Helper to spawn a single obscura sprite with randomised parameters.
+#[allow(clippy::too_many_arguments)]
 fn spawn_single_background_obscura_ingame(
     commands: &mut Commands,
     obscura: &ObscuraAssets,
     obscura_asset_index: usize,
-    window: &Window,
+    playfield: &Playfield,
     images: &Assets<Image>,
+    roots: &ParallaxRoots,
+    theme: &Theme,
     rng: &mut impl Rng,
     spawn_inside: bool,
 ) {
     use bevy::prelude::*;
 
-    let handle = obscura.images[obscura_asset_index].clone();
+    let source = &obscura.images[obscura_asset_index];
 
     // Randomise direction: 0 = right→left, 1 = left→right, 2 = bottom→top, 3 = top→bottom
     let dir = rng.gen_range(0..4);
@@ -611,8 +470,8 @@ fn spawn_single_background_obscura_ingame(
 
     // Start position & baseline perpendicular coordinate
     let (start_pos, base_perp) = if spawn_inside {
-        let half_w = window.width() / 2.0;
-        let half_h = window.height() / 2.0;
+        let half_w = playfield.width() / 2.0;
+        let half_h = playfield.height() / 2.0;
         let x = rng.gen_range(-half_w..half_w);
         let y = rng.gen_range(-half_h..half_h);
 
@@ -621,41 +480,43 @@ fn spawn_single_background_obscura_ingame(
     } else {
         match dir {
             0 => {
-                let x = window.width() / 2.0 + 100.0;
-                let y = rng.gen_range(-window.height() / 2.0..window.height() / 2.0);
+                let x = playfield.width() / 2.0 + 100.0;
+                let y = rng.gen_range(-playfield.height() / 2.0..playfield.height() / 2.0);
                 (Vec3::new(x, y, -1.0), y)
             }
             1 => {
-                let x = -window.width() / 2.0 - 100.0;
-                let y = rng.gen_range(-window.height() / 2.0..window.height() / 2.0);
+                let x = -playfield.width() / 2.0 - 100.0;
+                let y = rng.gen_range(-playfield.height() / 2.0..playfield.height() / 2.0);
                 (Vec3::new(x, y, -1.0), y)
             }
             2 => {
-                let y = -window.height() / 2.0 - 100.0;
-                let x = rng.gen_range(-window.width() / 2.0..window.width() / 2.0);
+                let y = -playfield.height() / 2.0 - 100.0;
+                let x = rng.gen_range(-playfield.width() / 2.0..playfield.width() / 2.0);
                 (Vec3::new(x, y, -1.0), x)
             }
             _ => {
-                let y = window.height() / 2.0 + 100.0;
-                let x = rng.gen_range(-window.width() / 2.0..window.width() / 2.0);
+                let y = playfield.height() / 2.0 + 100.0;
+                let x = rng.gen_range(-playfield.width() / 2.0..playfield.width() / 2.0);
                 (Vec3::new(x, y, -1.0), x)
             }
         }
     };
 
     // Scale so that the tallest side is at most 1/25th of the screen height.
-    let img_height = images
-        .get(&handle)
-        .map(|img| img.texture_descriptor.size.height as f32)
-        .unwrap_or(200.0);
+    let img_height = source.size(images).map_or(200.0, |size| size.y);
 
-    let desired_height = window.height() / 25.0;
+    let desired_height = Playfield::VIRTUAL_SIZE.y / 25.0;
     let uniform_scale = desired_height / img_height;
     let scale = Vec3::splat(uniform_scale);
 
+    // Mid-depth layer: scaled, tinted and parented to the obscura root.
+    let style = theme.parallax.obscura;
+    let mut sprite = source.sprite();
+    sprite.color = style.color();
+
     commands.spawn((
-        Sprite::from_image(handle.clone()),
-        Transform::from_translation(start_pos).with_scale(scale),
+        sprite,
+        Transform::from_translation(start_pos).with_scale(scale * style.scale),
         BackgroundObscura {
             velocity,
             wiggle_amp,
@@ -663,6 +524,7 @@ fn spawn_single_background_obscura_ingame(
             phase,
             base_perp,
         },
+        ChildOf(roots.obscura),
     ));
 }
 
@@ -670,8 +532,10 @@ fn spawn_single_background_obscura_ingame(
 pub fn spawn_background_obscura_initial_ingame(
     mut commands: Commands,
     obscura_opt: Option<Res<ObscuraAssets>>,
-    windows: Query<&Window>,
+    playfield: Res<Playfield>,
     images: Res<Assets<Image>>,
+    roots: Res<ParallaxRoots>,
+    theme: Res<Theme>,
     existing: Query<&BackgroundObscura>,
 ) {
     // If obscura already present (e.g. we just un-paused) skip to avoid duplicates.
@@ -688,7 +552,6 @@ pub fn spawn_background_obscura_initial_ingame(
         return;
     }
 
-    let window = windows.single().expect("No primary window");
     let mut rng = rand::thread_rng();
 
     let count = rng.gen_range(1..=2);
@@ -698,8 +561,10 @@ pub fn spawn_background_obscura_initial_ingame(
             &mut commands,
             &obscura,
             idx,
-            window,
+            &playfield,
             &images,
+            &roots,
+            &theme,
             &mut rng,
             true,
         );
@@ -707,14 +572,17 @@ pub fn spawn_background_obscura_initial_ingame(
 }
 
 /// Periodically spawn additional obscura during gameplay, keeping at most 2 on screen.
+#[allow(clippy::too_many_arguments)]
 pub fn background_obscura_spawn_system_ingame(
     mut commands: Commands,
     obscura_opt: Option<Res<ObscuraAssets>>,
-    windows: Query<&Window>,
+    playfield: Res<Playfield>,
     mut spawner: ResMut<BackgroundObscuraSpawner>,
     time: Res<Time>,
     existing: Query<&BackgroundObscura>,
     images: Res<Assets<Image>>,
+    roots: Res<ParallaxRoots>,
+    theme: Res<Theme>,
 ) {
     let Some(obscura) = obscura_opt else {
         return;
@@ -723,7 +591,6 @@ pub fn background_obscura_spawn_system_ingame(
         return;
     }
 
-    let window = windows.single().expect("No primary window");
     spawner.timer.tick(time.delta());
 
     // Maintain at most 2 obscura elements.
@@ -734,8 +601,10 @@ pub fn background_obscura_spawn_system_ingame(
             &mut commands,
             &obscura,
             idx,
-            window,
+            &playfield,
             &images,
+            &roots,
+            &theme,
             &mut rng,
             false,
         );
@@ -768,80 +637,119 @@ pub struct MovingFishSpawner {
     pub timer: Timer,
 }
 
-impl Default for MovingFishSpawner {
-    fn default() -> Self {
-        // Set first interval to exponential(1) seconds (mean 1 s).
-        let mut rng = rand::thread_rng();
-        let dur: f32 = Exp1.sample(&mut rng);
+impl MovingFishSpawner {
+    pub fn new(balance: &Balance, rng: &mut impl Rng) -> Self {
+        // Exponentially distributed first interval, like all later ones.
+        let dur = balance.next_spawn_delay(rng);
         Self {
             timer: Timer::from_seconds(dur, bevy::time::TimerMode::Once),
         }
     }
 }
 
-/// Spawn a single moving fish entity.
-fn spawn_single_moving_fish(
+/// Random number generator behind enemy fish spawning. Seedable so a run can
+/// be reproduced; decorative background fish and flora keep using
+/// `thread_rng`.
+#[derive(Resource)]
+pub struct GameRng(pub StdRng);
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self(StdRng::from_entropy())
+    }
+}
+
+impl GameRng {
+    #[cfg(feature = "dev")]
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+/// Developer cheats, toggled from the console (`dev` feature). The resource
+/// only exists in dev builds.
+#[derive(Resource, Default)]
+#[cfg_attr(not(feature = "dev"), allow(dead_code))]
+pub struct Cheats {
+    /// Bigger fish swim through the player instead of eating it, and hazards
+    /// leave it alone.
+    pub invincible: bool,
+    /// No new enemy fish or hazards spawn.
+    pub freeze_spawning: bool,
+}
+
+/// Spawn a single moving fish entity entering from a random side.
+pub fn spawn_single_moving_fish(
     commands: &mut Commands,
     enemy_assets: &EnemyFishAssets,
     images: &Assets<Image>,
-    window: &Window,
+    balance: &Balance,
+    rng: &mut impl Rng,
 ) {
     use bevy::prelude::*;
-    let mut rng = rand::thread_rng();
 
     if enemy_assets.images.is_empty() {
         return; // nothing to spawn
     }
-    let handle = enemy_assets.images[rng.gen_range(0..enemy_assets.images.len())].clone();
+    let index = rng.gen_range(0..enemy_assets.images.len());
+    let source = enemy_assets.images[index].clone();
 
     // Determine original image dimensions (fallback 200×200 if not yet loaded).
-    let (img_w, img_h) = images
-        .get(&handle)
-        .map(|img| {
-            let w = img.texture_descriptor.size.width as f32;
-            let h = img.texture_descriptor.size.height as f32;
-            (w, h)
-        })
+    let (img_w, img_h) = source
+        .size(images)
+        .map(|size| (size.x, size.y))
         .unwrap_or((200.0, 200.0));
 
-    // Target area range: between 0.1× player base area and 1/9th of screen area.
-    let screen_area = window.width() * window.height();
-    let min_area = img_w * img_h * PLAYER_BASE_SCALE * PLAYER_BASE_SCALE * 0.1;
-    let max_area = screen_area / 9.0;
+    // Area between a fraction of the player's base area and a fraction of the
+    // virtual playfield, biased towards small fish.
+    let scale = balance.enemy_scale(img_w * img_h, rng);
 
-    // Bias towards smaller fish by sampling with a quadratic distribution
-    // Generate a uniform [0,1) random, square it (values cluster near 0), then
-    // remap into [min_area, max_area].
-    let t: f32 = rng.gen::<f32>().powf(2.0);
-    let desired_area = min_area + t * (max_area - min_area);
-    let scale = (desired_area / (img_w * img_h)).sqrt();
+    // Decide side: true = left → right, false = right → left.
+    let rightwards = rng.gen_bool(0.5);
 
-    // Speed inversely correlated with size, plus randomness.
-    let base_speed = 25.0; // tuning constant
-    let speed = (base_speed / scale).clamp(10.0, 150.0) * rng.gen_range(0.8..1.2);
-
-    // Decide side: 0 = left → right, 1 = right → left.
-    let side = rng.gen_bool(0.5);
-
-    let half_sprite_width = images
-        .get(&handle)
-        .map(|img| img.texture_descriptor.size.width as f32)
-        .unwrap_or(300.0)
-        * scale
-        / 2.0;
-    let mut sprite = Sprite::from_image(handle.clone());
-    let (x, vx, flip_x) = if side {
-        (
-            -window.width() / 2.0 - half_sprite_width,
-            speed,
-            true, // sprite faces right (default faces left)
-        )
+    // Enter just outside the arena, not the visible area, so crossings take
+    // as long on every window shape.
+    let arena = Playfield::arena_half_size();
+    let half_sprite_width = source.size(images).map_or(300.0, |size| size.x) * scale / 2.0;
+    let x = if rightwards {
+        -arena.x - half_sprite_width
     } else {
-        (window.width() / 2.0 + half_sprite_width, -speed, false)
+        arena.x + half_sprite_width
     };
-    sprite.flip_x = flip_x;
+    let y = rng.gen_range(-arena.y..arena.y);
 
-    let y = rng.gen_range(-window.height() / 2.0..window.height() / 2.0);
+    let entity = spawn_moving_fish(commands, source, images, balance, scale, Vec2::new(x, y), rightwards, rng);
+    if enemy_assets.glowing.get(index).copied().unwrap_or(false) {
+        commands.entity(entity).insert(Glowing);
+    }
+}
+
+/// Spawn an ambient fish drawn with `source` at `position`, scaled by `scale`
+/// and swimming horizontally. Its `Meat` is its scaled area. Returns the new
+/// fish.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_moving_fish(
+    commands: &mut Commands,
+    source: ThemeSprite,
+    images: &Assets<Image>,
+    balance: &Balance,
+    scale: f32,
+    position: Vec2,
+    rightwards: bool,
+    rng: &mut impl Rng,
+) -> Entity {
+    let (img_w, img_h) = source
+        .size(images)
+        .map(|size| (size.x, size.y))
+        .unwrap_or((200.0, 200.0));
+
+    // Speed inversely correlated with size, plus randomness.
+    let speed = balance.enemy_speed(scale, rng);
+
+    let mut sprite = source.sprite();
+    // Sprites face left by default; flip those swimming right.
+    sprite.flip_x = rightwards;
+    let vx = if rightwards { speed } else { -speed };
 
     // Calculate meat (area in pixel² after scaling) for collision logic.
     let meat_val = (img_w * img_h * scale * scale) as usize;
@@ -851,43 +759,64 @@ fn spawn_single_moving_fish(
     let wiggle_speed = rng.gen_range(0.4..1.2); // Hz
     let phase = rng.gen_range(0.0..std::f32::consts::TAU);
 
-    commands.spawn((
-        sprite,
-        Transform::from_translation(Vec3::new(x, y, -0.5)).with_scale(Vec3::splat(scale)),
-        MovingFish {
-            velocity: Vec2::new(vx, 0.0),
-            wiggle_amp,
-            wiggle_speed,
-            phase,
-        },
-        Meat(meat_val),
-        FishTexture(handle.clone()),
-    ));
+    commands
+        .spawn((
+            sprite,
+            Transform::from_translation(position.extend(-0.5)).with_scale(Vec3::splat(scale)),
+            MovingFish {
+                velocity: Vec2::new(vx, 0.0),
+                wiggle_amp,
+                wiggle_speed,
+                phase,
+            },
+            Meat(meat_val),
+            FishTexture(source),
+        ))
+        .id()
 }
 
 /// Initialise the spawner when gameplay starts.
-pub fn setup_moving_fish_spawner(mut commands: Commands) {
-    commands.insert_resource(MovingFishSpawner::default());
+#[allow(clippy::needless_pass_by_value)]
+pub fn setup_moving_fish_spawner(mut commands: Commands, balance: Res<Balance>, mut rng: ResMut<GameRng>) {
+    commands.insert_resource(MovingFishSpawner::new(&balance, &mut rng.0));
 }
 
-/// Tick spawner timer and create new fish when needed.
+/// Tick spawner timer and create new fish when needed. Now and then a hazard
+/// comes instead (see [`Balance::hazard_chance`]).
+#[allow(clippy::too_many_arguments)]
 pub fn moving_fish_spawn_system(
     mut commands: Commands,
     mut spawner: ResMut<MovingFishSpawner>,
     time: Res<Time>,
     enemy_assets: Res<EnemyFishAssets>,
+    hazard_sprites: Res<HazardSprites>,
+    effect_sprites: Res<EffectSprites>,
     images: Res<Assets<Image>>,
-    windows: Query<&Window>,
+    balance: Res<Balance>,
+    mut rng: ResMut<GameRng>,
+    cheats: Option<Res<Cheats>>,
 ) {
-    let window = windows.single().expect("No primary window");
+    if cheats.is_some_and(|cheats| cheats.freeze_spawning) {
+        return;
+    }
 
     spawner.timer.tick(time.delta());
     if spawner.timer.finished() {
-        spawn_single_moving_fish(&mut commands, &enemy_assets, &images, window);
+        if rng.0.gen::<f32>() < balance.hazard_chance {
+            spawn_hazard(
+                &mut commands,
+                &hazard_sprites,
+                &effect_sprites,
+                &images,
+                &balance,
+                &mut rng.0,
+            );
+        } else {
+            spawn_single_moving_fish(&mut commands, &enemy_assets, &images, &balance, &mut rng.0);
+        }
 
-        // Schedule next interval from exponential(1) distribution.
-        let mut rng = rand::thread_rng();
-        let next: f32 = Exp1.sample(&mut rng);
+        // Schedule next interval from an exponential distribution.
+        let next = balance.next_spawn_delay(&mut rng.0);
         spawner
             .timer
             .set_duration(std::time::Duration::from_secs_f32(next));
@@ -895,24 +824,27 @@ pub fn moving_fish_spawn_system(
     }
 }
 
-/// Move ambient fish every frame and despawn when off-screen.
+/// Move ambient fish every frame and despawn once they have left the arena.
 pub fn moving_fish_movement_system(
     mut commands: Commands,
     mut query: Query<(Entity, &mut Transform, &MovingFish)>,
     time: Res<Time>,
-    windows: Query<&Window>,
+    accessibility: Res<Accessibility>,
 ) {
     let dt = time.delta_secs();
-    let window = windows.single().expect("No primary window");
-    let half_w = window.width() / 2.0;
+    let half_w = Playfield::arena_half_size().x;
 
     let elapsed = time.elapsed_secs();
 
     for (entity, mut transform, fish) in &mut query {
         transform.translation.x += fish.velocity.x * dt;
 
-        // Apply gentle rotational wiggle around Z axis
-        let angle = fish.wiggle_amp * (elapsed * fish.wiggle_speed + fish.phase).sin();
+        // Apply gentle rotational wiggle around Z axis (none with reduced motion)
+        let angle = if accessibility.reduced_motion {
+            0.0
+        } else {
+            fish.wiggle_amp * (elapsed * fish.wiggle_speed + fish.phase).sin()
+        };
         transform.rotation = Quat::from_rotation_z(angle);
 
         if transform.translation.x < -half_w - 150.0 || transform.translation.x > half_w + 150.0 {
@@ -941,7 +873,7 @@ pub fn spawn_meat_score_ui(
     asset_server: Res<AssetServer>,
     theme: Res<Theme>,
 ) {
-    let font = asset_server.load("fonts/Fredoka.ttf");
+    let font = asset_server.load(&theme.fonts.regular);
     let icon_handle = asset_server.load(theme.path("score_icon.png"));
 
     commands
@@ -1011,29 +943,53 @@ pub fn cleanup_meat_score_ui(mut commands: Commands, query: Query<Entity, With<M
 // Collision detection – player vs ambient moving fish
 // ------------------------------------------------------------
 
+/// Broad-phase collision radius: each fish is approximated with a circle whose
+/// radius is proportional to the square-root of its meat (treating the sprite
+/// as roughly square).
+pub fn broad_phase_radius(meat: usize) -> f32 {
+    (meat as f32).sqrt() * 0.5
+}
+
+/// Player fish data the collision test reads and grows.
+type PlayerBody = (
+    &'static Transform,
+    &'static FishTexture,
+    &'static mut Meat,
+    &'static mut GrowthLevel,
+);
+
+/// Enemy fish data the collision test reads.
+type EnemyBody = (Entity, &'static Transform, &'static FishTexture, &'static Meat);
+
 /// Detect collisions between the player's fish and moving enemy fish. If the
-/// enemy fish is smaller, despawn it and grow the player's meat by 10 % of the
-/// enemy's meat.
+/// enemy fish is smaller, despawn it and grow the player's meat by the
+/// balance's growth factor (25 %) of the enemy's meat. Outcomes are reported as
+/// [`FishEaten`], [`PlayerLeveledUp`] and [`PlayerDied`] events.
+#[allow(clippy::too_many_arguments)]
 pub fn collision_detection_system(
     mut commands: Commands,
-    mut player_q: Query<(&Transform, &FishTexture, &mut Meat), (With<PlayerFish>, Without<MovingFish>)>,
-    enemies: Query<(Entity, &Transform, &FishTexture, &Meat), (With<MovingFish>, Without<PlayerFish>)>,
+    mut player_q: Query<PlayerBody, (With<PlayerFish>, Without<MovingFish>)>,
+    enemies: Query<EnemyBody, (With<MovingFish>, Without<PlayerFish>)>,
     images: Res<Assets<Image>>, // for image info
     mut masks: ResMut<AlphaMasks>,
-    mut next_state: ResMut<NextState<InGameState>>, // For triggering GameOver
+    mut next_state: ResMut<NextState<InGameState>>, // For triggering the death sequence
+    mut eaten_events: EventWriter<FishEaten>,
+    mut died_events: EventWriter<PlayerDied>,
+    mut level_events: EventWriter<PlayerLeveledUp>,
+    balance: Res<Balance>,
+    cheats: Option<Res<Cheats>>,
 ) {
-    let Ok((player_tf, player_tex, mut player_meat)) = player_q.get_single_mut() else {
+    let invincible = cheats.is_some_and(|cheats| cheats.invincible);
+    let Ok((player_tf, player_tex, mut player_meat, mut growth)) = player_q.single_mut() else {
         return;
     };
     let player_handle = &player_tex.0;
 
-    // Approximate each fish with a circle whose radius is proportional to the
-    // square-root of its meat (treating the sprite as roughly square).
-    let player_radius = (player_meat.0 as f32).sqrt() * 0.5;
+    let player_radius = broad_phase_radius(player_meat.0);
 
     for (entity, enemy_tf, enemy_tex, enemy_meat) in enemies.iter() {
         let enemy_handle = &enemy_tex.0;
-        let enemy_radius = (enemy_meat.0 as f32).sqrt() * 0.5;
+        let enemy_radius = broad_phase_radius(enemy_meat.0);
 
         let dx = enemy_tf.translation.x - player_tf.translation.x;
         let dy = enemy_tf.translation.y - player_tf.translation.y;
@@ -1052,11 +1008,35 @@ pub fn collision_detection_system(
             ) {
                 // Confirmed overlap – evaluate size rule.
                 if enemy_meat.0 < player_meat.0 {
-                    // Increase by 25 % of the enemy's meat (rounded down).
-                    player_meat.0 += (enemy_meat.0 as f32 * 0.25) as usize;
+                    eaten_events.write(FishEaten {
+                        prey_meat: enemy_meat.0,
+                        player_meat: player_meat.0,
+                        position: enemy_tf.translation.truncate(),
+                    });
+
+                    // Increase by a fraction of the enemy's meat (rounded down).
+                    player_meat.0 += balance.meat_gain(enemy_meat.0);
                     commands.entity(entity).despawn();
-                } else {
-                    next_state.set(InGameState::GameOver);
+
+                    let level = growth.level_for(player_meat.0);
+                    if level > growth.level {
+                        growth.level = level;
+                        level_events.write(PlayerLeveledUp {
+                            level,
+                            player_meat: player_meat.0,
+                        });
+                    }
+                } else if !invincible {
+                    died_events.write(PlayerDied {
+                        cause: DeathCause::Eaten,
+                        killer: entity,
+                        killer_meat: enemy_meat.0,
+                        player_meat: player_meat.0,
+                        position: player_tf.translation.truncate(),
+                    });
+                    next_state.set(InGameState::Dying);
+                    // Dead: nothing else gets eaten (or eats us) this frame.
+                    return;
                 }
             }
         }
@@ -1064,23 +1044,23 @@ pub fn collision_detection_system(
 }
 
 /// Pixel-perfect overlap test ignoring rotation and using cached alpha masks.
-fn pixel_perfect_overlap(
+pub fn pixel_perfect_overlap(
     a_tf: &Transform,
-    a_handle: &Handle<Image>,
+    a_handle: &ThemeSprite,
     b_tf: &Transform,
-    b_handle: &Handle<Image>,
+    b_handle: &ThemeSprite,
     images: &Assets<Image>,
     masks: &mut AlphaMasks,
 ) -> bool {
     // Ensure both masks are present in the cache, inserting if necessary.
     if !masks.0.contains_key(a_handle) {
-        if let Some(img) = images.get(a_handle) {
-            masks.0.insert(a_handle.clone(), AlphaMask::from_image(img));
+        if let Some(img) = images.get(&a_handle.image) {
+            masks.0.insert(a_handle.clone(), AlphaMask::from_image(img, a_handle.rect));
         }
     }
     if !masks.0.contains_key(b_handle) {
-        if let Some(img) = images.get(b_handle) {
-            masks.0.insert(b_handle.clone(), AlphaMask::from_image(img));
+        if let Some(img) = images.get(&b_handle.image) {
+            masks.0.insert(b_handle.clone(), AlphaMask::from_image(img, b_handle.rect));
         }
     }
 
@@ -1132,11 +1112,23 @@ fn pixel_perfect_overlap(
 // Game Over overlay UI
 // ---------------------------------------------------------------------
 
-/// Spawn the Game Over UI overlay when entering `InGameState::GameOver`.
-pub fn setup_game_over_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let font_bold = asset_server.load("fonts/Fredoka-Bold.ttf");
-    let font = asset_server.load("fonts/Fredoka.ttf");
+/// Remember the cause of the player's death for the Game Over overlay.
+pub fn record_death_cause_system(mut died: EventReader<PlayerDied>, mut last: ResMut<LastDeathCause>) {
+    if let Some(event) = died.read().last() {
+        last.0 = Some(event.cause);
+    }
+}
 
+/// Spawn the Game Over UI overlay when entering `InGameState::GameOver`. It
+/// starts transparent with its button hidden; `game_over_fade_system` fades
+/// it in.
+#[allow(clippy::needless_pass_by_value)]
+pub fn setup_game_over_ui(mut commands: Commands, cause: Res<LastDeathCause>) {
+    let message = match cause.0 {
+        Some(DeathCause::Hooked) => "game_over.hooked",
+        Some(DeathCause::Exploded) => "game_over.exploded",
+        Some(DeathCause::Eaten) | None => "game_over.eaten",
+    };
     commands
         .spawn((
             Node {
@@ -1148,34 +1140,40 @@ pub fn setup_game_over_ui(mut commands: Commands, asset_server: Res<AssetServer>
                 align_items: AlignItems::Center,
                 ..default()
             },
-            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)), // semi-transparent black
+            BackgroundColor(Color::NONE), // fades to semi-transparent black
             GameOverUI,
+            GameOverFade::default(),
+            FocusScope("game_over"),
         ))
         .with_children(|parent| {
             parent.spawn((
-                Text::new("Game Over!"),
+                Text::default(),
                 TextFont {
-                    font: font_bold.clone(),
                     font_size: 72.0,
                     ..default()
                 },
-                TextColor(Color::WHITE),
+                TextColor(Color::WHITE.with_alpha(0.0)),
                 TextLayout::new_with_justify(JustifyText::Center),
+                LocalizedText::bold("game_over.title"),
             ));
 
             parent.spawn((
-                Text::new("You were eaten by a fish that's bigger than you!"),
+                Text::default(),
                 TextFont {
-                    font,
                     font_size: 28.0,
                     ..default()
                 },
-                TextColor(Color::WHITE),
+                TextColor(Color::WHITE.with_alpha(0.0)),
                 TextLayout::new_with_justify(JustifyText::Center),
+                LocalizedText::new(message),
             ));
 
             // Main Menu button
-            spawn_menu_button(parent, &asset_server, "Main Menu", GameOverMainMenuButton);
+            spawn_menu_button(
+                parent,
+                LocalizedText::new("menu.main_menu"),
+                (MenuAction::MainMenu, DefaultFocus, Visibility::Hidden),
+            );
         });
 }
 
@@ -1186,32 +1184,14 @@ pub fn cleanup_game_over(mut commands: Commands, query: Query<Entity, With<GameO
     }
 }
 
-/// Handle the "Main Menu" button on the Game Over screen.
-pub fn game_over_main_menu_button_system(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<GameOverMainMenuButton>),
-    >,
-    mut next_state: ResMut<NextState<GameState>>, // Switch to main menu
-) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = constants::PRESSED_COLOR.into();
-                next_state.set(GameState::MainMenu);
-            }
-            Interaction::Hovered => *color = constants::HOVER_COLOR.into(),
-            Interaction::None => *color = constants::IDLE_COLOR.into(),
-        }
-    }
-}
-
 // Add component to track angler light offset
 #[derive(Component)]
 pub struct AnglerLight {
     base_x: f32,
     base_y: f32,
     base_radius: f32, // NEW: store the spawn radius
+    /// Player scale when the light was spawned; the glow grows with the fish.
+    spawn_scale: f32,
 }
 
 // System to mirror light when fish flips direction
@@ -1230,16 +1210,21 @@ pub fn update_angler_light_position(
     }
 }
 
-// System to animate angler light intensity and radius
+// System to animate angler light intensity and radius. The lure shines
+// brighter and further the deeper the player is.
+#[allow(clippy::needless_pass_by_value)]
 pub fn animate_angler_light_system(
     time: Res<Time>,
+    depth: Res<Depth>,
+    player: Query<&DisplayScale, With<PlayerFish>>,
     mut query: Query<(&mut PointLight2d, &AnglerLight)>,
 ) {
     let t = time.elapsed_secs();
-    let intensity = 3.5 + 2.5 * (t * 1.2).sin();
-    let radius_coeff = 0.5 + 0.05 * (t * 1.2).sin();
+    let intensity = (3.5 + 2.5 * (t * 1.2).sin()) * (1.0 + depth.get());
+    let radius_coeff = (0.5 + 0.05 * (t * 1.2).sin()) * (1.0 + LURE_DEPTH_REACH * depth.get());
     for (mut light, angler) in &mut query {
+        let growth = player.single().map_or(1.0, |scale| scale.0 / angler.spawn_scale);
         light.intensity = intensity;
-        light.radius = angler.base_radius * radius_coeff;
+        light.radius = angler.base_radius * radius_coeff * growth;
     }
 }
@@ -0,0 +1,298 @@
+//! Depth lighting built on `bevy_light_2d`.
+//!
+//! The player sinks deeper as it grows: [`Depth`] follows its level towards
+//! the theme's `deep_level` and the camera's ambient light dims and tints with
+//! it ([`crate::theme::ThemeLighting`]). Light shafts fall from the surface
+//! while the water is shallow, glowing species light themselves up, and in
+//! the dark the angler fish's lure draws smaller fish towards its mouth.
+
+use bevy::prelude::*;
+use bevy_light_2d::prelude::*;
+use rand::Rng;
+
+use crate::assets::EffectSprites;
+use crate::components::*;
+use crate::settings::Accessibility;
+use crate::systems::in_game::{AnglerLight, MovingFish};
+use crate::systems::resize::Playfield;
+use crate::theme::Theme;
+
+/// How quickly [`Depth`] follows the player's level (1/s); slow, so the
+/// water darkens gradually.
+const DEPTH_EASE_RATE: f32 = 0.4;
+
+/// High contrast keeps the scene at least this bright.
+const HIGH_CONTRAST_MIN_BRIGHTNESS: f32 = 0.6;
+
+/// Light shafts from the surface, their opacity range and how far they sway
+/// (radians).
+const GOD_RAY_COUNT: usize = 4;
+const GOD_RAY_ALPHA: (f32, f32) = (0.06, 0.14);
+const GOD_RAY_SWAY: f32 = 0.05;
+
+/// Shafts sit in front of the background layers, behind every fish.
+const GOD_RAY_Z: f32 = -1.2;
+
+/// Intensity of the light under each shaft once the water has darkened.
+const SURFACE_LIGHT_INTENSITY: f32 = 1.5;
+
+/// Intensity of a glowing fish's light in the deepest zone.
+const GLOW_INTENSITY: f32 = 2.5;
+
+/// Share by which the lure's reach grows in the deepest zone.
+pub const LURE_DEPTH_REACH: f32 = 1.0;
+
+/// Speed (world units / s) at which the lure draws a fish at its centre in
+/// the deepest zone.
+const LURE_PULL_SPEED: f32 = 90.0;
+
+/// How deep the player is, from 0 at the surface to 1 in the deepest zone.
+#[derive(Resource, Default)]
+pub struct Depth {
+    current: f32,
+}
+
+impl Depth {
+    pub fn get(&self) -> f32 {
+        self.current
+    }
+}
+
+/// Light shaft falling from the surface.
+#[derive(Component)]
+pub struct GodRay {
+    /// Horizontal position as a share of the playfield half-width (-1‒1).
+    anchor_x: f32,
+    tilt: f32,
+    phase: f32,
+    alpha: f32,
+}
+
+/// Point light at the top of a [`GodRay`], brightening the water under the
+/// surface once the rest of the scene has darkened.
+#[derive(Component)]
+pub struct SurfaceLight {
+    anchor_x: f32,
+}
+
+/// Light carried by a [`Glowing`] fish.
+#[derive(Component)]
+pub struct GlowLight {
+    phase: f32,
+}
+
+/// Ambient brightness at `depth` for the current theme.
+fn ambient_brightness(theme: &Theme, depth: f32, accessibility: &Accessibility) -> f32 {
+    let lighting = &theme.lighting;
+    let brightness = lighting.surface + (lighting.deep - lighting.surface) * depth;
+    if accessibility.high_contrast {
+        brightness.max(HIGH_CONTRAST_MIN_BRIGHTNESS)
+    } else {
+        brightness
+    }
+}
+
+/// Follow the player's level down, and come back up outside gameplay.
+#[allow(clippy::needless_pass_by_value)]
+pub fn depth_system(
+    time: Res<Time>,
+    theme: Res<Theme>,
+    mut depth: ResMut<Depth>,
+    player: Query<&GrowthLevel, With<PlayerFish>>,
+) {
+    let deep_level = theme.lighting.deep_level.max(1) as f32;
+    let target = player
+        .single()
+        .map_or(0.0, |growth| (growth.level as f32 / deep_level).min(1.0));
+    let ease = 1.0 - (-DEPTH_EASE_RATE * time.delta_secs()).exp();
+    depth.current += (target - depth.current) * ease;
+}
+
+/// Dim and tint the camera's ambient light with depth.
+#[allow(clippy::needless_pass_by_value)]
+pub fn ambient_light_system(
+    depth: Res<Depth>,
+    theme: Res<Theme>,
+    accessibility: Res<Accessibility>,
+    mut cameras: Query<&mut AmbientLight2d>,
+) {
+    let (r, g, b) = theme.lighting.deep_tint;
+    let color = Color::WHITE.mix(&Color::srgb(r, g, b), depth.get());
+    let brightness = ambient_brightness(&theme, depth.get(), &accessibility);
+    for mut ambient in &mut cameras {
+        ambient.color = color;
+        ambient.brightness = brightness;
+    }
+}
+
+/// Spawn light shafts from the surface when gameplay starts.
+#[allow(clippy::needless_pass_by_value)]
+pub fn spawn_god_rays(
+    mut commands: Commands,
+    sprites: Res<EffectSprites>,
+    existing: Query<(), With<GodRay>>,
+) {
+    // Resuming from the pause menu re-enters `Playing`.
+    if !existing.is_empty() {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    for i in 0..GOD_RAY_COUNT {
+        // Spread the shafts out, each jittered within its slot.
+        let slot = 2.0 / GOD_RAY_COUNT as f32;
+        let anchor_x = -1.0 + slot * (i as f32 + rng.gen_range(0.2..0.8));
+        commands.spawn((
+            Sprite {
+                image: sprites.ray.clone(),
+                ..default()
+            },
+            Transform::default(),
+            GodRay {
+                anchor_x,
+                tilt: rng.gen_range(-0.3..-0.1),
+                phase: rng.gen_range(0.0..std::f32::consts::TAU),
+                alpha: rng.gen_range(GOD_RAY_ALPHA.0..GOD_RAY_ALPHA.1),
+            },
+        ));
+        commands.spawn((
+            PointLight2d {
+                color: Color::srgb(0.85, 0.95, 1.0),
+                radius: 400.0,
+                falloff: 2.0,
+                intensity: 0.0,
+                ..default()
+            },
+            Transform::default(),
+            SurfaceLight { anchor_x },
+        ));
+    }
+}
+
+/// Lay the shafts out over the playfield, sway them and fade them out as the
+/// player sinks; the lights under them grow brighter as the water darkens,
+/// then fade out too.
+#[allow(clippy::needless_pass_by_value)]
+pub fn god_ray_system(
+    time: Res<Time>,
+    depth: Res<Depth>,
+    theme: Res<Theme>,
+    accessibility: Res<Accessibility>,
+    playfield: Res<Playfield>,
+    mut rays: Query<(&mut Sprite, &mut Transform, &GodRay)>,
+    mut lights: Query<(&mut PointLight2d, &mut Transform, &SurfaceLight), Without<GodRay>>,
+) {
+    let half = playfield.half_size();
+    let shallow = 1.0 - depth.get();
+    let t = time.elapsed_secs();
+
+    for (mut sprite, mut transform, ray) in &mut rays {
+        let height = playfield.height() * 0.9;
+        sprite.custom_size = Some(Vec2::new(playfield.width() / 8.0, height));
+        sprite.color = Color::WHITE.with_alpha(ray.alpha * shallow);
+
+        let sway = if accessibility.reduced_motion {
+            0.0
+        } else {
+            GOD_RAY_SWAY * (t * 0.3 + ray.phase).sin()
+        };
+        let angle = ray.tilt + sway;
+        // Rotate about the top edge so the shaft stays pinned to the surface.
+        let top = Vec2::new(ray.anchor_x * half.x, half.y);
+        let centre = top + Vec2::new(0.0, -height / 2.0).rotate(Vec2::from_angle(angle));
+        transform.translation = centre.extend(GOD_RAY_Z);
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+
+    let darkness = 1.0 - ambient_brightness(&theme, depth.get(), &accessibility);
+    for (mut light, mut transform, surface) in &mut lights {
+        transform.translation = Vec3::new(surface.anchor_x * half.x, half.y, 0.0);
+        light.intensity = SURFACE_LIGHT_INTENSITY * darkness.max(0.0) * shallow;
+    }
+}
+
+/// Give newly spawned glowing fish their light.
+#[allow(clippy::needless_pass_by_value)]
+pub fn attach_glow_lights_system(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    fish: Query<(Entity, &Meat), Added<Glowing>>,
+) {
+    let (r, g, b) = theme.lighting.glow;
+    let mut rng = rand::thread_rng();
+    for (entity, meat) in &fish {
+        let light = commands
+            .spawn((
+                PointLight2d {
+                    color: Color::srgb(r, g, b),
+                    radius: (meat.0 as f32).sqrt() * 1.2,
+                    intensity: 0.0,
+                    ..default()
+                },
+                Transform::default(),
+                GlowLight {
+                    phase: rng.gen_range(0.0..std::f32::consts::TAU),
+                },
+            ))
+            .id();
+        commands.entity(entity).add_child(light);
+    }
+}
+
+/// Pulse glowing fish, which only shine once the water is dark.
+#[allow(clippy::needless_pass_by_value)]
+pub fn glow_light_system(
+    time: Res<Time>,
+    depth: Res<Depth>,
+    mut lights: Query<(&mut PointLight2d, &GlowLight)>,
+) {
+    let t = time.elapsed_secs();
+    for (mut light, glow) in &mut lights {
+        light.intensity = GLOW_INTENSITY * depth.get() * (1.0 + 0.25 * (t * 2.0 + glow.phase).sin());
+    }
+}
+
+/// In dark water, smaller fish swim towards the angler's lure, harder the
+/// closer they are.
+#[allow(clippy::needless_pass_by_value)]
+pub fn lure_attraction_system(
+    time: Res<Time>,
+    depth: Res<Depth>,
+    lures: Query<(&GlobalTransform, &PointLight2d), With<AnglerLight>>,
+    player: Query<&Meat, With<PlayerFish>>,
+    mut fish: Query<(&mut Transform, &Meat), With<MovingFish>>,
+) {
+    let (Ok((lure, light)), Ok(player_meat)) = (lures.single(), player.single()) else {
+        return;
+    };
+    if depth.get() <= 0.0 {
+        return;
+    }
+
+    let lure = lure.translation().truncate();
+    let pull = LURE_PULL_SPEED * depth.get() * time.delta_secs();
+    for (mut transform, meat) in &mut fish {
+        if meat.0 >= player_meat.0 {
+            continue;
+        }
+        let to_lure = lure - transform.translation.truncate();
+        let distance = to_lure.length();
+        if distance < light.radius && distance > 1.0 {
+            let step = to_lure / distance * pull * (1.0 - distance / light.radius);
+            transform.translation += step.extend(0.0);
+        }
+    }
+}
+
+/// Remove the surface lights and return to the surface when gameplay ends.
+#[allow(clippy::type_complexity)]
+pub fn cleanup_lighting(
+    mut commands: Commands,
+    mut depth: ResMut<Depth>,
+    query: Query<Entity, Or<(With<GodRay>, With<SurfaceLight>)>>,
+) {
+    for e in &query {
+        commands.entity(e).despawn();
+    }
+    *depth = Depth::default();
+}
@@ -0,0 +1,379 @@
+use bevy::asset::{LoadState, LoadedFolder, UntypedAssetId};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::assets::{
+    EnemyFishAssets, FloraAssets, ObscuraAssets, PlayableFishTextures, PrefetchedAssets, ThemeSelectAudio,
+    ThemeSprite,
+};
+use crate::components::*;
+use crate::states::GameState;
+use crate::systems::spawn_menu_button;
+use crate::theme::Theme;
+
+/// Seconds on the loading splash before the "continue anyway" button appears.
+const CONTINUE_ANYWAY_AFTER_SECS: f32 = 15.0;
+
+/// Failed paths listed on the error screen before the rest are summarised.
+const MAX_LISTED_FAILURES: usize = 6;
+
+/// Groups of assets the loading splash waits for, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadingCategory {
+    PlayableFish,
+    EnemyFish,
+    Obscura,
+    Flora,
+    Audio,
+    ThemeFiles,
+}
+
+impl LoadingCategory {
+    /// String table key naming the category on the splash.
+    pub const fn label_key(&self) -> &'static str {
+        match self {
+            LoadingCategory::PlayableFish => "loading.playable_fish",
+            LoadingCategory::EnemyFish => "loading.enemy_fish",
+            LoadingCategory::Obscura => "loading.obscura",
+            LoadingCategory::Flora => "loading.flora",
+            LoadingCategory::Audio => "loading.audio",
+            LoadingCategory::ThemeFiles => "loading.theme_files",
+        }
+    }
+
+    /// Whether the game cannot go on without this category. Failed sprites of
+    /// the other categories are drawn as a placeholder, failed sounds stay
+    /// silent.
+    pub const fn is_required(&self) -> bool {
+        matches!(self, LoadingCategory::PlayableFish)
+    }
+}
+
+/// Loaded and failed versus total assets of one [`LoadingCategory`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CategoryProgress {
+    pub loaded: usize,
+    pub failed: usize,
+    pub total: usize,
+}
+
+impl CategoryProgress {
+    /// Whether every asset has either loaded or failed.
+    pub fn is_complete(&self) -> bool {
+        self.loaded + self.failed >= self.total
+    }
+}
+
+/// An asset whose load ended in [`LoadState::Failed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedAsset {
+    pub category: LoadingCategory,
+    pub path: String,
+}
+
+/// Progress of the start-up asset loading, refreshed every frame on the
+/// loading splash.
+#[derive(Resource, Default)]
+pub struct LoadingProgress {
+    pub categories: Vec<(LoadingCategory, CategoryProgress)>,
+    /// Assets that failed to load, in category order.
+    pub failed: Vec<FailedAsset>,
+    /// Seconds spent on the splash so far.
+    pub elapsed: f32,
+}
+
+impl LoadingProgress {
+    pub fn loaded(&self) -> usize {
+        self.categories.iter().map(|(_, p)| p.loaded).sum()
+    }
+
+    pub fn total(&self) -> usize {
+        self.categories.iter().map(|(_, p)| p.total).sum()
+    }
+
+    /// Share of assets settled (loaded or failed), 0‒1.
+    pub fn fraction(&self) -> f32 {
+        match self.total() {
+            0 => 0.0,
+            total => (self.loaded() + self.failed.len()) as f32 / total as f32,
+        }
+    }
+
+    /// Failed assets the game cannot do without.
+    pub fn required_failures(&self) -> impl Iterator<Item = &FailedAsset> {
+        self.failed.iter().filter(|asset| asset.category.is_required())
+    }
+
+    /// Whether every asset has loaded or failed (false before the first
+    /// update).
+    pub fn is_complete(&self) -> bool {
+        !self.categories.is_empty() && self.categories.iter().all(|(_, p)| p.is_complete())
+    }
+
+    /// First category that is still loading.
+    pub fn pending(&self) -> Option<(LoadingCategory, CategoryProgress)> {
+        self.categories.iter().copied().find(|(_, p)| !p.is_complete())
+    }
+}
+
+/// Every asset collection the loading splash waits for.
+#[derive(SystemParam)]
+pub struct StartupAssets<'w> {
+    asset_server: Res<'w, AssetServer>,
+    folders: Res<'w, Assets<LoadedFolder>>,
+    playable: Res<'w, PlayableFishTextures>,
+    enemy: Res<'w, EnemyFishAssets>,
+    obscura: Res<'w, ObscuraAssets>,
+    flora: Res<'w, FloraAssets>,
+    audio: Res<'w, ThemeSelectAudio>,
+    prefetched: Res<'w, PrefetchedAssets>,
+    theme: Res<'w, Theme>,
+}
+
+impl StartupAssets<'_> {
+    /// Count loaded and failed assets among `ids`, recording the failures.
+    fn count<I: Into<UntypedAssetId>>(
+        &self,
+        category: LoadingCategory,
+        ids: impl IntoIterator<Item = I>,
+        failed: &mut Vec<FailedAsset>,
+    ) -> CategoryProgress {
+        let mut progress = CategoryProgress::default();
+        for id in ids {
+            let id = id.into();
+            progress.total += 1;
+            match self.asset_server.get_load_state(id) {
+                // Assets added directly instead of loaded (placeholders).
+                None | Some(LoadState::Loaded) => progress.loaded += 1,
+                Some(LoadState::Failed(_)) => {
+                    progress.failed += 1;
+                    let path = self
+                        .asset_server
+                        .get_path(id)
+                        .map_or_else(|| format!("{id:?}"), |path| path.to_string());
+                    failed.push(FailedAsset { category, path });
+                }
+                Some(_) => {}
+            }
+        }
+        progress
+    }
+
+    /// Prefetched folders count every file inside them once the folder has
+    /// been listed, and a single pending asset until then.
+    fn prefetched_progress(&self, failed: &mut Vec<FailedAsset>) -> CategoryProgress {
+        let category = LoadingCategory::ThemeFiles;
+        let mut progress = CategoryProgress::default();
+        for handle in self.prefetched.startup_handles(self.theme.id) {
+            let folder = handle
+                .clone()
+                .try_typed::<LoadedFolder>()
+                .ok()
+                .and_then(|folder| self.folders.get(&folder));
+            let files = match folder {
+                Some(folder) => self.count(category, folder.handles.iter().map(|h| h.id()), failed),
+                None => self.count(category, [handle.id()], failed),
+            };
+            progress.loaded += files.loaded;
+            progress.failed += files.failed;
+            progress.total += files.total;
+        }
+        progress
+    }
+
+    fn progress(&self) -> (Vec<(LoadingCategory, CategoryProgress)>, Vec<FailedAsset>) {
+        use LoadingCategory::*;
+
+        let mut failed = Vec::new();
+        let playable = [&self.playable.fish1, &self.playable.fish2, &self.playable.fish3];
+        let sprites = |images: &[ThemeSprite]| images.iter().map(|s| s.image.id()).collect::<Vec<_>>();
+        let categories = vec![
+            (PlayableFish, self.count(PlayableFish, playable.map(|h| h.id()), &mut failed)),
+            (EnemyFish, self.count(EnemyFish, sprites(&self.enemy.images), &mut failed)),
+            (Obscura, self.count(Obscura, sprites(&self.obscura.images), &mut failed)),
+            (Flora, self.count(Flora, sprites(&self.flora.images), &mut failed)),
+            (Audio, self.count(Audio, self.audio.clips.values().map(|h| h.id()), &mut failed)),
+            (ThemeFiles, self.prefetched_progress(&mut failed)),
+        ];
+        (categories, failed)
+    }
+}
+
+/// Recount loaded assets for the loading splash, logging new failures.
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_loading_progress_system(
+    assets: StartupAssets,
+    time: Res<Time>,
+    mut progress: ResMut<LoadingProgress>,
+) {
+    let (categories, failed) = assets.progress();
+    for asset in &failed {
+        if !progress.failed.contains(asset) {
+            warn!("failed to load {} ({:?})", asset.path, asset.category);
+        }
+    }
+    progress.categories = categories;
+    progress.failed = failed;
+    progress.elapsed += time.delta_secs();
+}
+
+/// Whether a theme has been picked this session. Switching theme goes back
+/// through the loading splash, which then returns to the main menu instead of
+/// opening the theme picker.
+#[derive(Resource, Default)]
+pub struct ThemeChosen(pub bool);
+
+impl ThemeChosen {
+    /// Where the splash leads once loading is done (or skipped).
+    pub fn next_state(&self) -> GameState {
+        if self.0 {
+            GameState::MainMenu
+        } else {
+            GameState::ThemePicker
+        }
+    }
+}
+
+/// Start a fresh count when the loading splash is (re-)entered, retrying the
+/// assets that failed last time.
+#[allow(clippy::needless_pass_by_value)]
+pub fn reset_loading_progress_system(asset_server: Res<AssetServer>, mut progress: ResMut<LoadingProgress>) {
+    for asset in &progress.failed {
+        // Folder listings cannot be reloaded by path; their files can.
+        if asset.category != LoadingCategory::ThemeFiles {
+            info!("retrying {}", asset.path);
+            asset_server.reload(asset.path.clone());
+        }
+    }
+    *progress = LoadingProgress::default();
+}
+
+/// Leave for the error screen as soon as a required asset has failed.
+#[allow(clippy::needless_pass_by_value)]
+pub fn load_failure_check_system(progress: Res<LoadingProgress>, mut next_state: ResMut<NextState<GameState>>) {
+    if let Some(asset) = progress.required_failures().next() {
+        error!("required asset {} failed to load", asset.path);
+        next_state.set(GameState::LoadError);
+    }
+}
+
+/// Reflect [`LoadingProgress`] in the splash's bar and category label, and
+/// reveal the "continue anyway" button after a timeout.
+#[allow(clippy::needless_pass_by_value)]
+pub fn loading_progress_ui_system(
+    progress: Res<LoadingProgress>,
+    mut fills: Query<&mut Node, (With<LoadingBarFill>, Without<LoadingContinueRow>)>,
+    mut categories: Query<&mut LocalizedText, With<LoadingCategoryText>>,
+    mut counts: Query<&mut Text, With<LoadingCountText>>,
+    mut continue_rows: Query<&mut Node, (With<LoadingContinueRow>, Without<LoadingBarFill>)>,
+) {
+    let width = Val::Percent(progress.fraction() * 100.0);
+    for mut fill in &mut fills {
+        if fill.width != width {
+            fill.width = width;
+        }
+    }
+
+    let (key, count) = match progress.pending() {
+        Some((category, p)) => (category.label_key(), format!("{} / {}", p.loaded, p.total)),
+        None => ("loading.done", String::new()),
+    };
+    for mut label in &mut categories {
+        label.set_if_neq(LocalizedText::new(key));
+    }
+    for mut text in &mut counts {
+        if **text != count {
+            **text = count.clone();
+        }
+    }
+
+    if progress.elapsed >= CONTINUE_ANYWAY_AFTER_SECS {
+        for mut row in &mut continue_rows {
+            if row.display == Display::None {
+                row.display = Display::Flex;
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  Load error screen
+// -----------------------------------------------------------------------------
+
+/// Explain which required assets failed to load and offer to retry or to pick
+/// another theme.
+#[allow(clippy::needless_pass_by_value)]
+pub fn spawn_load_error_ui(mut commands: Commands, progress: Res<LoadingProgress>) {
+    let failed: Vec<&FailedAsset> = progress.required_failures().collect();
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            LoadErrorUI,
+            FocusScope("load_error"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 56.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(JustifyText::Center),
+                LocalizedText::bold("load_error.title"),
+            ));
+
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(JustifyText::Center),
+                LocalizedText::new("load_error.message"),
+            ));
+
+            // Failed paths, verbatim
+            let mut paths: Vec<String> = failed.iter().take(MAX_LISTED_FAILURES).map(|a| a.path.clone()).collect();
+            if failed.len() > MAX_LISTED_FAILURES {
+                paths.push(format!("… +{}", failed.len() - MAX_LISTED_FAILURES));
+            }
+            parent.spawn((
+                Text::new(paths.join("\n")),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(20.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_menu_button(row, LocalizedText::new("load_error.retry"), (MenuAction::RetryLoading, DefaultFocus));
+                    spawn_menu_button(row, LocalizedText::new("load_error.other_theme"), MenuAction::OpenThemePicker);
+                });
+        });
+}
+
+/// Remove the load error screen.
+pub fn cleanup_load_error(mut commands: Commands, query: Query<Entity, With<LoadErrorUI>>) {
+    for e in &query {
+        commands.entity(e).despawn();
+    }
+}
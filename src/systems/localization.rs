@@ -0,0 +1,30 @@
+use crate::components::LocalizedText;
+use crate::localization::{font_for, StringTable, Strings};
+use crate::theme::Theme;
+use bevy::prelude::*;
+
+/// Fill every [`LocalizedText`] with its string in the current language and
+/// pick a font that can render it (the theme's font, else the fallback).
+///
+/// Only new or edited keys are touched normally; a language or theme switch
+/// or a (re)loaded string table re-renders all of them.
+#[allow(clippy::needless_pass_by_value)]
+pub fn localize_text_system(
+    strings: Strings,
+    mut table_events: EventReader<AssetEvent<StringTable>>,
+    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    mut texts: Query<(Ref<LocalizedText>, &mut Text, &mut TextFont)>,
+) {
+    let refresh_all = strings.is_changed() | theme.is_changed() | (table_events.read().count() > 0);
+
+    for (localized, mut text, mut font) in &mut texts {
+        if !refresh_all && !localized.is_changed() {
+            continue;
+        }
+
+        let value = strings.get(localized.key);
+        font.font = asset_server.load(font_for(&value, localized.bold, &theme.fonts).to_string());
+        text.0 = value;
+    }
+}
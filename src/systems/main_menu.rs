@@ -4,7 +4,13 @@ use crate::assets::ObscuraAssets;
 use crate::components::BackgroundObscura;
 use crate::assets::FloraAssets;
 use crate::components::BackgroundFlora;
-use crate::{components::*, constants, states::GameState};
+use crate::{components::*, constants};
+use crate::localization::Strings;
+use crate::settings::Accessibility;
+use crate::theme::Theme;
+use crate::systems::parallax::{spawn_parallax_flora, ParallaxRoots};
+use crate::systems::resize::Playfield;
+use bevy::asset::{LoadState, UntypedAssetId};
 use bevy::prelude::*;
 use rand::Rng;
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
@@ -13,8 +19,8 @@ use crate::assets::{PlayableFishTextures, EnemyFishAssets};
 use crate::systems::theme_picker::PendingThemeSelectSfx;
 use bevy::audio::{AudioPlayer, PlaybackSettings};
 
-/// Build the main-menu UI with "Play!" and "Quit" buttons.
-pub fn setup_menu_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// Build the main-menu UI with "Play!", "Themes", "Settings" and "Quit" buttons.
+pub fn setup_menu_ui(mut commands: Commands) {
     // Root node that fills the entire window and centers its children.
     commands
         .spawn((
@@ -28,127 +34,64 @@ pub fn setup_menu_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
                 ..default()
             },
             MainMenuUI,
+            FocusScope("main_menu"),
         ))
         .with_children(|parent| {
             // Play button (state transition)
-            spawn_menu_button(parent, &asset_server, "Play!", PlayButton);
+            spawn_menu_button(parent, LocalizedText::bold("menu.play"), (MenuAction::Play, DefaultFocus));
 
             // Theme picker button (return to theme selection screen)
-            spawn_menu_button(parent, &asset_server, "Themes", ThemePickerButton);
+            spawn_menu_button(parent, LocalizedText::new("menu.themes"), MenuAction::OpenThemePicker);
+
+            // Settings button (audio mix, ...)
+            spawn_menu_button(parent, LocalizedText::new("menu.settings"), MenuAction::OpenSettings);
 
             // Quit button (exit app) – desktop only
             if !cfg!(target_arch = "wasm32") {
-                spawn_menu_button(parent, &asset_server, "Quit", QuitButton);
+                spawn_menu_button(parent, LocalizedText::new("menu.quit"), MenuAction::Quit);
             }
         });
 }
 
-/// Helper to spawn a generic menu button with shared styling.
-pub fn spawn_menu_button<C: Component + Copy + 'static>(
+/// Helper to spawn a generic menu button with shared styling. `marker` is
+/// usually the button's [`MenuAction`], optionally in a tuple with other
+/// components (e.g. [`DefaultFocus`]).
+pub fn spawn_menu_button<B: Bundle>(
     parent: &mut ChildSpawnerCommands,
-    asset_server: &AssetServer,
-    label: &str,
-    marker: C,
+    label: LocalizedText,
+    marker: B,
 ) {
     parent
         .spawn((
             Button,
             Node {
-                width: Val::Px(220.0),
+                // Grow past the default width for long translations.
+                min_width: Val::Px(220.0),
                 height: Val::Px(90.0),
+                padding: UiRect::horizontal(Val::Px(24.0)),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 ..default()
             },
-            BackgroundColor(constants::IDLE_COLOR),
+            BackgroundColor(ButtonPalette::MENU.idle),
             BorderRadius::all(Val::Px(constants::BUTTON_RADIUS)),
+            ButtonPalette::MENU,
             marker,
         ))
         .with_children(|p| {
-            // Use bold font specifically for the Play! button if available.
-            let font_path = if label == "Play!" {
-                "fonts/Fredoka-Bold.ttf"
-            } else {
-                "fonts/Fredoka.ttf"
-            };
-
             p.spawn((
-                Text::new(label),
+                Text::default(),
                 TextFont {
-                    font: asset_server.load(font_path),
                     font_size: 42.0,
                     ..default()
                 },
                 TextColor(Color::WHITE),
                 TextLayout::new_with_justify(JustifyText::Center),
+                label,
             ));
         });
 }
 
-/// Change button color on hover/press and quit when pressed.
-pub fn quit_button_system(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<QuitButton>),
-    >,
-    mut exit: EventWriter<AppExit>,
-) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                // Pressed: darker teal (#005F6B)
-                *color = constants::PRESSED_COLOR.into();
-                exit.send(AppExit::Success);
-            }
-            Interaction::Hovered => {
-                // Hover: lighter teal (#00A5B4)
-                *color = constants::HOVER_COLOR.into();
-            }
-            Interaction::None => {
-                // Idle revert
-                *color = constants::IDLE_COLOR.into();
-            }
-        }
-    }
-}
-
-/// Handle the Play! button.
-pub fn play_button_system(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<PlayButton>),
-    >,
-    mut next_state: ResMut<NextState<GameState>>, // Transition top-level game state
-) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = constants::PRESSED_COLOR.into();
-                next_state.set(GameState::InGame);
-            }
-            Interaction::Hovered => *color = constants::HOVER_COLOR.into(),
-            Interaction::None => *color = constants::IDLE_COLOR.into(),
-        }
-    }
-}
-
-/// Handle the Theme Picker button.
-pub fn theme_picker_button_system(
-    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<ThemePickerButton>)>,
-    mut next_state: ResMut<NextState<GameState>>, // Transition to ThemePicker
-) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = constants::PRESSED_COLOR.into();
-                next_state.set(GameState::ThemePicker);
-            }
-            Interaction::Hovered => *color = constants::HOVER_COLOR.into(),
-            Interaction::None => *color = constants::IDLE_COLOR.into(),
-        }
-    }
-}
-
 /// Despawn all entities tagged as part of the main menu.
 pub fn cleanup_menu(
     mut commands: Commands,
@@ -174,8 +117,10 @@ pub fn cleanup_menu(
 pub fn spawn_background_obscura_initial(
     mut commands: Commands,
     obscura_opt: Option<Res<ObscuraAssets>>,
-    windows: Query<&Window>,
+    playfield: Res<Playfield>,
     images: Res<Assets<Image>>,
+    roots: Res<ParallaxRoots>,
+    theme: Res<Theme>,
     flora_opt: Option<Res<FloraAssets>>,
 ) {
     // Always create the spawner so that subsequent systems depending on it
@@ -193,13 +138,11 @@ pub fn spawn_background_obscura_initial(
     }
 
     // Wait for all obscura textures to load before spawning, just like flora.
-    if obscura.images.iter().any(|h| images.get(h).is_none()) {
+    if obscura.images.iter().any(|s| s.size(&images).is_none()) {
         trace!("spawn_background_obscura_initial: waiting for all obscura textures to load");
         return;
     }
 
-    let window = windows.single().expect("No primary window");
-
     let mut rng = rand::thread_rng();
 
     // Spawn between 6 and 8 obscura elements.
@@ -219,7 +162,7 @@ pub fn spawn_background_obscura_initial(
             previously_spawned_obscura_asset_indices.insert(ret);
             ret
         };
-        spawn_single_background_obscura(&mut commands, &obscura, obscura_asset_index, window, &images, &mut rng, true);
+        spawn_single_background_obscura(&mut commands, &obscura, obscura_asset_index, &playfield, &images, &roots, &theme, &mut rng, true);
     }
 
     // Flora decorations are spawned by their own dedicated system.
@@ -243,18 +186,21 @@ impl Default for BackgroundObscuraSpawner {
 
 /// Helper that actually creates a single background fish entity with randomised
 /// attributes.
+#[allow(clippy::too_many_arguments)]
 fn spawn_single_background_obscura(
     commands: &mut Commands,
     obscura: &ObscuraAssets,
     obscura_asset_index: usize,
-    window: &Window,
+    playfield: &Playfield,
     images: &Assets<Image>,
+    roots: &ParallaxRoots,
+    theme: &Theme,
     rng: &mut impl Rng,
     spawn_inside: bool,
 ) {
     use bevy::prelude::*;
 
-    let handle = obscura.images[obscura_asset_index].clone();
+    let source = &obscura.images[obscura_asset_index];
 
     // Randomise direction: 0 = right→left, 1 = left→right, 2 = bottom→top, 3 = top→bottom
     let dir = rng.gen_range(0..4);
@@ -274,8 +220,8 @@ fn spawn_single_background_obscura(
 
     // Calculate start position and baseline depending on whether we spawn inside.
     let (start_pos, base_perp) = if spawn_inside {
-        let half_w = window.width() / 2.0;
-        let half_h = window.height() / 2.0;
+        let half_w = playfield.width() / 2.0;
+        let half_h = playfield.height() / 2.0;
         let x = rng.gen_range(-half_w..half_w);
         let y = rng.gen_range(-half_h..half_h);
 
@@ -285,44 +231,40 @@ fn spawn_single_background_obscura(
         match dir {
             // From right edge moving leftwards
             0 => {
-                let x = window.width() / 2.0 + 100.0;
-                let y = rng.gen_range(-window.height() / 2.0..window.height() / 2.0);
+                let x = playfield.width() / 2.0 + 100.0;
+                let y = rng.gen_range(-playfield.height() / 2.0..playfield.height() / 2.0);
                 (Vec3::new(x, y, -1.0), y)
             }
             // From left edge moving rightwards
             1 => {
-                let x = -window.width() / 2.0 - 100.0;
-                let y = rng.gen_range(-window.height() / 2.0..window.height() / 2.0);
+                let x = -playfield.width() / 2.0 - 100.0;
+                let y = rng.gen_range(-playfield.height() / 2.0..playfield.height() / 2.0);
                 (Vec3::new(x, y, -1.0), y)
             }
             // From bottom edge moving upwards
             2 => {
-                let y = -window.height() / 2.0 - 100.0;
-                let x = rng.gen_range(-window.width() / 2.0..window.width() / 2.0);
+                let y = -playfield.height() / 2.0 - 100.0;
+                let x = rng.gen_range(-playfield.width() / 2.0..playfield.width() / 2.0);
                 (Vec3::new(x, y, -1.0), x)
             }
             // From top edge moving downwards
             _ => {
-                let y = window.height() / 2.0 + 100.0;
-                let x = rng.gen_range(-window.width() / 2.0..window.width() / 2.0);
+                let y = playfield.height() / 2.0 + 100.0;
+                let x = rng.gen_range(-playfield.width() / 2.0..playfield.width() / 2.0);
                 (Vec3::new(x, y, -1.0), x)
             }
         }
     };
 
     // --- NEW SIZE CONSTRAINT LOGIC ---
-    // Compute uniform scale so that the sprite fits within max(96x96, 1/20th the screen), preserving aspect ratio.
-    let (img_w, img_h) = images
-        .get(&handle)
-        .map(|img| {
-            let w = img.texture_descriptor.size.width as f32;
-            let h = img.texture_descriptor.size.height as f32;
-            (w, h)
-        })
+    // Compute uniform scale so that the sprite fits within max(96x96, 1/20th the virtual playfield), preserving aspect ratio.
+    let (img_w, img_h) = source
+        .size(images)
+        .map(|size| (size.x, size.y))
         .unwrap_or((200.0, 200.0));
 
-    let max_screen_w = window.width() / 20.0;
-    let max_screen_h = window.height() / 20.0;
+    let max_screen_w = Playfield::VIRTUAL_SIZE.x / 20.0;
+    let max_screen_h = Playfield::VIRTUAL_SIZE.y / 20.0;
     let max_w = max_screen_w.max(96.0);
     let max_h = max_screen_h.max(96.0);
     let scale = {
@@ -332,9 +274,14 @@ fn spawn_single_background_obscura(
         Vec3::splat(uniform)
     };
 
+    // Mid-depth layer: scaled, tinted and parented to the obscura root.
+    let style = theme.parallax.obscura;
+    let mut sprite = source.sprite();
+    sprite.color = style.color();
+
     commands.spawn((
-        Sprite::from_image(handle),
-        Transform::from_translation(start_pos).with_scale(scale),
+        sprite,
+        Transform::from_translation(start_pos).with_scale(scale * style.scale),
         BackgroundObscura {
             velocity,
             wiggle_amp,
@@ -342,6 +289,7 @@ fn spawn_single_background_obscura(
             phase,
             base_perp,
         },
+        ChildOf(roots.obscura),
     ));
 }
 
@@ -378,15 +326,13 @@ pub fn background_obscura_movement_system(
 pub fn background_obscura_despawn_system(
     mut commands: Commands,
     mut query: Query<(Entity, &Transform), With<BackgroundObscura>>, // need transform for position
-    windows: Query<&Window>,
+    playfield: Res<Playfield>,
     mut spawner: ResMut<BackgroundObscuraSpawner>,
 ) {
-    let window = windows.single().expect("No primary window");
-
     let mut rng = rand::thread_rng();
 
-    let half_w = window.width() / 2.0;
-    let half_h = window.height() / 2.0;
+    let half_w = playfield.width() / 2.0;
+    let half_h = playfield.height() / 2.0;
 
     for (entity, transform) in &mut query {
         // Despawn once the sprite is well outside any screen edge (+buffer).
@@ -408,14 +354,17 @@ pub fn background_obscura_despawn_system(
 }
 
 /// Periodically spawn new background fish as governed by `BackgroundObscuraSpawner`.
+#[allow(clippy::too_many_arguments)]
 pub fn background_obscura_spawn_system(
     mut commands: Commands,
     obscura_opt: Option<Res<ObscuraAssets>>,
-    windows: Query<&Window>,
+    playfield: Res<Playfield>,
     mut spawner: ResMut<BackgroundObscuraSpawner>,
     time: Res<Time>,
     existing: Query<&BackgroundObscura>,
     images: Res<Assets<Image>>,
+    roots: Res<ParallaxRoots>,
+    theme: Res<Theme>,
 ) {
     let Some(obscura) = obscura_opt else {
         return;
@@ -425,14 +374,12 @@ pub fn background_obscura_spawn_system(
         return;
     }
 
-    let window = windows.single().expect("No primary window");
-
     spawner.timer.tick(time.delta());
 
     // Maintain at most 8 fish.
     if spawner.timer.finished() && existing.iter().count() < 8 {
         let mut rng = rand::thread_rng();
-        spawn_single_background_obscura(&mut commands, &obscura, rng.gen_range(0..obscura.images.len()), window, &images, &mut rng, false);
+        spawn_single_background_obscura(&mut commands, &obscura, rng.gen_range(0..obscura.images.len()), &playfield, &images, &roots, &theme, &mut rng, false);
 
         // Set next spawn delay now so we don't spawn in the very next frame.
         let next = rng.gen_range(1.0..3.0);
@@ -457,11 +404,14 @@ pub fn cleanup_background_obscura(
 }
 
 /// Spawn a batch of static flora decorations along the sea floor.
+#[allow(clippy::needless_pass_by_value)]
 pub fn spawn_background_flora_initial(
     mut commands: Commands,
     flora_opt: Option<Res<FloraAssets>>, 
-    windows: Query<&Window>,
+    playfield: Res<Playfield>,
     images: Res<Assets<Image>>,
+    roots: Res<ParallaxRoots>,
+    theme: Res<Theme>,
     existing: Query<Entity, With<BackgroundFlora>>, // ensure we spawn only once per menu visit
 ) {
     // If we already have flora entities, skip (prevents duplicates when this system is in Update).
@@ -501,23 +451,22 @@ pub fn spawn_background_flora_initial(
     if flora
         .images
         .iter()
-        .any(|h| images.get(h).is_none())
+        .any(|s| s.size(&images).is_none())
     {
         trace!("spawn_background_flora_initial: waiting for all flora textures to load");
         return;
     }
 
-    let window = windows.single().expect("No primary window");
     let mut rng = rand::thread_rng();
 
     // Spawn slightly fewer decorations than before so the sea floor looks cleaner.
     let count = rng.gen_range(6..=14);
-    let half_w = window.width() / 2.0;
+    let half_w = playfield.width() / 2.0;
 
     info!(
-        "spawn_background_flora_initial: window = {:.1}×{:.1} px, spawning {} flora decorations ({} available images).",
-        window.width(),
-        window.height(),
+        "spawn_background_flora_initial: playfield = {:.1}×{:.1} px, spawning {} flora decorations ({} available images).",
+        playfield.width(),
+        playfield.height(),
         count,
         flora.images.len()
     );
@@ -545,20 +494,16 @@ pub fn spawn_background_flora_initial(
             idx.unwrap_or_else(|| rng.gen_range(0..flora.images.len()))
         };
         picked_indices.insert(img_idx);
-        let handle = flora.images[img_idx].clone();
+        let source = &flora.images[img_idx];
 
-        // Scale the sprite so that its height does not exceed 1/4 of the current
-        // window height. This guarantees flora never towers over gameplay UI
-        // on very small or large screens while still preserving the original
-        // pixel aspect ratio.
+        // Scale the sprite so that its height does not exceed 1/4 of the virtual
+        // playfield height. This guarantees flora never towers over gameplay UI
+        // on any screen while still preserving the original pixel aspect ratio.
 
-        let img_height_px = images
-            .get(&handle)
-            .map(|img| img.texture_descriptor.size.height as f32)
-            .unwrap_or(200.0); // reasonable fallback until asset loads
+        let img_height_px = source.size(&images).map_or(200.0, |size| size.y); // reasonable fallback until asset loads
 
         // Desired maximum height in world units (pixels) for this flora sprite.
-        let desired_height = window.height() / 4.0;
+        let desired_height = Playfield::VIRTUAL_SIZE.y / 4.0;
 
         // Uniform scale factor so that `img_height_px * scale == desired_height`.
         // If the source image is smaller than 1/4 of the screen we may scale
@@ -593,10 +538,7 @@ pub fn spawn_background_flora_initial(
         // Determine the original (un-scaled) image height in pixels. If the
         // asset hasn't fully loaded yet we fall back to 0 which will still
         // place the sprite roughly at the bottom without crashing.
-        let img_height = images
-            .get(&handle)
-            .map(|img| img.texture_descriptor.size.height as f32)
-            .unwrap_or(0.0);
+        let img_height = source.size(&images).map_or(0.0, |size| size.y);
 
         // Adjust for the scale we are going to apply to the sprite so that the
         // calculation is done in world units (1 px = 1 world unit in Bevy's 2D
@@ -605,7 +547,7 @@ pub fn spawn_background_flora_initial(
 
         // Center y coordinate such that the bottom of the sprite is `offset`
         // pixels below the bottom of the screen.
-        let y = -window.height() / 2.0 - offset + scaled_height / 2.0;
+        let y = -playfield.height() / 2.0 - offset + scaled_height / 2.0;
 
         let pulse_amp = rng.gen_range(0.03..0.07);
         let pulse_speed = rng.gen_range(0.3..0.8);
@@ -613,20 +555,24 @@ pub fn spawn_background_flora_initial(
         let wiggle_amp = rng.gen_range(2.0f32.to_radians()..5.0f32.to_radians());
 
         debug!(
-            "Flora #{}/{} → pos=({:.1},{:.1}), base_scale={:.3}, img_idx={} (handle={:?})",
+            "Flora #{}/{} → pos=({:.1},{:.1}), base_scale={:.3}, img_idx={} (sprite={:?})",
             i + 1,
             count,
             x,
             y,
             base_scale,
             img_idx,
-            handle
+            source
         );
 
         commands.spawn((
-            Sprite::from_image(handle),
+            source.sprite(),
             Transform::from_xyz(x, y, -2.0).with_scale(Vec3::splat(base_scale)),
             BackgroundFlora {
+                layer: ParallaxLayer::Playfield,
+                anchor_x: x / half_w,
+                floor_offset: offset,
+                image_height: img_height_px,
                 base_scale,
                 pulse_amp,
                 pulse_speed,
@@ -635,17 +581,27 @@ pub fn spawn_background_flora_initial(
             },
         ));
     }
+
+    // Distant silhouettes and foreground kelp from the same flora images.
+    spawn_parallax_flora(&mut commands, &flora, &images, &playfield, &roots, &theme, &mut rng);
 }
 
 /// Animate flora pulsation and slight rotation.
+#[allow(clippy::needless_pass_by_value)]
 pub fn background_flora_animation_system(
     mut query: Query<(&mut Transform, &BackgroundFlora)>,
     time: Res<Time>,
+    accessibility: Res<Accessibility>,
 ) {
     let t = time.elapsed_secs();
     let mut processed = 0u32;
     for (mut transform, flora) in &mut query {
-        let sin = (t * flora.pulse_speed + flora.phase).sin();
+        // Reduced motion: hold every plant at its resting pose.
+        let sin = if accessibility.reduced_motion {
+            0.0
+        } else {
+            (t * flora.pulse_speed + flora.phase).sin()
+        };
         transform.scale = Vec3::splat(flora.base_scale * (1.0 + flora.pulse_amp * sin));
         transform.rotation = Quat::from_rotation_z(sin * flora.wiggle_amp);
 
@@ -685,8 +641,9 @@ pub struct GradientBackground;
 pub fn spawn_gradient_background(
     mut commands: Commands,
     mut images: ResMut<Assets<Image>>, 
-    windows: Query<&Window>,
+    playfield: Res<Playfield>,
     existing: Query<Entity, With<GradientBackground>>, // existing gradient layers
+    theme: Res<Theme>,
 ) {
     // Remove any pre-existing gradient background (both backdrop and overlay) to
     // prevent multiple transparent overlays stacking up when this system runs
@@ -695,15 +652,16 @@ pub fn spawn_gradient_background(
         commands.entity(entity).despawn();
     }
 
-    let window = windows.single().expect("No primary window");
-
     // Texture dimensions (1×N to minimise memory, scaled to window size).
     const HEIGHT: usize = 512; // adequate vertical resolution for smoothness
     const WIDTH: usize = 1;
 
-    // Bottom (deep) and top (surface) colours expressed in sRGB 0-1 range.
-    let bottom = [0.0, 0.15, 0.20]; // deep cold water (#002633)
-    let top = [0.20, 0.65, 0.90];   // sunlit surface blue
+    // Bottom (deep) and top (surface) colours expressed in sRGB 0-1 range,
+    // taken from the theme's palette.
+    let (b_r, b_g, b_b) = theme.palette.water_bottom;
+    let (t_r, t_g, t_b) = theme.palette.water_top;
+    let bottom = [b_r, b_g, b_b];
+    let top = [t_r, t_g, t_b];
 
     let mut data = Vec::with_capacity(WIDTH * HEIGHT * 4);
     // NOTE: In image data, the very first row ends up at the top of the sprite. We therefore
@@ -732,7 +690,7 @@ pub fn spawn_gradient_background(
 
     // Create a sprite component from the generated texture handle.
     let mut sprite = Sprite::from_image(handle.clone());
-    sprite.custom_size = Some(Vec2::new(window.width(), window.height()));
+    sprite.custom_size = Some(Vec2::new(playfield.width(), playfield.height()));
 
     commands.spawn((
         sprite,
@@ -770,7 +728,7 @@ pub fn spawn_gradient_background(
     let overlay_handle = images.add(overlay_image);
 
     let mut overlay_sprite = Sprite::from_image(overlay_handle.clone());
-    overlay_sprite.custom_size = Some(Vec2::new(window.width(), window.height()));
+    overlay_sprite.custom_size = Some(Vec2::new(playfield.width(), playfield.height()));
 
     commands.spawn((
         overlay_sprite,
@@ -805,8 +763,9 @@ pub struct LoadingText {
     phase: u8, // 0-3
 }
 
-/// Spawn a centered "Loading…" text while we wait for assets.
-pub fn spawn_loading_splash_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// Spawn a centered "Loading…" text, a progress bar naming the category still
+/// loading and a hidden "continue anyway" button while we wait for assets.
+pub fn spawn_loading_splash_ui(mut commands: Commands) {
     use bevy::ui::*;
 
     commands
@@ -814,34 +773,107 @@ pub fn spawn_loading_splash_ui(mut commands: Commands, asset_server: Res<AssetSe
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.0),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
                 ..default()
             },
             LoadingSplashUI,
+            FocusScope("loading"),
         ))
         .with_children(|p| {
             p.spawn((
-                Text::new("Loading"),
+                Text::default(),
                 TextFont {
-                    font: asset_server.load("fonts/Fredoka-Bold.ttf"),
                     font_size: 48.0,
                     ..default()
                 },
                 TextColor(Color::WHITE),
                 TextLayout::new_with_justify(JustifyText::Center),
+                LocalizedText::bold("loading"),
                 LoadingText {
                     timer: Timer::from_seconds(0.5, bevy::time::TimerMode::Repeating),
                     phase: 0,
                 },
             ));
+
+            // Progress bar: dark track with a fill sized to the loaded share.
+            p.spawn((
+                Node {
+                    width: Val::Px(480.0),
+                    max_width: Val::Percent(80.0),
+                    height: Val::Px(24.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(constants::LOADING_BAR_TRACK_COLOR),
+                BorderColor(Color::WHITE),
+                BorderRadius::all(Val::Px(constants::BUTTON_RADIUS)),
+            ))
+            .with_children(|track| {
+                track.spawn((
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(constants::LOADING_BAR_FILL_COLOR),
+                    BorderRadius::all(Val::Px(constants::BUTTON_RADIUS)),
+                    LoadingBarFill,
+                ));
+            });
+
+            // "<category> 12 / 40"
+            p.spawn(Node {
+                column_gap: Val::Px(12.0),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::default(),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    LocalizedText::new("loading.done"),
+                    LoadingCategoryText,
+                ));
+                row.spawn((
+                    Text::default(),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    LoadingCountText,
+                ));
+            });
+
+            p.spawn((
+                Node {
+                    display: Display::None,
+                    ..default()
+                },
+                LoadingContinueRow,
+            ))
+            .with_children(|row| {
+                spawn_menu_button(
+                    row,
+                    LocalizedText::bold("loading.continue"),
+                    (MenuAction::SkipLoading, DefaultFocus),
+                );
+            });
         });
 }
 
 /// Animate the dots after the "Loading" label (cycles 0‒3 dots).
+#[allow(clippy::needless_pass_by_value)]
 pub fn loading_text_animation_system(
     mut query: Query<(&mut Text, &mut LoadingText)>,
     time: Res<Time>,
+    strings: Strings,
 ) {
     for (mut text, mut loader) in &mut query {
         loader.timer.tick(time.delta());
@@ -850,7 +882,7 @@ pub fn loading_text_animation_system(
             let dots: String = ".".repeat(loader.phase as usize);
             // In Bevy 0.16 `Text` dereferences to its underlying `String`, so we can
             // update the contents directly rather than editing text sections.
-            *text = Text::new(format!("Loading{}", dots));
+            *text = Text::new(format!("{}{}", strings.get("loading"), dots));
         }
     }
 }
@@ -875,8 +907,18 @@ pub fn flora_assets_ready(flora_opt: Option<Res<FloraAssets>>) -> bool {
 // Main-menu loading indicator (shown after selecting a new theme) -------------
 // -----------------------------------------------------------------------------
 
+/// Whether an asset is done loading. Failed assets count as done so a single
+/// broken file cannot keep the indicator up forever, and so do assets added
+/// directly rather than loaded (placeholders).
+fn is_settled(asset_server: &AssetServer, id: impl Into<UntypedAssetId>) -> bool {
+    matches!(
+        asset_server.get_load_state(id),
+        None | Some(LoadState::Loaded | LoadState::Failed(_))
+    )
+}
+
 /// Helper that returns `true` when **all** assets required for the main menu
-/// have finished loading for the currently-selected theme.
+/// have finished loading (or failed to) for the currently-selected theme.
 fn theme_assets_ready(
     asset_server: &AssetServer,
     obscura: &ObscuraAssets,
@@ -884,14 +926,14 @@ fn theme_assets_ready(
     playable: &PlayableFishTextures,
     enemy: &EnemyFishAssets,
 ) -> bool {
-    let obscura_ready = obscura.images.iter().all(|h| asset_server.is_loaded(h));
-    let flora_ready = flora.images.iter().all(|h| asset_server.is_loaded(h));
+    let obscura_ready = obscura.images.iter().all(|s| is_settled(asset_server, &s.image));
+    let flora_ready = flora.images.iter().all(|s| is_settled(asset_server, &s.image));
 
-    let playable_ready = asset_server.is_loaded(&playable.fish1)
-        && asset_server.is_loaded(&playable.fish2)
-        && asset_server.is_loaded(&playable.fish3);
+    let playable_ready = is_settled(asset_server, &playable.fish1)
+        && is_settled(asset_server, &playable.fish2)
+        && is_settled(asset_server, &playable.fish3);
 
-    let enemy_ready = enemy.images.iter().all(|h| asset_server.is_loaded(h));
+    let enemy_ready = enemy.images.iter().all(|s| is_settled(asset_server, &s.image));
 
     obscura_ready && flora_ready && playable_ready && enemy_ready
 }
@@ -929,14 +971,14 @@ pub fn spawn_menu_loading_indicator(
         ))
         .with_children(|p| {
             p.spawn((
-                Text::new("Loading"),
+                Text::default(),
                 TextFont {
-                    font: asset_server.load("fonts/Fredoka-Bold.ttf"),
                     font_size: 32.0,
                     ..default()
                 },
                 TextColor(Color::WHITE),
                 TextLayout::new_with_justify(JustifyText::Center),
+                LocalizedText::bold("loading"),
                 // Re-use the same dot-cycling component & animation system.
                 LoadingText {
                     timer: Timer::from_seconds(0.5, bevy::time::TimerMode::Repeating),
@@ -996,6 +1038,7 @@ pub fn play_pending_theme_select_sfx_system(
         commands.spawn((
             AudioPlayer::new(pending.handle.clone()),
             PlaybackSettings::DESPAWN,
+            AudioChannel::ui(),
         ));
 
         // Remove the marker so we don't play it again.
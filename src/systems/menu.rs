@@ -0,0 +1,146 @@
+use crate::assets::{PlayableFishTextures, ThemeSelectAudio};
+use crate::events::MenuActionEvent;
+use crate::localization::Localization;
+use crate::settings::{Accessibility, AudioMixer, ParticleDensity};
+use crate::theme::{Theme, ThemeRegistry};
+use crate::{
+    components::*,
+    states::{GameState, InGameState},
+};
+use bevy::audio::{AudioPlayer, PlaybackSettings};
+use bevy::prelude::*;
+
+use super::in_game::SelectedFish;
+use super::ThemeChosen;
+
+/// Single interaction handler for every menu button: applies the button's
+/// [`ButtonPalette`] (or the high-contrast one) and emits a
+/// [`MenuActionEvent`] when it is pressed.
+#[allow(clippy::type_complexity, clippy::needless_pass_by_value)]
+pub fn menu_button_interaction_system(
+    mut buttons: Query<(Ref<Interaction>, &mut BackgroundColor, Ref<ButtonPalette>, Option<&MenuAction>)>,
+    accessibility: Res<Accessibility>,
+    mut events: EventWriter<MenuActionEvent>,
+) {
+    for (interaction, mut color, palette, action) in &mut buttons {
+        // Recolour new buttons and every button when the contrast mode flips.
+        let restyle = palette.is_added() || accessibility.is_changed();
+        if !interaction.is_changed() && !restyle {
+            continue;
+        }
+
+        let palette = if accessibility.high_contrast {
+            &ButtonPalette::HIGH_CONTRAST
+        } else {
+            &*palette
+        };
+
+        match *interaction {
+            Interaction::Pressed => {
+                *color = palette.pressed.into();
+                if let (true, Some(action)) = (interaction.is_changed(), action) {
+                    events.write(MenuActionEvent(*action));
+                }
+            }
+            Interaction::Hovered => *color = palette.hover.into(),
+            Interaction::None => *color = palette.idle.into(),
+        }
+    }
+}
+
+/// Carry out the effect of pressed menu buttons.
+#[allow(clippy::too_many_arguments)]
+pub fn menu_action_system(
+    mut events: EventReader<MenuActionEvent>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut next_in_game_state: ResMut<NextState<InGameState>>,
+    mut exit: EventWriter<AppExit>,
+    mut theme: ResMut<Theme>,
+    registry: Res<ThemeRegistry>,
+    mut selected: ResMut<SelectedFish>,
+    textures: Option<Res<PlayableFishTextures>>,
+    mut mixer: ResMut<AudioMixer>,
+    mut localization: ResMut<Localization>,
+    mut accessibility: ResMut<Accessibility>,
+    mut density: ResMut<ParticleDensity>,
+    mut chosen: ResMut<ThemeChosen>,
+) {
+    for MenuActionEvent(action) in events.read() {
+        match *action {
+            MenuAction::Play => next_game_state.set(GameState::InGame),
+            MenuAction::OpenThemePicker => next_game_state.set(GameState::ThemePicker),
+            MenuAction::OpenSettings => next_game_state.set(GameState::Settings),
+            MenuAction::MainMenu => next_game_state.set(GameState::MainMenu),
+            MenuAction::SkipLoading => next_game_state.set(chosen.next_state()),
+            MenuAction::RetryLoading => next_game_state.set(GameState::PreMainMenu),
+            MenuAction::Resume => next_in_game_state.set(InGameState::Playing),
+            MenuAction::Quit => {
+                exit.write(AppExit::Success);
+            }
+            MenuAction::SelectTheme(selected_theme) => {
+                // Store the chosen theme and wait on the splash for its
+                // required assets before showing the main menu.
+                *theme = registry.get(selected_theme).clone();
+                chosen.0 = true;
+                next_game_state.set(GameState::PreMainMenu);
+            }
+            // Opens a browser file dialog, see `theme_pack_upload_dialog_system`.
+            MenuAction::ImportThemePack => {}
+            MenuAction::SelectFish(index) => {
+                let Some(textures) = textures.as_ref() else {
+                    continue;
+                };
+                let handle = match index {
+                    0 => textures.fish1.clone(),
+                    1 => textures.fish2.clone(),
+                    _ => textures.fish3.clone(),
+                };
+                selected.0 = Some(handle);
+                next_in_game_state.set(InGameState::Playing);
+            }
+            MenuAction::AdjustVolume { setting, step } => {
+                let level = mixer.level_mut(setting);
+                *level = (*level + step).clamp(0.0, 1.0);
+            }
+            MenuAction::ToggleMute => mixer.muted = !mixer.muted,
+            MenuAction::CycleLanguage => localization.language = localization.language.next(),
+            MenuAction::ToggleAccessibility(option) => accessibility.toggle(option),
+            MenuAction::CycleParticleDensity => *density = density.next(),
+        }
+    }
+}
+
+/// Tag for the theme preview clip played while hovering a theme button.
+#[derive(Component)]
+pub struct ThemeHoverSfx;
+
+/// Preview a theme's select clip while its button is hovered.
+#[allow(clippy::needless_pass_by_value)]
+pub fn theme_hover_preview_system(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &MenuAction), Changed<Interaction>>,
+    hover_audio_query: Query<Entity, With<ThemeHoverSfx>>,
+    audio: Res<ThemeSelectAudio>,
+) {
+    for (interaction, action) in &interaction_query {
+        let (Interaction::Hovered, MenuAction::SelectTheme(theme)) = (interaction, action) else {
+            continue;
+        };
+        let Some(clip) = audio.for_theme(*theme) else {
+            continue;
+        };
+
+        // Avoid overlapping hover SFX – remove any currently playing one.
+        for entity in &hover_audio_query {
+            commands.entity(entity).despawn();
+        }
+
+        // Spawn a new audio player for the hover SFX (auto-despawns when finished).
+        commands.spawn((
+            AudioPlayer::new(clip),
+            PlaybackSettings::DESPAWN,
+            AudioChannel::ui(),
+            ThemeHoverSfx,
+        ));
+    }
+}
@@ -1,7 +1,43 @@
 pub mod main_menu;
 pub mod in_game;
 pub mod theme_picker;
+pub mod audio;
+pub mod settings_menu;
+pub mod focus;
+pub mod menu;
+pub mod localization;
+pub mod accessibility;
+pub mod loading;
+pub mod resize;
+pub mod debug_overlay;
+pub mod telemetry;
+pub mod attract;
+pub mod effects;
+pub mod particles;
+pub mod parallax;
+pub mod lighting;
+pub mod hazards;
+#[cfg(feature = "dev")]
+pub mod console;
 
 pub use main_menu::*;
 pub use in_game::*;
-pub use theme_picker::*; 
\ No newline at end of file
+pub use theme_picker::*;
+pub use audio::*;
+pub use settings_menu::*;
+pub use focus::*;
+pub use menu::*;
+pub use localization::*;
+pub use accessibility::*;
+pub use loading::*;
+pub use resize::*;
+pub use debug_overlay::*;
+pub use telemetry::*;
+pub use attract::*;
+pub use effects::*;
+pub use particles::*;
+pub use parallax::*;
+pub use lighting::*;
+pub use hazards::*;
+#[cfg(feature = "dev")]
+pub use console::*;
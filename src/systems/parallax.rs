@@ -0,0 +1,186 @@
+//! Parallax depth layers: faint silhouettes far behind, the drifting obscura
+//! fish, the playfield itself and kelp in front of it.
+//!
+//! Every layer except the playfield has a root entity its sprites are
+//! parented to. Each frame the roots are offset by how far the view has
+//! "moved" — the camera (e.g. screen shake) plus a lean towards the player
+//! or demo fish — scaled by the layer's scroll factor, so nearer layers slide
+//! further than distant ones. Scale, tint and alpha come from the theme
+//! ([`ThemeParallax`]) and are applied when sprites spawn.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::assets::FloraAssets;
+use crate::components::*;
+use crate::settings::Accessibility;
+use crate::systems::resize::Playfield;
+use crate::theme::{ParallaxLayerStyle, Theme};
+
+/// How quickly the view's lean catches up with the followed fish (1/s).
+const FOLLOW_EASE_RATE: f32 = 3.0;
+
+/// Depth of the silhouette and foreground flora.
+const SILHOUETTE_Z: f32 = -4.0;
+const FOREGROUND_Z: f32 = 1.5;
+
+/// Extra depth flora in the scrolling layers sinks below the sea floor, so
+/// the view leaning upwards never lifts it off the floor.
+const FLOOR_MARGIN: f32 = 60.0;
+
+/// Root entities of the scrolling layers. Created at startup and kept for
+/// the whole session.
+#[derive(Resource)]
+pub struct ParallaxRoots {
+    pub silhouettes: Entity,
+    pub obscura: Entity,
+    pub foreground: Entity,
+}
+
+impl ParallaxRoots {
+    /// Parent for sprites of `layer`; `None` for the playfield.
+    pub fn root(&self, layer: ParallaxLayer) -> Option<Entity> {
+        match layer {
+            ParallaxLayer::Silhouettes => Some(self.silhouettes),
+            ParallaxLayer::Obscura => Some(self.obscura),
+            ParallaxLayer::Playfield => None,
+            ParallaxLayer::Foreground => Some(self.foreground),
+        }
+    }
+}
+
+/// Smoothed lean of the view towards the followed fish.
+#[derive(Resource, Default)]
+pub struct ParallaxView {
+    follow: Vec2,
+}
+
+/// The theme's style for `layer`; `None` for the playfield, which is drawn
+/// as is.
+pub fn layer_style(theme: &Theme, layer: ParallaxLayer) -> Option<ParallaxLayerStyle> {
+    match layer {
+        ParallaxLayer::Silhouettes => Some(theme.parallax.silhouettes),
+        ParallaxLayer::Obscura => Some(theme.parallax.obscura),
+        ParallaxLayer::Playfield => None,
+        ParallaxLayer::Foreground => Some(theme.parallax.foreground),
+    }
+}
+
+/// Create the layer roots. Runs at `Startup`.
+pub fn spawn_parallax_roots(mut commands: Commands) {
+    let mut root = |layer: ParallaxLayer| {
+        commands
+            .spawn((layer, Transform::default(), Visibility::default()))
+            .id()
+    };
+    let roots = ParallaxRoots {
+        silhouettes: root(ParallaxLayer::Silhouettes),
+        obscura: root(ParallaxLayer::Obscura),
+        foreground: root(ParallaxLayer::Foreground),
+    };
+    commands.insert_resource(roots);
+}
+
+/// Offset each layer root by the view's movement times `1 - scroll`: distant
+/// layers lag behind the playfield, the foreground overtakes it.
+#[allow(clippy::type_complexity, clippy::needless_pass_by_value)]
+pub fn parallax_system(
+    time: Res<Time>,
+    theme: Res<Theme>,
+    accessibility: Res<Accessibility>,
+    mut view: ResMut<ParallaxView>,
+    cameras: Query<&Transform, With<Camera2d>>,
+    followed: Query<&Transform, Or<(With<PlayerFish>, With<AttractFish>)>>,
+    mut roots: Query<
+        (&ParallaxLayer, &mut Transform),
+        (Without<Camera2d>, Without<PlayerFish>, Without<AttractFish>),
+    >,
+) {
+    // Reduced motion: layers only follow the camera itself.
+    let target = match followed.iter().next() {
+        Some(transform) if !accessibility.reduced_motion => {
+            transform.translation.truncate() * theme.parallax.follow
+        }
+        _ => Vec2::ZERO,
+    };
+    let ease = 1.0 - (-FOLLOW_EASE_RATE * time.delta_secs()).exp();
+    view.follow = view.follow.lerp(target, ease);
+
+    let camera = cameras
+        .iter()
+        .next()
+        .map_or(Vec2::ZERO, |transform| transform.translation.truncate());
+    let offset = camera + view.follow;
+
+    for (layer, mut transform) in &mut roots {
+        let Some(style) = layer_style(&theme, *layer) else {
+            continue;
+        };
+        let shift = offset * (1.0 - style.scroll);
+        transform.translation.x = shift.x;
+        transform.translation.y = shift.y;
+    }
+}
+
+/// Spawn distant silhouettes and foreground kelp from the theme's flora,
+/// alongside the sea floor flora.
+pub fn spawn_parallax_flora(
+    commands: &mut Commands,
+    flora: &FloraAssets,
+    images: &Assets<Image>,
+    playfield: &Playfield,
+    roots: &ParallaxRoots,
+    theme: &Theme,
+    rng: &mut impl Rng,
+) {
+    if flora.images.is_empty() {
+        return;
+    }
+    let half = playfield.half_size();
+
+    let layers = [
+        (ParallaxLayer::Silhouettes, rng.gen_range(4..=6), SILHOUETTE_Z),
+        (ParallaxLayer::Foreground, rng.gen_range(2..=3), FOREGROUND_Z),
+    ];
+    for (layer, count, z) in layers {
+        let (Some(root), Some(style)) = (roots.root(layer), layer_style(theme, layer)) else {
+            continue;
+        };
+        for _ in 0..count {
+            let source = &flora.images[rng.gen_range(0..flora.images.len())];
+            let image_height = source.size(images).map_or(200.0, |size| size.y);
+            let base_scale = Playfield::VIRTUAL_SIZE.y / 4.0 / image_height * style.scale;
+
+            // Foreground kelp keeps to the sides so the middle stays clear.
+            let anchor_x = if layer == ParallaxLayer::Foreground {
+                let side = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+                side * rng.gen_range(0.6..1.0)
+            } else {
+                rng.gen_range(-1.0..1.0)
+            };
+            let floor_offset = FLOOR_MARGIN + rng.gen_range(5.0..15.0);
+            let y = -half.y - floor_offset + image_height * base_scale / 2.0;
+
+            let mut sprite = source.sprite();
+            sprite.color = style.color();
+            sprite.flip_x = rng.gen_bool(0.5);
+
+            commands.spawn((
+                sprite,
+                Transform::from_xyz(anchor_x * half.x, y, z).with_scale(Vec3::splat(base_scale)),
+                BackgroundFlora {
+                    layer,
+                    anchor_x,
+                    floor_offset,
+                    image_height,
+                    base_scale,
+                    pulse_amp: rng.gen_range(0.02..0.05),
+                    pulse_speed: rng.gen_range(0.2..0.6),
+                    phase: rng.gen_range(0.0..std::f32::consts::TAU),
+                    wiggle_amp: rng.gen_range(2.0f32.to_radians()..5.0f32.to_radians()),
+                },
+                ChildOf(root),
+            ));
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! Lightweight CPU particles: bubbles rising from flora and from the player's
+//! mouth while it swims, drifting plankton specks, and the bursts thrown out
+//! by bites.
+//!
+//! Emitters queue [`ParticleSpec`]s on the [`Particles`] resource; a single
+//! system turns them into sprites, reusing hidden entities from a pool
+//! instead of spawning and despawning every particle. Emission rates scale
+//! with the [`ParticleDensity`] setting and the theme decides how particles
+//! look ([`ThemeParticles`]).
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::assets::EffectSprites;
+use crate::components::*;
+use crate::settings::ParticleDensity;
+use crate::systems::resize::Playfield;
+use crate::theme::{ParticleShape, Theme, ThemeParticles};
+
+/// Particles alive at once; emissions beyond this are dropped.
+const MAX_PARTICLES: usize = 800;
+
+/// Bubbles per second rising from each plant.
+const FLORA_BUBBLE_RATE: f32 = 0.12;
+
+/// Bubbles per second from the player's mouth while it swims.
+const MOUTH_BUBBLE_RATE: f32 = 8.0;
+
+/// Plankton specks on screen at normal density.
+const PLANKTON_COUNT: f32 = 50.0;
+
+/// Plankton lifetime range, in seconds.
+const PLANKTON_LIFETIME: (f32, f32) = (6.0, 12.0);
+
+/// Pixel particles are snapped to multiples of this size (world units).
+const PIXEL_GRID: f32 = 4.0;
+
+/// Depth of each particle layer: plankton between flora and background fish,
+/// flora bubbles just in front of the plants, everything else over the fish.
+const PLANKTON_Z: f32 = -1.5;
+const FLORA_BUBBLE_Z: f32 = -1.9;
+pub const EFFECT_Z: f32 = 1.0;
+
+/// What a particle is, which decides how the theme draws it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParticleKind {
+    Bubble,
+    Plankton,
+    /// Bits of a bitten fish; always plain squares in their own colour.
+    Crumb,
+}
+
+/// Everything needed to start one particle.
+#[derive(Clone, Copy, Debug)]
+pub struct ParticleSpec {
+    pub kind: ParticleKind,
+    pub position: Vec3,
+    pub velocity: Vec2,
+    /// Vertical acceleration: positive rises, negative sinks.
+    pub buoyancy: f32,
+    /// Proportion of velocity lost per second.
+    pub drag: f32,
+    /// Sideways sway amplitude (world units / s).
+    pub sway: f32,
+    /// Diameter in world units.
+    pub size: f32,
+    pub lifetime: f32,
+    /// Seconds to fade in; particles always fade out over their lifetime's end.
+    pub fade_in: f32,
+    /// Colour overriding the theme's tint for this kind.
+    pub color: Option<Color>,
+}
+
+impl ParticleSpec {
+    /// A particle of `kind` at `position` with no motion of its own.
+    pub fn new(kind: ParticleKind, position: Vec3, size: f32, lifetime: f32) -> Self {
+        Self {
+            kind,
+            position,
+            velocity: Vec2::ZERO,
+            buoyancy: 0.0,
+            drag: 0.0,
+            sway: 0.0,
+            size,
+            lifetime,
+            fade_in: 0.0,
+            color: None,
+        }
+    }
+}
+
+/// Pooled particle entity. Hidden and `active == false` while in the pool.
+#[derive(Component)]
+pub struct Particle {
+    active: bool,
+    velocity: Vec2,
+    buoyancy: f32,
+    drag: f32,
+    sway: f32,
+    phase: f32,
+    age: f32,
+    lifetime: f32,
+    fade_in: f32,
+    alpha: f32,
+}
+
+/// Particles waiting to be emitted and the pool of idle particle entities.
+#[derive(Resource, Default)]
+pub struct Particles {
+    queued: Vec<ParticleSpec>,
+    free: Vec<Entity>,
+    live: usize,
+}
+
+impl Particles {
+    /// Queue a particle; it appears when `particle_emit_system` next runs.
+    pub fn emit(&mut self, spec: ParticleSpec) {
+        self.queued.push(spec);
+    }
+}
+
+/// Number of particles to emit for a burst of `count` at the current density.
+pub fn burst_count(count: f32, density: ParticleDensity) -> usize {
+    (count * density.multiplier()).round() as usize
+}
+
+/// Whether a continuous emitter at `rate` per second fires during `dt`.
+fn emits(rate: f32, dt: f32, density: ParticleDensity, rng: &mut impl Rng) -> bool {
+    rng.gen::<f32>() < rate * density.multiplier() * dt
+}
+
+fn tint((r, g, b, a): (f32, f32, f32, f32)) -> Color {
+    Color::srgba(r, g, b, a)
+}
+
+/// Sprite and rotation drawing `spec` in the theme's style.
+fn particle_look(
+    spec: &ParticleSpec,
+    style: &ThemeParticles,
+    sprites: &EffectSprites,
+    rng: &mut impl Rng,
+) -> (Sprite, Quat) {
+    let color = spec.color.unwrap_or_else(|| match spec.kind {
+        ParticleKind::Bubble => tint(style.bubble),
+        ParticleKind::Plankton | ParticleKind::Crumb => tint(style.plankton),
+    });
+    let square = |side: f32| Sprite {
+        color,
+        custom_size: Some(Vec2::splat(side)),
+        ..default()
+    };
+
+    if spec.kind == ParticleKind::Crumb {
+        return (square(spec.size), Quat::from_rotation_z(rng.gen_range(0.0..TAU)));
+    }
+    match style.shape {
+        ParticleShape::Round => {
+            let image = match spec.kind {
+                ParticleKind::Bubble => sprites.bubble.clone(),
+                _ => sprites.speck.clone(),
+            };
+            let sprite = Sprite {
+                image,
+                color,
+                custom_size: Some(Vec2::splat(spec.size)),
+                ..default()
+            };
+            (sprite, Quat::IDENTITY)
+        }
+        ParticleShape::Pixel => {
+            let side = (spec.size / PIXEL_GRID).round().max(1.0) * PIXEL_GRID;
+            (square(side), Quat::IDENTITY)
+        }
+        ParticleShape::Stroke => {
+            let sprite = Sprite {
+                image: sprites.stroke.clone(),
+                color,
+                custom_size: Some(Vec2::new(spec.size * 1.8, spec.size * 0.6)),
+                ..default()
+            };
+            (sprite, Quat::from_rotation_z(rng.gen_range(0.0..TAU)))
+        }
+    }
+}
+
+/// Turn queued specs into particles, reusing pooled entities first.
+#[allow(clippy::needless_pass_by_value)]
+pub fn particle_emit_system(
+    mut commands: Commands,
+    mut particles: ResMut<Particles>,
+    theme: Res<Theme>,
+    sprites: Res<EffectSprites>,
+    mut pooled: Query<(&mut Particle, &mut Sprite, &mut Transform, &mut Visibility)>,
+) {
+    if particles.queued.is_empty() {
+        return;
+    }
+    let mut rng = rand::thread_rng();
+    let queued = std::mem::take(&mut particles.queued);
+
+    for spec in queued {
+        if particles.live >= MAX_PARTICLES {
+            break;
+        }
+        let (sprite, rotation) = particle_look(&spec, &theme.particles, &sprites, &mut rng);
+        let transform = Transform::from_translation(spec.position).with_rotation(rotation);
+        let particle = Particle {
+            active: true,
+            velocity: spec.velocity,
+            buoyancy: spec.buoyancy,
+            drag: spec.drag,
+            sway: spec.sway,
+            phase: rng.gen_range(0.0..TAU),
+            age: 0.0,
+            lifetime: spec.lifetime,
+            fade_in: spec.fade_in,
+            alpha: sprite.color.alpha(),
+        };
+        particles.live += 1;
+
+        let reused = particles
+            .free
+            .pop()
+            .and_then(|entity| pooled.get_mut(entity).ok());
+        match reused {
+            Some((mut pooled_particle, mut pooled_sprite, mut pooled_transform, mut visibility)) => {
+                *pooled_particle = particle;
+                *pooled_sprite = sprite;
+                *pooled_transform = transform;
+                *visibility = Visibility::Inherited;
+            }
+            None => {
+                commands.spawn((sprite, transform, particle));
+            }
+        }
+    }
+}
+
+/// Move and fade live particles, returning expired ones to the pool.
+#[allow(clippy::needless_pass_by_value)]
+pub fn particle_update_system(
+    time: Res<Time>,
+    mut particles: ResMut<Particles>,
+    mut query: Query<(Entity, &mut Particle, &mut Sprite, &mut Transform, &mut Visibility)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut particle, mut sprite, mut transform, mut visibility) in &mut query {
+        if !particle.active {
+            continue;
+        }
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            particle.active = false;
+            *visibility = Visibility::Hidden;
+            particles.free.push(entity);
+            particles.live -= 1;
+            continue;
+        }
+
+        particle.velocity.y += particle.buoyancy * dt;
+        let drag = (1.0 - particle.drag * dt).max(0.0);
+        particle.velocity *= drag;
+        let sway = particle.sway * (particle.age * 2.0 + particle.phase).sin();
+        transform.translation += (particle.velocity + Vec2::new(sway, 0.0)).extend(0.0) * dt;
+
+        let fade_in = if particle.fade_in > 0.0 {
+            (particle.age / particle.fade_in).min(1.0)
+        } else {
+            1.0
+        };
+        // Fade out over the last third of the lifetime.
+        let fade_out = ((particle.lifetime - particle.age) / (particle.lifetime / 3.0)).min(1.0);
+        sprite.color.set_alpha(particle.alpha * fade_in * fade_out);
+    }
+}
+
+/// Hide every live particle and return it to the pool, e.g. when gameplay
+/// starts or ends.
+pub fn release_particles_system(
+    mut particles: ResMut<Particles>,
+    mut query: Query<(Entity, &mut Particle, &mut Visibility)>,
+) {
+    particles.queued.clear();
+    for (entity, mut particle, mut visibility) in &mut query {
+        if particle.active {
+            particle.active = false;
+            *visibility = Visibility::Hidden;
+            particles.free.push(entity);
+        }
+    }
+    particles.live = 0;
+}
+
+/// Occasional bubbles rising from the top of each plant.
+#[allow(clippy::needless_pass_by_value)]
+pub fn flora_bubble_system(
+    time: Res<Time>,
+    density: Res<ParticleDensity>,
+    mut particles: ResMut<Particles>,
+    flora: Query<(&Transform, &BackgroundFlora)>,
+) {
+    let mut rng = rand::thread_rng();
+    for (transform, plant) in &flora {
+        // Silhouettes are too far away, foreground kelp too close.
+        if plant.layer != ParallaxLayer::Playfield {
+            continue;
+        }
+        if !emits(FLORA_BUBBLE_RATE, time.delta_secs(), *density, &mut rng) {
+            continue;
+        }
+        let height = plant.image_height * transform.scale.y;
+        let top = transform.translation.truncate() + Vec2::new(rng.gen_range(-20.0..20.0), height * 0.4);
+        particles.emit(ParticleSpec {
+            velocity: Vec2::Y * rng.gen_range(20.0..40.0),
+            buoyancy: rng.gen_range(15.0..30.0),
+            drag: 0.3,
+            sway: rng.gen_range(8.0..20.0),
+            fade_in: 0.3,
+            ..ParticleSpec::new(
+                ParticleKind::Bubble,
+                top.extend(FLORA_BUBBLE_Z),
+                rng.gen_range(6.0..16.0),
+                rng.gen_range(3.0..6.0),
+            )
+        });
+    }
+}
+
+/// Bubbles trailing from the player's mouth while it swims.
+#[allow(clippy::needless_pass_by_value)]
+pub fn mouth_bubble_system(
+    time: Res<Time>,
+    density: Res<ParticleDensity>,
+    images: Res<Assets<Image>>,
+    mut particles: ResMut<Particles>,
+    player: Query<(&Transform, &Sprite, &FishTexture, &Swimming), With<PlayerFish>>,
+) {
+    let Ok((transform, sprite, texture, swimming)) = player.single() else {
+        return;
+    };
+    let mut rng = rand::thread_rng();
+    if !swimming.0 || !emits(MOUTH_BUBBLE_RATE, time.delta_secs(), *density, &mut rng) {
+        return;
+    }
+
+    // Sprites face left unless flipped; the mouth sits near the front edge.
+    let half_width = texture.0.size(&images).map_or(100.0, |size| size.x) * transform.scale.x.abs() / 2.0;
+    let facing = if sprite.flip_x { 1.0 } else { -1.0 };
+    let mouth = transform.translation.truncate() + Vec2::new(facing * half_width * 0.85, 0.0);
+    particles.emit(ParticleSpec {
+        velocity: Vec2::new(facing * rng.gen_range(10.0..40.0), rng.gen_range(10.0..30.0)),
+        buoyancy: rng.gen_range(40.0..70.0),
+        drag: 1.0,
+        sway: rng.gen_range(5.0..15.0),
+        ..ParticleSpec::new(
+            ParticleKind::Bubble,
+            mouth.extend(EFFECT_Z),
+            rng.gen_range(5.0..12.0),
+            rng.gen_range(0.8..1.5),
+        )
+    });
+}
+
+/// Keep plankton specks drifting through the water. Emitting at the target
+/// count divided by the mean lifetime keeps about that many on screen.
+#[allow(clippy::needless_pass_by_value)]
+pub fn plankton_system(
+    time: Res<Time>,
+    density: Res<ParticleDensity>,
+    playfield: Res<Playfield>,
+    mut particles: ResMut<Particles>,
+    mut owed: Local<f32>,
+) {
+    let mean_lifetime = (PLANKTON_LIFETIME.0 + PLANKTON_LIFETIME.1) / 2.0;
+    *owed += PLANKTON_COUNT * density.multiplier() / mean_lifetime * time.delta_secs();
+
+    let mut rng = rand::thread_rng();
+    let half = playfield.half_size();
+    while *owed >= 1.0 {
+        *owed -= 1.0;
+        let position = Vec2::new(rng.gen_range(-half.x..half.x), rng.gen_range(-half.y..half.y));
+        particles.emit(ParticleSpec {
+            velocity: Vec2::from_angle(rng.gen_range(0.0..TAU)) * rng.gen_range(3.0..12.0),
+            sway: rng.gen_range(2.0..8.0),
+            fade_in: 1.5,
+            ..ParticleSpec::new(
+                ParticleKind::Plankton,
+                position.extend(PLANKTON_Z),
+                rng.gen_range(3.0..7.0),
+                rng.gen_range(PLANKTON_LIFETIME.0..PLANKTON_LIFETIME.1),
+            )
+        });
+    }
+}
@@ -0,0 +1,170 @@
+use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
+use bevy::window::PrimaryWindow;
+
+use crate::components::*;
+use crate::systems::main_menu::GradientBackground;
+
+/// Visible play area in world units.
+///
+/// Gameplay sizes (player minimum size, enemy areas, sprite heights) and
+/// bounds (where fish and hazards spawn and leave, how far the player can
+/// swim) are derived from the fixed [`Playfield::VIRTUAL_SIZE`], so `Meat`
+/// scores and difficulty are the same on every device. The camera always
+/// shows at least that area and extends it along the window's longer axis
+/// instead of stretching; `size` is the area actually visible, used only for
+/// scenery. The extra margin is shaded by [`ArenaMargin`] bars.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Playfield {
+    pub size: Vec2,
+}
+
+impl Default for Playfield {
+    fn default() -> Self {
+        Self {
+            size: Self::VIRTUAL_SIZE,
+        }
+    }
+}
+
+impl Playfield {
+    /// World area every gameplay measurement is based on.
+    pub const VIRTUAL_SIZE: Vec2 = crate::balance::VIRTUAL_SIZE;
+
+    /// Visible area for a window of `window_size` logical pixels under the
+    /// camera's [`ScalingMode::AutoMin`].
+    pub fn for_window(window_size: Vec2) -> Self {
+        let aspect = window_size.x / window_size.y;
+        let size = if aspect > Self::VIRTUAL_SIZE.x / Self::VIRTUAL_SIZE.y {
+            Vec2::new(Self::VIRTUAL_SIZE.y * aspect, Self::VIRTUAL_SIZE.y)
+        } else {
+            Vec2::new(Self::VIRTUAL_SIZE.x, Self::VIRTUAL_SIZE.x / aspect)
+        };
+        Self { size }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.size.x
+    }
+
+    pub fn height(&self) -> f32 {
+        self.size.y
+    }
+
+    pub fn half_size(&self) -> Vec2 {
+        self.size / 2.0
+    }
+
+    /// Half of the arena gameplay is confined to, whatever the window shape.
+    pub fn arena_half_size() -> Vec2 {
+        Self::VIRTUAL_SIZE / 2.0
+    }
+}
+
+/// Shading over the visible area beyond the arena; the outward direction of
+/// the side it covers.
+#[derive(Component)]
+pub struct ArenaMargin(Vec2);
+
+/// Colour of the margin bars: the deep water, mostly opaque so fish entering
+/// the arena emerge from murk rather than popping into view.
+const MARGIN_COLOR: Color = Color::srgba(0.0, 0.06, 0.12, 0.85);
+
+/// In front of the fish, the foreground kelp and the depth overlay, behind UI.
+const MARGIN_Z: f32 = 2.5;
+
+/// Spawn one margin bar per side of the arena. They stay for the whole
+/// session and are zero-sized while the window matches the arena's shape.
+pub fn spawn_arena_margins(mut commands: Commands, playfield: Res<Playfield>) {
+    for side in [Vec2::NEG_X, Vec2::X, Vec2::NEG_Y, Vec2::Y] {
+        let (size, position) = margin_layout(&playfield, side);
+        commands.spawn((
+            Sprite {
+                color: MARGIN_COLOR,
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_translation(position.extend(MARGIN_Z)),
+            ArenaMargin(side),
+        ));
+    }
+}
+
+/// Size and centre of the margin bar on `side` of the arena.
+fn margin_layout(playfield: &Playfield, side: Vec2) -> (Vec2, Vec2) {
+    let arena = Playfield::arena_half_size();
+    let extra = (playfield.half_size() - arena).max(Vec2::ZERO);
+    if side.x != 0.0 {
+        (Vec2::new(extra.x, playfield.height()), side * (arena.x + extra.x / 2.0))
+    } else {
+        (Vec2::new(playfield.width(), extra.y), side * (arena.y + extra.y / 2.0))
+    }
+}
+
+/// Fit the margin bars to the resized playfield.
+#[allow(clippy::needless_pass_by_value)]
+pub fn relayout_arena_margins_system(
+    playfield: Res<Playfield>,
+    mut query: Query<(&mut Sprite, &mut Transform, &ArenaMargin)>,
+) {
+    for (mut sprite, mut transform, margin) in &mut query {
+        let (size, position) = margin_layout(&playfield, margin.0);
+        sprite.custom_size = Some(size);
+        transform.translation = position.extend(MARGIN_Z);
+    }
+}
+
+/// Camera projection showing at least [`Playfield::VIRTUAL_SIZE`] world units
+/// whatever the window size.
+pub fn playfield_projection() -> Projection {
+    Projection::Orthographic(OrthographicProjection {
+        scaling_mode: ScalingMode::AutoMin {
+            min_width: Playfield::VIRTUAL_SIZE.x,
+            min_height: Playfield::VIRTUAL_SIZE.y,
+        },
+        ..OrthographicProjection::default_2d()
+    })
+}
+
+/// Track the area visible in the primary window. Runs in `PreUpdate` so every
+/// system of the frame sees the same playfield.
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_playfield_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut playfield: ResMut<Playfield>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    // Minimised windows report 0×0; keep the last usable size.
+    if window.width() <= 0.0 || window.height() <= 0.0 {
+        return;
+    }
+    playfield.set_if_neq(Playfield::for_window(window.size()));
+}
+
+/// Stretch the gradient backdrop and overlay over the resized playfield.
+#[allow(clippy::needless_pass_by_value)]
+pub fn resize_gradient_background_system(
+    playfield: Res<Playfield>,
+    mut query: Query<&mut Sprite, With<GradientBackground>>,
+) {
+    for mut sprite in &mut query {
+        sprite.custom_size = Some(playfield.size);
+    }
+}
+
+/// Re-lay out flora along the sea floor of the resized playfield, keeping each
+/// plant's relative horizontal position.
+#[allow(clippy::needless_pass_by_value)]
+pub fn relayout_flora_system(
+    playfield: Res<Playfield>,
+    mut query: Query<(&mut Transform, &BackgroundFlora)>,
+) {
+    let half = playfield.half_size();
+    for (mut transform, flora) in &mut query {
+        let scaled_height = flora.image_height * flora.base_scale;
+        transform.translation.x = flora.anchor_x * half.x;
+        transform.translation.y = -half.y - flora.floor_offset + scaled_height / 2.0;
+    }
+}
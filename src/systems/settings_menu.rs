@@ -0,0 +1,289 @@
+use crate::settings::{Accessibility, AccessibilityOption, AudioMixer, ParticleDensity, VolumeSetting};
+use crate::{components::*, constants, states::GameState};
+use crate::theme::Theme;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use super::main_menu::spawn_menu_button;
+
+/// Amount a single "-" / "+" press changes a volume slider by.
+const VOLUME_STEP: f32 = 0.1;
+
+/// Format a 0‒1 volume as a whole percentage.
+fn volume_label(level: f32) -> String {
+    format!("{}%", (level * 100.0).round() as i32)
+}
+
+fn mute_label(muted: bool) -> LocalizedText {
+    if muted {
+        LocalizedText::new("settings.unmute")
+    } else {
+        LocalizedText::new("settings.mute")
+    }
+}
+
+/// Helper to spawn a small square button used for the volume steppers.
+fn spawn_small_button<B: Bundle>(
+    parent: &mut ChildSpawnerCommands,
+    font: Handle<Font>,
+    label: &str,
+    marker: B,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(64.0),
+                height: Val::Px(64.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(ButtonPalette::MENU.idle),
+            BorderRadius::all(Val::Px(constants::BUTTON_RADIUS)),
+            ButtonPalette::MENU,
+            marker,
+        ))
+        .with_children(|p| {
+            p.spawn((
+                Text::new(label),
+                TextFont {
+                    font,
+                    font_size: 42.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+/// Spawn a horizontal settings row; `build` fills in the row's children.
+fn spawn_settings_row(parent: &mut ChildSpawnerCommands, build: impl FnOnce(&mut ChildSpawnerCommands)) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(16.0),
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(build);
+}
+
+/// Fixed-width label at the start of a settings row.
+fn spawn_row_label(row: &mut ChildSpawnerCommands, key: &'static str, width: f32) {
+    row.spawn((
+        Text::default(),
+        TextFont {
+            font_size: 32.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            width: Val::Px(width),
+            ..default()
+        },
+        LocalizedText::new(key),
+    ));
+}
+
+fn toggle_label(on: bool) -> LocalizedText {
+    if on {
+        LocalizedText::new("settings.on")
+    } else {
+        LocalizedText::new("settings.off")
+    }
+}
+
+/// Build the settings menu: volume sliders and mute on the left, language,
+/// accessibility switches and particle density on the right, back button
+/// below.
+pub fn setup_settings_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mixer: Res<AudioMixer>,
+    accessibility: Res<Accessibility>,
+    density: Res<ParticleDensity>,
+    theme: Res<Theme>,
+) {
+    let font = asset_server.load(&theme.fonts.regular);
+    let font_bold = asset_server.load(&theme.fonts.bold);
+
+    let column = Node {
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(16.0),
+        align_items: AlignItems::Center,
+        ..default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            SettingsMenuUI,
+            FocusScope("settings"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 54.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(JustifyText::Center),
+                LocalizedText::bold("settings.title"),
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(48.0),
+                    align_items: AlignItems::Start,
+                    ..default()
+                })
+                .with_children(|columns| {
+                    // ---- Audio ----
+                    columns.spawn(column.clone()).with_children(|audio| {
+                        for setting in VolumeSetting::ALL {
+                            spawn_settings_row(audio, |row| {
+                                spawn_row_label(row, setting.label_key(), 180.0);
+
+                                spawn_small_button(
+                                    row,
+                                    font_bold.clone(),
+                                    "-",
+                                    MenuAction::AdjustVolume { setting, step: -VOLUME_STEP },
+                                );
+
+                                row.spawn((
+                                    Text::new(volume_label(mixer.level(setting))),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 32.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                    TextLayout::new_with_justify(JustifyText::Center),
+                                    Node {
+                                        width: Val::Px(90.0),
+                                        ..default()
+                                    },
+                                    VolumeValueText(setting),
+                                ));
+
+                                spawn_small_button(
+                                    row,
+                                    font_bold.clone(),
+                                    "+",
+                                    MenuAction::AdjustVolume { setting, step: VOLUME_STEP },
+                                );
+                            });
+                        }
+
+                        spawn_menu_button(audio, mute_label(mixer.muted), (MenuAction::ToggleMute, MuteButton));
+                    });
+
+                    // ---- Language & accessibility ----
+                    columns.spawn(column.clone()).with_children(|options| {
+                        // The language button shows the current language's own name.
+                        spawn_settings_row(options, |row| {
+                            spawn_row_label(row, "settings.language", 280.0);
+                            spawn_menu_button(row, LocalizedText::new("language.name"), MenuAction::CycleLanguage);
+                        });
+
+                        for option in AccessibilityOption::ALL {
+                            spawn_settings_row(options, |row| {
+                                spawn_row_label(row, option.label_key(), 280.0);
+                                spawn_menu_button(
+                                    row,
+                                    toggle_label(accessibility.get(option)),
+                                    (MenuAction::ToggleAccessibility(option), AccessibilityToggle(option)),
+                                );
+                            });
+                        }
+
+                        spawn_settings_row(options, |row| {
+                            spawn_row_label(row, "settings.particles", 280.0);
+                            spawn_menu_button(
+                                row,
+                                LocalizedText::new(density.label_key()),
+                                (MenuAction::CycleParticleDensity, ParticleDensityButton),
+                            );
+                        });
+                    });
+                });
+
+            spawn_menu_button(parent, LocalizedText::new("menu.back"), MenuAction::MainMenu);
+        });
+}
+
+/// Pressing ESC in the settings menu returns to the main menu.
+#[allow(clippy::needless_pass_by_value)]
+pub fn esc_to_main_menu_from_settings_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::MainMenu);
+    }
+}
+
+/// Keep the percentage labels and toggle button texts in sync with the
+/// settings (the mixer can also change through the mute hotkey).
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+pub fn update_settings_labels_system(
+    mixer: Res<AudioMixer>,
+    accessibility: Res<Accessibility>,
+    density: Res<ParticleDensity>,
+    mut volume_texts: Query<(&mut Text, &VolumeValueText)>,
+    mute_buttons: Query<&Children, With<MuteButton>>,
+    toggles: Query<(&Children, &AccessibilityToggle)>,
+    density_buttons: Query<&Children, With<ParticleDensityButton>>,
+    mut labels: Query<&mut LocalizedText>,
+) {
+    if mixer.is_changed() {
+        for (mut text, value) in &mut volume_texts {
+            *text = Text::new(volume_label(mixer.level(value.0)));
+        }
+
+        for children in &mute_buttons {
+            let mut iter = labels.iter_many_mut(children);
+            while let Some(mut label) = iter.fetch_next() {
+                label.set_if_neq(mute_label(mixer.muted));
+            }
+        }
+    }
+
+    if accessibility.is_changed() {
+        for (children, toggle) in &toggles {
+            let mut iter = labels.iter_many_mut(children);
+            while let Some(mut label) = iter.fetch_next() {
+                label.set_if_neq(toggle_label(accessibility.get(toggle.0)));
+            }
+        }
+    }
+
+    if density.is_changed() {
+        for children in &density_buttons {
+            let mut iter = labels.iter_many_mut(children);
+            while let Some(mut label) = iter.fetch_next() {
+                label.set_if_neq(LocalizedText::new(density.label_key()));
+            }
+        }
+    }
+}
+
+/// Despawn the settings menu on state exit.
+pub fn cleanup_settings_menu(mut commands: Commands, query: Query<Entity, With<SettingsMenuUI>>) {
+    for e in &query {
+        commands.entity(e).despawn();
+    }
+}
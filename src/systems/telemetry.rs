@@ -0,0 +1,192 @@
+//! Feed gameplay into the optional run [`Telemetry`] log.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::events::{FishEaten, PlayerDied, PlayerLeveledUp, PlayerStung};
+use crate::balance::Balance;
+use crate::systems::in_game::{broad_phase_radius, MovingFish};
+use crate::telemetry::{RunEnd, Side, Telemetry, TelemetryEvent};
+use crate::theme::Theme;
+
+/// Seconds between player size/position samples.
+const SAMPLE_INTERVAL: f32 = 1.0;
+
+/// A dangerous fish whose collision circle comes this close to the player's
+/// without touching counts as a near miss.
+const NEAR_MISS_MARGIN: f32 = 40.0;
+
+/// Start a log when the player fish is spawned.
+#[allow(clippy::needless_pass_by_value)]
+pub fn telemetry_run_start_system(
+    mut telemetry: ResMut<Telemetry>,
+    theme: Res<Theme>,
+    balance: Res<Balance>,
+    player: Query<&Meat, Added<PlayerFish>>,
+) {
+    let Ok(meat) = player.single() else {
+        return;
+    };
+    telemetry.begin_run(TelemetryEvent::RunStarted {
+        theme: theme.key.clone(),
+        player_meat: meat.0,
+        balance: *balance,
+    });
+}
+
+/// Advance the run clock and sample the player's size and position.
+#[allow(clippy::needless_pass_by_value)]
+pub fn telemetry_sample_system(
+    mut telemetry: ResMut<Telemetry>,
+    time: Res<Time>,
+    mut since_sample: Local<f32>,
+    player: Query<(&Transform, &Meat), With<PlayerFish>>,
+) {
+    if !telemetry.is_recording() {
+        return;
+    }
+    telemetry.tick(time.delta_secs());
+
+    *since_sample += time.delta_secs();
+    if *since_sample < SAMPLE_INTERVAL {
+        return;
+    }
+    *since_sample = 0.0;
+    if let Ok((transform, meat)) = player.single() {
+        telemetry.record(TelemetryEvent::Sample {
+            meat: meat.0,
+            x: transform.translation.x,
+            y: transform.translation.y,
+        });
+    }
+}
+
+/// Log every enemy fish as it enters the playfield.
+pub fn telemetry_fish_spawned_system(
+    mut telemetry: ResMut<Telemetry>,
+    fish: Query<(&MovingFish, &Meat), Added<MovingFish>>,
+) {
+    if !telemetry.is_recording() {
+        return;
+    }
+    for (fish, meat) in &fish {
+        telemetry.record(TelemetryEvent::FishSpawned {
+            meat: meat.0,
+            speed: fish.velocity.x.abs(),
+            side: if fish.velocity.x > 0.0 { Side::Left } else { Side::Right },
+        });
+    }
+}
+
+/// Log bites, level-ups, stings and the death that ends the run.
+#[allow(clippy::needless_pass_by_value)]
+pub fn telemetry_gameplay_events_system(
+    mut telemetry: ResMut<Telemetry>,
+    mut eaten: EventReader<FishEaten>,
+    mut leveled: EventReader<PlayerLeveledUp>,
+    mut stung: EventReader<PlayerStung>,
+    mut died: EventReader<PlayerDied>,
+    asset_server: Res<AssetServer>,
+    killers: Query<&FishTexture>,
+) {
+    if !telemetry.is_recording() {
+        eaten.clear();
+        leveled.clear();
+        stung.clear();
+        died.clear();
+        return;
+    }
+
+    for event in eaten.read() {
+        telemetry.record(TelemetryEvent::FishEaten {
+            prey_meat: event.prey_meat,
+            player_meat: event.player_meat,
+            x: event.position.x,
+            y: event.position.y,
+        });
+    }
+
+    for event in leveled.read() {
+        telemetry.record(TelemetryEvent::LeveledUp {
+            level: event.level,
+            player_meat: event.player_meat,
+        });
+    }
+
+    for event in stung.read() {
+        telemetry.record(TelemetryEvent::Stung {
+            meat_lost: event.meat_lost,
+            player_meat: event.player_meat,
+            x: event.position.x,
+            y: event.position.y,
+        });
+    }
+
+    if let Some(event) = died.read().last() {
+        let killer_sprite = killers
+            .get(event.killer)
+            .ok()
+            .and_then(|texture| asset_server.get_path(texture.0.image.id()))
+            .map(|path| path.to_string());
+        telemetry.record(TelemetryEvent::Died {
+            cause: event.cause,
+            killer_sprite,
+            killer_meat: event.killer_meat,
+            player_meat: event.player_meat,
+            x: event.position.x,
+            y: event.position.y,
+        });
+        telemetry.end_run(RunEnd::Died, event.player_meat);
+    }
+}
+
+/// Log dangerous fish that pass within [`NEAR_MISS_MARGIN`] of the player.
+/// The closest gap is tracked while a fish is in range and recorded once it
+/// swims clear.
+#[allow(clippy::needless_pass_by_value)]
+pub fn telemetry_near_miss_system(
+    mut telemetry: ResMut<Telemetry>,
+    mut closest: Local<HashMap<Entity, f32>>,
+    player: Query<(&Transform, &Meat), With<PlayerFish>>,
+    fish: Query<(Entity, &Transform, &Meat), With<MovingFish>>,
+) {
+    let player = player.single().ok().filter(|_| telemetry.is_recording());
+    let Some((player_tf, player_meat)) = player else {
+        closest.clear();
+        return;
+    };
+    let player_radius = broad_phase_radius(player_meat.0);
+
+    for (entity, transform, meat) in &fish {
+        if meat.0 < player_meat.0 {
+            // Edible now; forget it if it was threatening before the player grew.
+            closest.remove(&entity);
+            continue;
+        }
+        let distance = transform.translation.truncate().distance(player_tf.translation.truncate());
+        let gap = (distance - player_radius - broad_phase_radius(meat.0)).max(0.0);
+
+        if gap <= NEAR_MISS_MARGIN {
+            let entry = closest.entry(entity).or_insert(gap);
+            *entry = entry.min(gap);
+        } else if let Some(gap) = closest.remove(&entity) {
+            telemetry.record(TelemetryEvent::NearMiss {
+                enemy_meat: meat.0,
+                player_meat: player_meat.0,
+                gap,
+            });
+        }
+    }
+    // Fish that swam off-screen while still in range.
+    closest.retain(|entity, _| fish.contains(*entity));
+}
+
+/// Close the log when gameplay is left without dying.
+pub fn telemetry_run_quit_system(mut telemetry: ResMut<Telemetry>, player: Query<&Meat, With<PlayerFish>>) {
+    if telemetry.is_recording() {
+        let final_meat = player.single().map_or(0, |meat| meat.0);
+        telemetry.end_run(RunEnd::Quit, final_meat);
+    }
+}
@@ -1,9 +1,11 @@
-use crate::{components::*, constants, states::GameState};
+use crate::{components::*, constants};
 use bevy::prelude::*;
-use crate::theme::Theme;
+use crate::localization::font_for;
+use crate::theme::{Theme, ThemeRegistry};
+use crate::theme_pack::PackError;
 use bevy::audio::AudioSource;
-use crate::assets::ThemeSelectAudio;
-use bevy::audio::{AudioPlayer, PlaybackSettings};
+#[cfg(target_arch = "wasm32")]
+use crate::{assets::ThemeSelectAudio, events::MenuActionEvent, theme_pack::{self, PackUploads}};
 
 /// Resource inserted when the player clicks a theme button to defer the
 /// theme-select SFX until all heavy image assets finish loading.
@@ -13,39 +15,97 @@ pub struct PendingThemeSelectSfx {
 }
 
 /// Helper function to spawn a themed image button used in the picker.
-fn spawn_theme_image_button<C: Component + Copy + 'static>(
+/// `focused` marks the button as the screen's [`DefaultFocus`].
+fn spawn_theme_image_button(
     parent: &mut ChildSpawnerCommands,
     asset_server: &AssetServer,
-    image_path: &str,
-    marker: C,
+    theme: &Theme,
+    focused: bool,
 ) {
     use bevy::ui::*;
 
-    let handle = asset_server.load(image_path);
+    let handle = asset_server.load(theme.path(&theme.preview));
+
+    let mut button = parent.spawn((
+        Button,
+        // Fixed width with auto height to preserve aspect ratio.
+        Node {
+            width: Val::Px(200.0),
+            height: Val::Auto,
+            padding: UiRect::all(Val::Px(0.0)),
+            border: UiRect::all(Val::Px(20.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        ImageNode::new(handle),
+        BackgroundColor(ButtonPalette::THEME_PICKER.idle),
+        BorderColor(Color::BLACK),
+        BorderRadius::all(Val::Px(constants::BUTTON_RADIUS)),
+        ButtonPalette::THEME_PICKER,
+        MenuAction::SelectTheme(theme.id),
+        Name::new(theme.name.clone()),
+    ));
+
+    if focused {
+        button.insert(DefaultFocus);
+    }
+}
+
+/// Card explaining why a theme pack was not registered.
+fn spawn_pack_error_card(
+    parent: &mut ChildSpawnerCommands,
+    asset_server: &AssetServer,
+    theme: &Theme,
+    error: &PackError,
+) {
+    let detail = format!("{}: {}", error.pack, error.message);
+    let detail_font = asset_server.load(font_for(&detail, false, &theme.fonts).to_string());
 
     parent
         .spawn((
-            Button,
-            // Fixed width with auto height to preserve aspect ratio.
             Node {
-                width: Val::Px(200.0),
-                height: Val::Auto,
-                padding: UiRect::all(Val::Px(0.0)),
-                border: UiRect::all(Val::Px(20.0)),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
+                width: Val::Px(420.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(3.0)),
                 ..default()
             },
-            ImageNode::new(handle),
-            BackgroundColor(constants::THEME_PICKER_IDLE_COLOR.into()),
-            BorderColor(Color::BLACK),
+            BackgroundColor(constants::PACK_ERROR_BACKGROUND_COLOR),
+            BorderColor(constants::DANGER_INDICATOR_COLOR),
             BorderRadius::all(Val::Px(constants::BUTTON_RADIUS)),
-            marker,
-        ));
+        ))
+        .with_children(|card| {
+            card.spawn((
+                Text::default(),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(constants::DANGER_INDICATOR_COLOR),
+                LocalizedText::bold("theme_picker.broken_pack"),
+            ));
+            card.spawn((
+                Text::new(detail),
+                TextFont {
+                    font: detail_font,
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
 }
 
-/// Build the theme picker UI with three vertically stacked buttons.
-pub fn setup_theme_picker_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
+/// Build the theme picker UI with one button per registered theme, an error
+/// card per broken theme pack and, on the web, a button importing a pack.
+pub fn setup_theme_picker_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    theme: Res<Theme>,
+    registry: Res<ThemeRegistry>,
+) {
     // Full-screen root container, centred.
     commands
         .spawn((
@@ -59,146 +119,90 @@ pub fn setup_theme_picker_ui(mut commands: Commands, asset_server: Res<AssetServ
                 ..default()
             },
             ThemePickerUI,
+            FocusScope("theme_picker"),
         ))
         .with_children(|parent| {
             // Title above the theme buttons.
             parent.spawn((
-                Text::new("Choose a Theme!"),
+                Text::default(),
                 TextFont {
-                    font: asset_server.load("fonts/Fredoka-Bold.ttf"),
                     font_size: 54.0,
                     ..default()
                 },
                 TextColor(Color::WHITE),
                 TextLayout::new_with_justify(JustifyText::Center),
+                LocalizedText::bold("theme_picker.title"),
             ));
 
-            // Replace text with representative fish icons for each theme.
-            spawn_theme_image_button(
-                parent,
-                &asset_server,
-                "images/crayon/playable_fish/fish_1.png",
-                CrayonButton,
-            );
-            spawn_theme_image_button(
-                parent,
-                &asset_server,
-                "images/chibi/playable_fish/fish_2.png",
-                ChibiButton,
-            );
-            spawn_theme_image_button(
+            // A representative fish icon for each theme, wrapping onto further
+            // rows once theme packs are installed.
+            parent
+                .spawn(Node {
+                    max_width: Val::Percent(90.0),
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(20.0),
+                    row_gap: Val::Px(20.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for entry in registry.iter() {
+                        spawn_theme_image_button(row, &asset_server, entry, entry.id == theme.id);
+                    }
+                });
+
+            for error in registry.broken() {
+                spawn_pack_error_card(parent, &asset_server, &theme, error);
+            }
+
+            #[cfg(target_arch = "wasm32")]
+            super::spawn_menu_button(
                 parent,
-                &asset_server,
-                "images/retro/playable_fish/fish_2.png",
-                RetroPixelButton,
+                LocalizedText::new("theme_picker.import_pack"),
+                MenuAction::ImportThemePack,
             );
         });
 }
 
-/// Generic interaction handler for a theme button.
-#[derive(Component)]
-pub struct ThemeHoverSfx;
-
-fn theme_button_interaction<B: Component>(
-    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<B>)>,
-    mut commands: Commands,
-    hover_audio_query: Query<Entity, With<ThemeHoverSfx>>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut theme: ResMut<Theme>,
-    selected_theme: Theme,
-    audio_handle: Handle<AudioSource>,
-) {
-    for (interaction, mut color) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *color = constants::THEME_PICKER_PRESSED_COLOR.into();
-
-                // Store chosen theme then transition to main menu.
-                *theme = selected_theme;
-                next_state.set(GameState::MainMenu);
-            }
-            Interaction::Hovered => {
-                *color = constants::THEME_PICKER_HOVER_COLOR.into();
-
-                // Avoid overlapping hover SFX – remove any currently playing one.
-                for entity in &hover_audio_query {
-                    commands.entity(entity).despawn();
-                }
-
-                // Spawn a new audio player for the hover SFX (auto-despawns when finished).
-                commands.spawn((
-                    AudioPlayer::new(audio_handle.clone()),
-                    PlaybackSettings::DESPAWN,
-                    ThemeHoverSfx,
-                ));
-            }
-            Interaction::None => {
-                *color = constants::THEME_PICKER_IDLE_COLOR.into();
-            }
-        }
+/// Despawn all theme picker UI entities on state exit.
+pub fn cleanup_theme_picker(mut commands: Commands, query: Query<Entity, With<ThemePickerUI>>) {
+    for e in &query {
+        commands.entity(e).despawn_recursive();
     }
 }
 
-pub fn crayon_button_system(
-    interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<CrayonButton>)>,
-    commands: Commands,
-    hover_audio_query: Query<Entity, With<ThemeHoverSfx>>,
-    next_state: ResMut<NextState<GameState>>,
-    theme: ResMut<Theme>,
-    audio: Res<ThemeSelectAudio>,
-) {
-    theme_button_interaction::<CrayonButton>(
-        interaction_query,
-        commands,
-        hover_audio_query,
-        next_state,
-        theme,
-        Theme::Crayon,
-        audio.crayon.clone(),
-    );
-}
-
-pub fn chibi_button_system(
-    interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<ChibiButton>)>,
-    commands: Commands,
-    hover_audio_query: Query<Entity, With<ThemeHoverSfx>>,
-    next_state: ResMut<NextState<GameState>>,
-    theme: ResMut<Theme>,
-    audio: Res<ThemeSelectAudio>,
+/// Open the browser's file dialog when the import button is pressed.
+#[cfg(target_arch = "wasm32")]
+#[allow(clippy::needless_pass_by_value)]
+pub fn theme_pack_upload_dialog_system(
+    mut events: EventReader<MenuActionEvent>,
+    uploads: Res<PackUploads>,
 ) {
-    theme_button_interaction::<ChibiButton>(
-        interaction_query,
-        commands,
-        hover_audio_query,
-        next_state,
-        theme,
-        Theme::Chibi,
-        audio.chibi.clone(),
-    );
+    for MenuActionEvent(action) in events.read() {
+        if *action == MenuAction::ImportThemePack {
+            theme_pack::open_upload_dialog(&uploads);
+        }
+    }
 }
 
-pub fn retro_button_system(
-    interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<RetroPixelButton>)>,
-    commands: Commands,
-    hover_audio_query: Query<Entity, With<ThemeHoverSfx>>,
-    next_state: ResMut<NextState<GameState>>,
-    theme: ResMut<Theme>,
-    audio: Res<ThemeSelectAudio>,
+/// Register zips picked through the file dialog and preload their hover clip.
+/// Touching the registry rebuilds the picker, showing the new theme or its
+/// error card.
+#[cfg(target_arch = "wasm32")]
+#[allow(clippy::needless_pass_by_value)]
+pub fn import_theme_pack_uploads_system(
+    uploads: Res<PackUploads>,
+    mut registry: ResMut<ThemeRegistry>,
+    mut select_audio: ResMut<ThemeSelectAudio>,
+    asset_server: Res<AssetServer>,
 ) {
-    theme_button_interaction::<RetroPixelButton>(
-        interaction_query,
-        commands,
-        hover_audio_query,
-        next_state,
-        theme,
-        Theme::Retro,
-        audio.retro.clone(),
-    );
-}
-
-/// Despawn all theme picker UI entities on state exit.
-pub fn cleanup_theme_picker(mut commands: Commands, query: Query<Entity, With<ThemePickerUI>>) {
-    for e in &query {
-        commands.entity(e).despawn_recursive();
+    let pending = std::mem::take(&mut *uploads.0.lock().unwrap());
+    for (file_name, bytes) in pending {
+        if let Some(theme) = theme_pack::import_zip(&mut registry, &file_name, &bytes) {
+            select_audio
+                .clips
+                .insert(theme.id, asset_server.load(theme.select_sound.clone()));
+        }
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,225 @@
+//! Optional per-run gameplay telemetry for balancing.
+//!
+//! When enabled, every run is logged as JSON Lines: one object per event with
+//! an `event` tag and the run time `t` in seconds (pauses excluded). Native
+//! builds opt in by setting [`TELEMETRY_ENV`] to an output directory; the web
+//! build opts in with a `telemetry` query parameter and offers each log as a
+//! download when the run ends.
+//!
+//! ```text
+//! {"t":0.0,"event":"run_started","theme":"retro","player_meat":4096,"balance":{...}}
+//! {"t":1.3,"event":"fish_spawned","meat":912,"speed":61.2,"side":"left"}
+//! {"t":2.0,"event":"sample","meat":4096,"x":120.5,"y":-33.0}
+//! ```
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::balance::Balance;
+use crate::events::DeathCause;
+
+/// Environment variable naming the directory run logs are written to.
+#[cfg(not(target_arch = "wasm32"))]
+pub const TELEMETRY_ENV: &str = "FISHY_TELEMETRY";
+
+/// Query parameter enabling telemetry in the web build.
+#[cfg(target_arch = "wasm32")]
+const TELEMETRY_QUERY: &str = "telemetry";
+
+/// Side of the playfield a fish entered from.
+#[derive(Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// Why a run ended.
+#[derive(Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RunEnd {
+    /// Eaten by a bigger fish or killed by a hazard.
+    Died,
+    /// Left gameplay from the pause menu (or any other way).
+    Quit,
+}
+
+/// One line of a run log.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TelemetryEvent {
+    /// The player fish was spawned. Records the tuning the run was played with.
+    RunStarted {
+        theme: String,
+        player_meat: usize,
+        balance: Balance,
+    },
+    /// An enemy fish entered the playfield.
+    FishSpawned { meat: usize, speed: f32, side: Side },
+    /// The player ate a fish.
+    FishEaten { prey_meat: usize, player_meat: usize, x: f32, y: f32 },
+    /// A fish big enough to eat the player passed within the near-miss margin
+    /// without touching it. `gap` is the closest distance between their
+    /// collision circles.
+    NearMiss { enemy_meat: usize, player_meat: usize, gap: f32 },
+    /// The player grew into a new size level.
+    LeveledUp { level: u32, player_meat: usize },
+    /// A jellyfish stung the player.
+    Stung { meat_lost: usize, player_meat: usize, x: f32, y: f32 },
+    /// The player died.
+    Died {
+        cause: DeathCause,
+        /// Asset path of the killer's sprite, when known.
+        killer_sprite: Option<String>,
+        killer_meat: usize,
+        player_meat: usize,
+        x: f32,
+        y: f32,
+    },
+    /// Periodic player sample.
+    Sample { meat: usize, x: f32, y: f32 },
+    /// Last line of every log.
+    RunEnded { reason: RunEnd, final_meat: usize },
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    t: f32,
+    #[serde(flatten)]
+    event: &'a TelemetryEvent,
+}
+
+/// Telemetry switch and the log of the run in progress.
+#[derive(Resource, Default)]
+pub struct Telemetry {
+    pub enabled: bool,
+    run: Option<RunLog>,
+}
+
+struct RunLog {
+    /// Seconds of gameplay since the run started.
+    time: f32,
+    lines: String,
+}
+
+impl Telemetry {
+    /// Enabled through [`TELEMETRY_ENV`] (native) or the page URL (web).
+    pub fn detect() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let enabled = std::env::var_os(TELEMETRY_ENV).is_some();
+        #[cfg(target_arch = "wasm32")]
+        let enabled = web_sys::window()
+            .and_then(|window| window.location().search().ok())
+            .is_some_and(|search| {
+                search
+                    .trim_start_matches('?')
+                    .split('&')
+                    .any(|pair| pair.split('=').next() == Some(TELEMETRY_QUERY))
+            });
+
+        if enabled {
+            info!("Run telemetry enabled");
+        }
+        Self { enabled, run: None }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.run.is_some()
+    }
+
+    /// Start a new log (dropping any unfinished one) with `event` as its
+    /// first line.
+    pub fn begin_run(&mut self, event: TelemetryEvent) {
+        if !self.enabled {
+            return;
+        }
+        self.run = Some(RunLog {
+            time: 0.0,
+            lines: String::new(),
+        });
+        self.record(event);
+    }
+
+    /// Advance the run clock.
+    pub fn tick(&mut self, seconds: f32) {
+        if let Some(run) = &mut self.run {
+            run.time += seconds;
+        }
+    }
+
+    /// Append `event` to the run in progress, if any.
+    pub fn record(&mut self, event: TelemetryEvent) {
+        let Some(run) = &mut self.run else {
+            return;
+        };
+        let record = Record {
+            t: run.time,
+            event: &event,
+        };
+        match serde_json::to_string(&record) {
+            Ok(line) => {
+                run.lines.push_str(&line);
+                run.lines.push('\n');
+            }
+            Err(e) => warn!("could not serialise telemetry event {event:?}: {e}"),
+        }
+    }
+
+    /// Close the run in progress with a [`TelemetryEvent::RunEnded`] line and
+    /// export its log.
+    pub fn end_run(&mut self, reason: RunEnd, final_meat: usize) {
+        self.record(TelemetryEvent::RunEnded { reason, final_meat });
+        if let Some(run) = self.run.take() {
+            export(&run.lines);
+        }
+    }
+}
+
+/// Write a finished log to [`TELEMETRY_ENV`]`/run-<unix time>.jsonl`.
+#[cfg(not(target_arch = "wasm32"))]
+fn export(log: &str) {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let Some(dir) = std::env::var_os(TELEMETRY_ENV).map(std::path::PathBuf::from) else {
+        return;
+    };
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis());
+    let path = dir.join(format!("run-{stamp}.jsonl"));
+
+    let result = std::fs::create_dir_all(&dir).and_then(|()| std::fs::write(&path, log));
+    match result {
+        Ok(()) => info!("Run telemetry written to {}", path.display()),
+        Err(e) => warn!("could not write run telemetry to {}: {e}", path.display()),
+    }
+}
+
+/// Offer a finished log as a browser download.
+#[cfg(target_arch = "wasm32")]
+fn export(log: &str) {
+    use wasm_bindgen::JsCast;
+
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let parts = js_sys::Array::of1(&wasm_bindgen::JsValue::from_str(log));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("application/x-ndjson");
+    let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options) else {
+        return;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+    if let Ok(link) = document
+        .create_element("a")
+        .map(|element| element.unchecked_into::<web_sys::HtmlAnchorElement>())
+    {
+        link.set_href(&url);
+        link.set_download(&format!("fishy-run-{}.jsonl", js_sys::Date::now() as u64));
+        // The object URL is not revoked: some browsers cancel downloads whose
+        // URL is revoked right after the click.
+        link.click();
+    }
+}
@@ -1,40 +1,275 @@
 use bevy::prelude::*;
+use serde::Deserialize;
 
-/// Available visual themes for the game assets.
-///
-/// The enum implements [`Resource`] so the currently selected theme can be stored
-/// globally via [`insert_resource`](bevy::prelude::Commands::insert_resource).
-#[derive(Resource, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
-pub enum Theme {
-    /// Hand-drawn crayon look.
+use crate::theme_pack::{PackError, THEME_PACK_SOURCE};
+
+/// Registry file listing every built-in theme.
+pub const THEME_REGISTRY_PATH: &str = "assets/themes.ron";
+
+/// Stable handle to a theme: its index in the [`ThemeRegistry`]. Cheap to copy
+/// into components, events and button actions.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub struct ThemeId(pub usize);
+
+/// Water gradient colours (sRGB, 0‒1) used behind menus and gameplay.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ThemePalette {
+    pub water_top: (f32, f32, f32),
+    pub water_bottom: (f32, f32, f32),
+}
+
+/// UI fonts for a theme. Scripts they cannot render fall back to the chain in
+/// [`crate::localization`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThemeFonts {
+    pub regular: String,
+    pub bold: String,
+}
+
+/// How a theme draws ambient particles (bubbles, plankton, bite bursts).
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub enum ParticleShape {
+    /// Soft round bubbles and specks.
     #[default]
-    Crayon,
-    /// Cute chibi style.
-    Chibi,
-    /// Retro pixel-art vibe.
-    Retro,
+    Round,
+    /// Hard-edged squares snapped to a coarse grid size (retro).
+    Pixel,
+    /// Short hand-drawn strokes (crayon).
+    Stroke,
+}
+
+/// Particle look of a theme. Tints are sRGB 0‒1 with alpha.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct ThemeParticles {
+    pub shape: ParticleShape,
+    pub bubble: (f32, f32, f32, f32),
+    pub plankton: (f32, f32, f32, f32),
+}
+
+impl Default for ThemeParticles {
+    fn default() -> Self {
+        Self {
+            shape: ParticleShape::Round,
+            bubble: (0.85, 0.95, 1.0, 0.8),
+            plankton: (0.8, 0.95, 0.7, 0.5),
+        }
+    }
+}
+
+/// Look and motion of one background or foreground depth layer.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ParallaxLayerStyle {
+    /// How far the layer moves with the view: 1 moves like the playfield,
+    /// smaller values are further away, larger ones closer.
+    pub scroll: f32,
+    /// Scale applied to the layer's sprites.
+    pub scale: f32,
+    /// Sprite tint (sRGB 0‒1).
+    pub tint: (f32, f32, f32),
+    /// Opacity, standing in for depth blur.
+    pub alpha: f32,
+}
+
+impl ParallaxLayerStyle {
+    /// The layer's tint and opacity as a sprite colour.
+    pub fn color(&self) -> Color {
+        let (r, g, b) = self.tint;
+        Color::srgba(r, g, b, self.alpha)
+    }
+}
+
+/// Depth layers around the playfield, back to front. The playfield itself
+/// never scrolls.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct ThemeParallax {
+    /// Large, faint shapes far behind everything else.
+    pub silhouettes: ParallaxLayerStyle,
+    /// The drifting obscura fish.
+    pub obscura: ParallaxLayerStyle,
+    /// Kelp in front of the playfield.
+    pub foreground: ParallaxLayerStyle,
+    /// Share of the followed fish's position the view leans towards.
+    pub follow: f32,
+}
+
+impl Default for ThemeParallax {
+    fn default() -> Self {
+        Self {
+            silhouettes: ParallaxLayerStyle {
+                scroll: 0.25,
+                scale: 1.4,
+                tint: (0.05, 0.14, 0.22),
+                alpha: 0.5,
+            },
+            obscura: ParallaxLayerStyle {
+                scroll: 0.6,
+                scale: 0.85,
+                tint: (0.75, 0.85, 0.95),
+                alpha: 0.8,
+            },
+            foreground: ParallaxLayerStyle {
+                scroll: 1.4,
+                scale: 1.35,
+                tint: (0.3, 0.45, 0.4),
+                alpha: 0.6,
+            },
+            follow: 0.12,
+        }
+    }
+}
+
+/// Depth lighting of a theme: how dark the water gets as the player grows
+/// and which enemy species glow.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ThemeLighting {
+    /// Ambient brightness at the surface (1 leaves the scene unlit).
+    pub surface: f32,
+    /// Ambient brightness in the deepest zone.
+    pub deep: f32,
+    /// Ambient tint in the deepest zone (sRGB 0‒1).
+    pub deep_tint: (f32, f32, f32),
+    /// Player level at which the deepest zone is reached.
+    pub deep_level: u32,
+    /// Enemy fish images (file names under `images/fish/`) that glow.
+    pub glowing: Vec<String>,
+    /// Light colour of glowing fish (sRGB 0‒1).
+    pub glow: (f32, f32, f32),
+}
+
+impl Default for ThemeLighting {
+    fn default() -> Self {
+        Self {
+            surface: 1.0,
+            deep: 0.2,
+            deep_tint: (0.45, 0.6, 1.0),
+            deep_level: 6,
+            glowing: Vec::new(),
+            glow: (0.5, 1.0, 0.9),
+        }
+    }
+}
+
+/// Colours of a theme's environmental hazards (sRGB 0‒1). They tint the
+/// generated sprites of themes without their own `images/hazards/`.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default)]
+pub struct ThemeHazards {
+    pub jellyfish: (f32, f32, f32),
+    pub hook: (f32, f32, f32),
+    pub mine: (f32, f32, f32),
+    /// Fishing line and the warning marker before a hook drops.
+    pub line: (f32, f32, f32),
+}
+
+impl Default for ThemeHazards {
+    fn default() -> Self {
+        Self {
+            jellyfish: (0.95, 0.6, 0.9),
+            hook: (0.75, 0.78, 0.82),
+            mine: (0.22, 0.24, 0.28),
+            line: (0.92, 0.92, 0.85),
+        }
+    }
+}
+
+/// Where a theme's files come from.
+#[derive(Debug, Clone, Default)]
+pub enum ThemeOrigin {
+    /// Shipped in `assets/` and listed in `assets/themes.ron`.
+    #[default]
+    BuiltIn,
+    /// User-installed theme pack, served from the `themepack://` asset source.
+    Pack {
+        /// Every file in the pack, relative to the pack root (`/`-separated).
+        files: Vec<String>,
+        /// Built-in theme directory whose sounds stand in for ones the pack
+        /// does not provide.
+        sound_fallback: String,
+    },
+}
+
+/// One visual theme as described in `assets/themes.ron` or a theme pack
+/// manifest.
+///
+/// The currently selected theme is stored as a [`Resource`] so loaders can
+/// resolve paths with `Res<Theme>`.
+#[derive(Resource, Debug, Clone, Deserialize)]
+pub struct Theme {
+    /// Position in the registry, assigned when the registry is loaded.
+    #[serde(skip)]
+    pub id: ThemeId,
+    /// Short identifier, unique across all themes (`id` in the registry file).
+    #[serde(rename = "id")]
+    pub key: String,
+    /// Name shown to the player.
+    pub name: String,
+    /// Directory under `assets/images/` and `assets/sounds/`.
+    pub directory: String,
+    /// Picker preview image, relative to the theme's image directory.
+    pub preview: String,
+    /// Clip previewed while the theme is hovered in the picker.
+    pub select_sound: String,
+    pub palette: ThemePalette,
+    pub fonts: ThemeFonts,
+    /// Angler-light position on playable fish 3 (unscaled px from top-left).
+    #[serde(default)]
+    pub lure_offset: Option<(f32, f32)>,
+    /// Ambient particle look; round bubbles when omitted.
+    #[serde(default)]
+    pub particles: ThemeParticles,
+    /// Depth layer look and motion; the defaults when omitted.
+    #[serde(default)]
+    pub parallax: ThemeParallax,
+    /// Depth lighting; the defaults when omitted.
+    #[serde(default)]
+    pub lighting: ThemeLighting,
+    /// Hazard colours; the defaults when omitted.
+    #[serde(default)]
+    pub hazards: ThemeHazards,
+    #[serde(skip)]
+    pub origin: ThemeOrigin,
 }
 
 impl Theme {
-    /// Return the lowercase prefix that all files belonging to this theme are
+    /// Return the directory name that all files belonging to this theme are
     /// stored under in the `assets/images` directory.
-    pub const fn prefix(&self) -> &'static str {
-        match self {
-            Theme::Crayon => "crayon",
-            Theme::Chibi => "chibi",
-            Theme::Retro => "retro",
+    pub fn prefix(&self) -> &str {
+        &self.directory
+    }
+
+    /// Asset path of the theme's image directory: `images/<theme>` for
+    /// built-in themes, `themepack://<pack>/images` for theme packs.
+    pub fn image_root(&self) -> String {
+        match self.origin {
+            ThemeOrigin::BuiltIn => format!("images/{}", self.prefix()),
+            ThemeOrigin::Pack { .. } => format!("{THEME_PACK_SOURCE}://{}/images", self.prefix()),
         }
     }
 
-    /// Helper that prefixes the supplied relative path with the theme directory.
-    ///
-    /// ```
-    /// # use fishy_redux::theme::Theme;
-    /// let t = Theme::Crayon;
-    /// assert_eq!(t.path("playable_fish/fish_1.png"), "images/crayon/playable_fish/fish_1.png");
-    /// ```
+    /// Helper that prefixes the supplied relative path with the theme directory,
+    /// e.g. `playable_fish/fish_1.png` → `images/crayon/playable_fish/fish_1.png`.
     pub fn path(&self, rel: &str) -> String {
-        format!("images/{}/{}", self.prefix(), rel)
+        format!("{}/{}", self.image_root(), rel)
+    }
+
+    /// Helper that prefixes the supplied relative path with the theme's sound
+    /// directory (`assets/sounds/<theme>/`). Theme packs without the clip fall
+    /// back to a built-in theme's sound.
+    pub fn sound_path(&self, rel: &str) -> String {
+        match &self.origin {
+            ThemeOrigin::BuiltIn => format!("sounds/{}/{}", self.prefix(), rel),
+            ThemeOrigin::Pack { files, sound_fallback } => {
+                let pack_file = format!("sounds/{rel}");
+                if files.contains(&pack_file) {
+                    format!("{THEME_PACK_SOURCE}://{}/{pack_file}", self.prefix())
+                } else {
+                    format!("sounds/{sound_fallback}/{rel}")
+                }
+            }
+        }
     }
 
     /// Helper that returns the absolute on-disk path (inside the `assets` folder)
@@ -43,4 +278,84 @@ impl Theme {
     pub fn assets_dir(&self, subdir: &str) -> String {
         format!("assets/images/{}/{}", self.prefix(), subdir)
     }
-} 
\ No newline at end of file
+}
+
+/// All themes the player can pick from, in picker order, plus the theme packs
+/// that failed validation.
+#[derive(Resource, Debug, Clone)]
+pub struct ThemeRegistry {
+    themes: Vec<Theme>,
+    broken: Vec<PackError>,
+}
+
+impl ThemeRegistry {
+    /// Parse a registry file and assign every entry its [`ThemeId`].
+    pub fn parse(source: &str) -> Result<Self, ron::error::SpannedError> {
+        let mut themes: Vec<Theme> = ron::from_str(source)?;
+        for (index, theme) in themes.iter_mut().enumerate() {
+            theme.id = ThemeId(index);
+        }
+        Ok(Self {
+            themes,
+            broken: Vec::new(),
+        })
+    }
+
+    /// Load the built-in registry. Native builds read it from disk so themes
+    /// can be added without recompiling; wasm embeds it at build time.
+    pub fn load() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let source = std::fs::read_to_string(THEME_REGISTRY_PATH)
+            .unwrap_or_else(|e| panic!("failed to read {THEME_REGISTRY_PATH}: {e}"));
+        #[cfg(target_arch = "wasm32")]
+        let source = include_str!("../assets/themes.ron").to_string();
+
+        let registry =
+            Self::parse(&source).unwrap_or_else(|e| panic!("invalid {THEME_REGISTRY_PATH}: {e}"));
+        assert!(!registry.themes.is_empty(), "{THEME_REGISTRY_PATH} lists no themes");
+        for (index, theme) in registry.themes.iter().enumerate() {
+            assert!(
+                registry.themes[..index].iter().all(|other| other.key != theme.key),
+                "{THEME_REGISTRY_PATH} lists theme id {:?} twice",
+                theme.key
+            );
+        }
+        registry
+    }
+
+    /// Append a theme (e.g. from a theme pack), assigning its [`ThemeId`].
+    pub fn add(&mut self, mut theme: Theme) -> ThemeId {
+        theme.id = ThemeId(self.themes.len());
+        self.themes.push(theme);
+        ThemeId(self.themes.len() - 1)
+    }
+
+    /// Record a theme pack that could not be registered so the picker can
+    /// explain why it is missing.
+    pub fn add_broken(&mut self, error: PackError) {
+        warn!("theme pack {:?} rejected: {}", error.pack, error.message);
+        self.broken.push(error);
+    }
+
+    /// Whether a theme with the given registry id (`key`) exists.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.themes.iter().any(|theme| theme.key == key)
+    }
+
+    pub fn get(&self, id: ThemeId) -> &Theme {
+        &self.themes[id.0]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Theme> {
+        self.themes.iter()
+    }
+
+    pub fn broken(&self) -> impl Iterator<Item = &PackError> {
+        self.broken.iter()
+    }
+
+    /// Theme selected on first launch: the first registry entry.
+    pub fn default_theme(&self) -> Theme {
+        self.themes[0].clone()
+    }
+}
@@ -0,0 +1,385 @@
+//! User-installable theme packs.
+//!
+//! A theme pack is a folder (or, on the web, a `.zip` of that folder) laid out
+//! like a built-in theme plus a manifest:
+//!
+//! ```text
+//! my_pack/
+//!   theme.ron                 id, name, preview, optional palette/fonts/sounds/particles/parallax/lighting/hazards
+//!   images/playable_fish/fish_1.png … fish_3.png
+//!   images/fish/*.png         at least one enemy fish
+//!   images/flora/*.png        optional
+//!   images/obscura/*.png      optional
+//!   images/hazards/*.png      optional jellyfish.png, hook.png, mine.png
+//!   images/score_icon.png
+//!   sounds/*.wav              optional, missing clips use a built-in theme's
+//! ```
+//!
+//! Native builds scan [`theme_packs_dir`] once at start-up; the web build
+//! imports zips picked through the theme picker. Packs are served to the
+//! asset server through the `themepack://` asset source. Packs that fail
+//! validation are kept as [`PackError`]s and shown on the theme picker.
+
+use bevy::asset::io::{AssetSource, AssetSourceBuilder};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::theme::{
+    Theme, ThemeFonts, ThemeHazards, ThemeLighting, ThemeOrigin, ThemePalette, ThemeParallax,
+    ThemeParticles, ThemeRegistry,
+};
+
+/// Asset source theme pack files are loaded from (`themepack://<pack>/…`).
+pub const THEME_PACK_SOURCE: &str = "themepack";
+
+/// Manifest file every pack must contain at its root.
+const MANIFEST_FILE: &str = "theme.ron";
+
+/// Images the game loads by name, relative to the pack's `images/` folder.
+const REQUIRED_IMAGES: &[&str] = &[
+    "playable_fish/fish_1.png",
+    "playable_fish/fish_2.png",
+    "playable_fish/fish_3.png",
+    "score_icon.png",
+];
+
+/// A theme pack that could not be registered.
+#[derive(Debug, Clone)]
+pub struct PackError {
+    /// Folder or zip name of the pack.
+    pub pack: String,
+    /// Human-readable reason, shown on the theme picker.
+    pub message: String,
+}
+
+/// `theme.ron` inside a pack. Everything a built-in theme gets from
+/// `assets/themes.ron` except the directory, which is the pack's folder.
+#[derive(Deserialize)]
+struct PackManifest {
+    id: String,
+    name: String,
+    /// Picker preview image, relative to the pack's `images/` folder.
+    preview: String,
+    /// Hover clip relative to the pack root; defaults to the first built-in
+    /// theme's clip.
+    #[serde(default)]
+    select_sound: Option<String>,
+    #[serde(default)]
+    palette: Option<ThemePalette>,
+    #[serde(default)]
+    fonts: Option<ThemeFonts>,
+    #[serde(default)]
+    lure_offset: Option<(f32, f32)>,
+    #[serde(default)]
+    particles: ThemeParticles,
+    #[serde(default)]
+    parallax: ThemeParallax,
+    #[serde(default)]
+    lighting: ThemeLighting,
+    #[serde(default)]
+    hazards: ThemeHazards,
+}
+
+/// Check a pack's manifest and file list and turn it into a [`Theme`].
+///
+/// `files` lists every file in the pack relative to its root, `/`-separated.
+/// Defaults for optional manifest fields come from the registry's first theme.
+pub fn validate_pack(
+    pack: &str,
+    manifest: Option<&str>,
+    files: Vec<String>,
+    registry: &ThemeRegistry,
+) -> Result<Theme, String> {
+    if pack.is_empty() || pack.contains(['/', '\\', '#', ':']) {
+        return Err(format!("folder name {pack:?} cannot be used as a pack name"));
+    }
+    let already_installed = registry
+        .iter()
+        .any(|theme| matches!(theme.origin, ThemeOrigin::Pack { .. }) && theme.directory == pack);
+    if already_installed {
+        return Err(format!("a pack named {pack:?} is already installed"));
+    }
+
+    let manifest = manifest.ok_or_else(|| format!("missing {MANIFEST_FILE}"))?;
+    let manifest: PackManifest =
+        ron::from_str(manifest).map_err(|e| format!("{MANIFEST_FILE}: {e}"))?;
+    if registry.contains_key(&manifest.id) {
+        return Err(format!("theme id {:?} is already taken", manifest.id));
+    }
+
+    let has = |path: &str| files.iter().any(|file| file == path);
+    let mut images = REQUIRED_IMAGES.to_vec();
+    if !images.contains(&manifest.preview.as_str()) {
+        images.push(&manifest.preview);
+    }
+    let mut missing: Vec<String> = images
+        .iter()
+        .map(|image| format!("images/{image}"))
+        .filter(|path| !has(path))
+        .collect();
+    let has_enemy_fish = files
+        .iter()
+        .any(|file| file.starts_with("images/fish/") && file.ends_with(".png"));
+    if !has_enemy_fish {
+        missing.push("images/fish/*.png".to_string());
+    }
+    if let Some(sound) = manifest.select_sound.as_deref().filter(|sound| !has(sound)) {
+        missing.push(sound.to_string());
+    }
+    if !missing.is_empty() {
+        return Err(format!("missing {}", missing.join(", ")));
+    }
+
+    let defaults = registry.default_theme();
+    Ok(Theme {
+        id: default(),
+        key: manifest.id,
+        name: manifest.name,
+        directory: pack.to_string(),
+        preview: manifest.preview,
+        select_sound: manifest
+            .select_sound
+            .map(|sound| format!("{THEME_PACK_SOURCE}://{pack}/{sound}"))
+            .unwrap_or(defaults.select_sound),
+        palette: manifest.palette.unwrap_or(defaults.palette),
+        fonts: manifest.fonts.unwrap_or(defaults.fonts),
+        lure_offset: manifest.lure_offset,
+        particles: manifest.particles,
+        parallax: manifest.parallax,
+        lighting: manifest.lighting,
+        hazards: manifest.hazards,
+        origin: ThemeOrigin::Pack {
+            files,
+            sound_fallback: defaults.directory,
+        },
+    })
+}
+
+/// Validate a pack and add it (or its error) to the registry.
+pub fn register_pack(
+    registry: &mut ThemeRegistry,
+    pack: &str,
+    manifest: Option<&str>,
+    files: Vec<String>,
+) -> Option<Theme> {
+    match validate_pack(pack, manifest, files, registry) {
+        Ok(theme) => {
+            info!("registered theme pack {pack:?} ({})", theme.name);
+            let id = registry.add(theme);
+            Some(registry.get(id).clone())
+        }
+        Err(message) => {
+            registry.add_broken(PackError {
+                pack: pack.to_string(),
+                message,
+            });
+            None
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  Native: packs are folders in the theme pack directory
+// -----------------------------------------------------------------------------
+
+/// Environment variable overriding [`DEFAULT_THEME_PACKS_DIR`].
+#[cfg(not(target_arch = "wasm32"))]
+pub const THEME_PACKS_ENV: &str = "FISHY_THEME_PACKS";
+
+/// Directory scanned for theme packs, relative to the working directory.
+#[cfg(not(target_arch = "wasm32"))]
+pub const DEFAULT_THEME_PACKS_DIR: &str = "theme_packs";
+
+/// Directory holding one sub-folder per theme pack.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn theme_packs_dir() -> std::path::PathBuf {
+    let dir = std::env::var_os(THEME_PACKS_ENV)
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| DEFAULT_THEME_PACKS_DIR.into());
+    // Bevy resolves relative source paths against the executable, not the
+    // working directory the scan uses.
+    std::fs::canonicalize(&dir).unwrap_or(dir)
+}
+
+/// `themepack://` asset source reading straight from [`theme_packs_dir`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn asset_source() -> AssetSourceBuilder {
+    let root = theme_packs_dir().to_string_lossy().into_owned();
+    AssetSource::build().with_reader(AssetSource::get_default_reader(root))
+}
+
+/// Register every pack folder found in [`theme_packs_dir`].
+#[cfg(not(target_arch = "wasm32"))]
+pub fn scan_theme_packs(registry: &mut ThemeRegistry) {
+    use std::fs;
+
+    let root = theme_packs_dir();
+    let Ok(entries) = fs::read_dir(&root) else {
+        return;
+    };
+
+    let mut packs: Vec<_> = entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect();
+    packs.sort();
+
+    for dir in packs {
+        let Some(pack) = dir.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let manifest = fs::read_to_string(dir.join(MANIFEST_FILE)).ok();
+        let mut files = Vec::new();
+        list_files(&dir, "", &mut files);
+        register_pack(registry, pack, manifest.as_deref(), files);
+    }
+}
+
+/// Recursively collect every file below `dir` as `/`-separated paths.
+#[cfg(not(target_arch = "wasm32"))]
+fn list_files(dir: &std::path::Path, prefix: &str, out: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Some(name) = entry.file_name().to_str().map(str::to_owned) else {
+            continue;
+        };
+        let path = entry.path();
+        if path.is_dir() {
+            list_files(&path, &format!("{prefix}{name}/"), out);
+        } else {
+            out.push(format!("{prefix}{name}"));
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+//  Web: packs are zips picked through a file input
+// -----------------------------------------------------------------------------
+
+/// In-memory file tree behind the `themepack://` source. The asset plugin
+/// builds its readers before any resource exists, so the tree is a static.
+#[cfg(target_arch = "wasm32")]
+static PACK_FILES: std::sync::LazyLock<bevy::asset::io::memory::Dir> =
+    std::sync::LazyLock::new(Default::default);
+
+/// `themepack://` asset source reading from the imported zips.
+#[cfg(target_arch = "wasm32")]
+pub fn asset_source() -> AssetSourceBuilder {
+    use bevy::asset::io::memory::MemoryAssetReader;
+
+    AssetSource::build().with_reader(|| {
+        Box::new(MemoryAssetReader {
+            root: PACK_FILES.clone(),
+        })
+    })
+}
+
+/// Zips the player picked that still need unpacking: `(file name, bytes)`.
+/// Filled from the browser's file reader callback, drained by
+/// [`import_theme_pack_uploads_system`](crate::systems::import_theme_pack_uploads_system).
+#[cfg(target_arch = "wasm32")]
+#[derive(Resource, Default, Clone)]
+pub struct PackUploads(pub std::sync::Arc<std::sync::Mutex<Vec<(String, Vec<u8>)>>>);
+
+/// Show the browser's file dialog; the chosen zip is queued in `uploads`.
+#[cfg(target_arch = "wasm32")]
+pub fn open_upload_dialog(uploads: &PackUploads) {
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let Ok(input) = document
+        .create_element("input")
+        .map(|element| element.unchecked_into::<web_sys::HtmlInputElement>())
+    else {
+        return;
+    };
+    input.set_type("file");
+    input.set_accept(".zip");
+
+    let queue = uploads.0.clone();
+    let picker = input.clone();
+    let on_change = Closure::once(move || {
+        let Some(file) = picker.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        wasm_bindgen_futures::spawn_local(async move {
+            let name = file.name();
+            match wasm_bindgen_futures::JsFuture::from(file.array_buffer()).await {
+                Ok(buffer) => {
+                    let bytes = js_sys::Uint8Array::new(&buffer).to_vec();
+                    queue.lock().unwrap().push((name, bytes));
+                }
+                Err(e) => warn!("could not read {name}: {e:?}"),
+            }
+        });
+    });
+    input.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+    on_change.forget();
+    input.click();
+}
+
+/// Unpack an uploaded zip into the in-memory tree and register it. A zip of
+/// the pack folder itself (one top-level directory) is accepted too.
+#[cfg(target_arch = "wasm32")]
+pub fn import_zip(registry: &mut ThemeRegistry, file_name: &str, bytes: &[u8]) -> Option<Theme> {
+    let pack = file_name.strip_suffix(".zip").unwrap_or(file_name);
+    let mut entries = match unzip(bytes) {
+        Ok(entries) => entries,
+        Err(message) => {
+            registry.add_broken(PackError {
+                pack: pack.to_string(),
+                message,
+            });
+            return None;
+        }
+    };
+
+    // Strip a single wrapping folder, e.g. `my_pack/theme.ron`.
+    if !entries.iter().any(|(path, _)| path == MANIFEST_FILE) {
+        if let Some((top, _)) = entries.first().and_then(|(path, _)| path.split_once('/')) {
+            let top = format!("{top}/");
+            if entries.iter().all(|(path, _)| path.starts_with(&top)) {
+                for (path, _) in &mut entries {
+                    *path = path[top.len()..].to_string();
+                }
+            }
+        }
+    }
+
+    let manifest = entries
+        .iter()
+        .find(|(path, _)| path == MANIFEST_FILE)
+        .and_then(|(_, data)| String::from_utf8(data.clone()).ok());
+    let files = entries.iter().map(|(path, _)| path.clone()).collect();
+    let theme = register_pack(registry, pack, manifest.as_deref(), files)?;
+
+    for (path, data) in entries {
+        PACK_FILES.insert_asset(std::path::Path::new(&format!("{pack}/{path}")), data);
+    }
+    Some(theme)
+}
+
+/// Every file in a zip archive as `(/-separated path, contents)`.
+#[cfg(target_arch = "wasm32")]
+fn unzip(bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    use std::io::Read;
+
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(bytes)).map_err(|e| format!("not a zip file: {e}"))?;
+    let mut entries = Vec::new();
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|e| e.to_string())?;
+        if entry.is_dir() {
+            continue;
+        }
+        let Some(path) = entry.enclosed_name() else {
+            continue;
+        };
+        let path = path.to_string_lossy().replace('\\', "/");
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).map_err(|e| format!("{path}: {e}"))?;
+        entries.push((path, data));
+    }
+    Ok(entries)
+}